@@ -335,6 +335,26 @@ pub fn directed_fan(n: usize) -> DiGraph<(), ()> {
     g
 }
 
+/// A flow network where the source (node 0) and the sink (node 1) are joined
+/// through two fully connected layers of `layer` nodes.
+pub fn layered_network(layer: usize) -> DiGraph<(), usize> {
+    let mut g = DiGraph::new();
+    let source = g.add_node(());
+    let sink = g.add_node(());
+    let left: Vec<_> = (0..layer).map(|_| g.add_node(())).collect();
+    let right: Vec<_> = (0..layer).map(|_| g.add_node(())).collect();
+    for (i, &l) in left.iter().enumerate() {
+        g.add_edge(source, l, layer + i);
+        for (j, &r) in right.iter().enumerate() {
+            g.add_edge(l, r, (i * 7 + j * 13) % 11 + 1);
+        }
+    }
+    for (j, &r) in right.iter().enumerate() {
+        g.add_edge(r, sink, layer + j);
+    }
+    g
+}
+
 /// Parse a file in graph6 format into an undirected graph
 pub fn ungraph_from_graph6_file(path: &str) -> Graph<(), (), Undirected, u32> {
     let mut f = File::open(path).expect("file not found");
@@ -2,6 +2,10 @@
 extern crate petgraph;
 extern crate test;
 
+#[allow(dead_code)]
+mod common;
+use common::*;
+
 use petgraph::algo::dinics;
 use petgraph::prelude::{Graph, NodeIndex};
 use test::Bencher;
@@ -22,3 +26,11 @@ fn dinics_bench(bench: &mut Bencher) {
         );
     });
 }
+
+#[bench]
+fn dinics_dense_bench(bench: &mut Bencher) {
+    let g = layered_network(100);
+    bench.iter(|| {
+        let _flow = dinics(&g, NodeIndex::from(0), NodeIndex::from(1));
+    });
+}
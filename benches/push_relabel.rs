@@ -0,0 +1,36 @@
+#![feature(test)]
+extern crate petgraph;
+extern crate test;
+
+#[allow(dead_code)]
+mod common;
+use common::*;
+
+use petgraph::algo::push_relabel;
+use petgraph::prelude::{Graph, NodeIndex};
+use test::Bencher;
+
+#[bench]
+fn push_relabel_bench(bench: &mut Bencher) {
+    static NODE_COUNT: usize = 1_000;
+    let mut g: Graph<usize, usize> = Graph::new();
+    let nodes: Vec<NodeIndex<_>> = (0..NODE_COUNT).map(|i| g.add_node(i)).collect();
+    for i in 0..NODE_COUNT - 1 {
+        g.add_edge(nodes[i], nodes[i + 1], 1);
+    }
+    bench.iter(|| {
+        let _flow = push_relabel(
+            &g,
+            NodeIndex::from(0),
+            NodeIndex::from(g.node_count() as u32 - 1),
+        );
+    });
+}
+
+#[bench]
+fn push_relabel_dense_bench(bench: &mut Bencher) {
+    let g = layered_network(100);
+    bench.iter(|| {
+        let _flow = push_relabel(&g, NodeIndex::from(0), NodeIndex::from(1));
+    });
+}
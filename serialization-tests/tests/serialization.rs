@@ -8,6 +8,7 @@ extern crate serde_json;
 #[macro_use]
 extern crate defmac;
 
+use std::collections::hash_map::RandomState;
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::iter::FromIterator;
@@ -15,10 +16,15 @@ use std::iter::FromIterator;
 use itertools::assert_equal;
 use itertools::{repeat_n, Itertools};
 
+use petgraph::adj::List;
+use petgraph::csr::Csr;
+use petgraph::data::convert;
 use petgraph::graph::{edge_index, node_index, IndexType};
+use petgraph::matrix_graph::{DiMatrix, MatrixGraph, UnMatrix};
 use petgraph::prelude::*;
 use petgraph::visit::EdgeRef;
 use petgraph::visit::IntoEdgeReferences;
+use petgraph::visit::NodeCount;
 use petgraph::visit::NodeIndexable;
 use petgraph::EdgeType;
 
@@ -564,3 +570,265 @@ fn json_graphmap_struct() {
         (1, 2.)
     );
 }
+
+// Return the edges of a graph as node index pairs with their weight, sorted.
+fn sorted_edges<G>(g: G) -> Vec<(usize, usize, G::EdgeWeight)>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::EdgeWeight: Ord + Clone,
+{
+    let mut edges: Vec<_> = g
+        .edge_references()
+        .map(|e| {
+            (
+                g.to_index(e.source()),
+                g.to_index(e.target()),
+                e.weight().clone(),
+            )
+        })
+        .collect();
+    edges.sort();
+    edges
+}
+
+fn assert_csr_eq<N, E, Ty>(g: &Csr<N, E, Ty>, h: &Csr<N, E, Ty>)
+where
+    N: PartialEq + Debug,
+    E: PartialEq + Debug,
+    Ty: EdgeType,
+{
+    assert_eq!(g.node_count(), h.node_count());
+    assert_eq!(g.edge_count(), h.edge_count());
+    for a in 0..g.node_count() as u32 {
+        assert_eq!(g[a], h[a]);
+        assert_eq!(g.neighbors_slice(a), h.neighbors_slice(a));
+        assert_eq!(g.edges_slice(a), h.edges_slice(a));
+    }
+}
+
+fn assert_matrix_graph_eq<N, E, Ty>(
+    g: &MatrixGraph<N, E, RandomState, Ty>,
+    h: &MatrixGraph<N, E, RandomState, Ty>,
+) where
+    N: PartialEq + Debug,
+    E: Ord + Clone + Debug,
+    Ty: EdgeType,
+{
+    assert_eq!(g.node_count(), h.node_count());
+    assert_eq!(g.node_bound(), h.node_bound());
+    for i in 0..g.node_bound() {
+        let a = node_index(i);
+        assert_eq!(g.get_node_weight(a), h.get_node_weight(a));
+    }
+    let edges = sorted_edges(g);
+    assert_eq!(h.edge_count(), edges.len());
+    assert_eq!(edges, sorted_edges(h));
+}
+
+quickcheck! {
+    fn json_csr_to_csr(g1: DiGraph<i32, i32>) -> () {
+        let (csr, _): (Csr<i32, i32>, _) = convert(&g1);
+        let csr2: Csr<i32, i32> = rejson!(&csr);
+        assert_csr_eq(&csr, &csr2);
+    }
+
+    fn bincode_undirected_csr_to_csr(g1: UnGraph<i8, i8>) -> () {
+        let (csr, _): (Csr<i8, i8, Undirected>, _) = convert(&g1);
+        let csr2: Csr<i8, i8, Undirected> = recode!(&csr);
+        assert_csr_eq(&csr, &csr2);
+    }
+
+    fn json_matrix_graph_to_matrix_graph(g1: DiGraph<i32, i32, u16>) -> () {
+        let (mut matrix, _): (DiMatrix<i32, i32>, _) = convert(&g1);
+        // Leave holes in the node indices.
+        for i in (1..matrix.node_bound()).step_by(3) {
+            matrix.remove_node(node_index(i));
+        }
+        let matrix2: DiMatrix<i32, i32> = rejson!(&matrix);
+        assert_matrix_graph_eq(&matrix, &matrix2);
+    }
+
+    fn bincode_undirected_matrix_graph_to_matrix_graph(g1: UnGraph<i8, i8, u16>) -> () {
+        let (mut matrix, _): (UnMatrix<i8, i8>, _) = convert(&g1);
+        for i in (0..matrix.node_bound()).step_by(4) {
+            matrix.remove_node(node_index(i));
+        }
+        let mut matrix2: UnMatrix<i8, i8> = recode!(&matrix);
+        assert_matrix_graph_eq(&matrix, &matrix2);
+        // Removed node indices are reused.
+        if matrix2.node_count() < matrix2.node_bound() {
+            let a = matrix2.add_node(0);
+            assert!(a.index() < matrix.node_bound());
+        }
+    }
+
+    fn json_list_to_list(g1: DiGraph<(), i32>) -> () {
+        let (list, _): (List<i32>, _) = convert(&g1);
+        let list2: List<i32> = rejson!(&list);
+        assert_eq!(list.node_count(), list2.node_count());
+        // Edges are kept in order, with their indices.
+        assert_equal(
+            list.edge_references().map(|e| (e.id(), e.source(), e.target(), *e.weight())),
+            list2.edge_references().map(|e| (e.id(), e.source(), e.target(), *e.weight())),
+        );
+    }
+
+    fn bincode_list_to_list(g1: DiGraph<(), ()>) -> () {
+        let (list, _): (List<()>, _) = convert(&g1);
+        let list2: List<()> = recode!(&list);
+        assert_eq!(list.node_count(), list2.node_count());
+        assert_eq!(sorted_edges(&list), sorted_edges(&list2));
+    }
+}
+
+#[test]
+fn json_csr_format() {
+    let csr =
+        Csr::<_, _, Undirected>::from_sorted_undirected_edges(&[(0, 1, 'a'), (1, 1, 'b')]).unwrap();
+    assert_eq!(
+        tojson!(&csr),
+        r#"{"nodes":[null,null],"edge_property":"undirected","row":[0,1,3],"column":[1,0,1],"edges":["a","a","b"]}"#
+    );
+    let csr2: Csr<(), char, Undirected> = rejson!(&csr);
+    assert_csr_eq(&csr, &csr2);
+    assert_eq!(csr2.edge_count(), 2);
+}
+
+const CSR_INCONSISTENT_ROW: &str = r#"{
+    "nodes":[null,null,null],
+    "edge_property": "directed",
+    "row":[0,2,1,3],
+    "column":[1,2,0],
+    "edges":[null,null,null]
+}"#;
+
+const CSR_SHORT_ROW: &str = r#"{
+    "nodes":[null,null,null],
+    "edge_property": "directed",
+    "row":[0,2,3],
+    "column":[1,2,0],
+    "edges":[null,null,null]
+}"#;
+
+const CSR_INDEX_OOB: &str = r#"{
+    "nodes":[null,null,null],
+    "edge_property": "directed",
+    "row":[0,2,2,3],
+    "column":[1,3,0],
+    "edges":[null,null,null]
+}"#;
+
+const CSR_UNSORTED: &str = r#"{
+    "nodes":[null,null,null],
+    "edge_property": "directed",
+    "row":[0,2,2,3],
+    "column":[2,1,0],
+    "edges":[null,null,null]
+}"#;
+
+const CSR_ASYMMETRIC: &str = r#"{
+    "nodes":[null,null,null],
+    "edge_property": "undirected",
+    "row":[0,2,3,3],
+    "column":[1,2,0],
+    "edges":[null,null,null]
+}"#;
+
+#[test]
+fn from_json_csr() {
+    let csr: Csr<(), ()> = fromjson!(&CSR_UNSORTED.replace("[2,1,0]", "[1,2,0]"));
+    assert_eq!(csr.edge_count(), 3);
+    assert_eq!(csr.neighbors_slice(0), &[1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "not a valid row index")]
+fn from_json_csr_inconsistent_row() {
+    let _: Csr<(), ()> = fromjson!(&CSR_INCONSISTENT_ROW);
+}
+
+#[test]
+#[should_panic(expected = "not a valid row index")]
+fn from_json_csr_short_row() {
+    let _: Csr<(), ()> = fromjson!(&CSR_SHORT_ROW);
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn from_json_csr_index_oob() {
+    let _: Csr<(), ()> = fromjson!(&CSR_INDEX_OOB);
+}
+
+#[test]
+#[should_panic(expected = "strictly increasing")]
+fn from_json_csr_unsorted() {
+    let _: Csr<(), ()> = fromjson!(&CSR_UNSORTED);
+}
+
+#[test]
+#[should_panic(expected = "both directions")]
+fn from_json_csr_asymmetric() {
+    let _: Csr<(), (), Undirected> = fromjson!(&CSR_ASYMMETRIC);
+}
+
+#[test]
+#[should_panic(expected = "edge property mismatch")]
+fn from_json_csr_edge_property_mismatch() {
+    let _: Csr<(), (), Undirected> = fromjson!(&CSR_INDEX_OOB);
+}
+
+const MATRIX_GRAPH_HOLE: &str = r#"{
+    "nodes":["a",null,"c"],
+    "edge_property": "directed",
+    "edges":[[0,2,1],[2,0,2]]
+}"#;
+
+#[test]
+fn from_json_matrix_graph() {
+    let mut matrix: DiMatrix<&str, i32> = fromjson!(&MATRIX_GRAPH_HOLE);
+    assert_eq!(matrix.node_count(), 2);
+    assert_eq!(matrix.edge_count(), 2);
+    assert_eq!(*matrix.edge_weight(node_index(2), node_index(0)), 2);
+    assert_eq!(matrix.add_node("b"), node_index(1));
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn from_json_matrix_graph_index_oob() {
+    let _: DiMatrix<&str, i32> = fromjson!(&MATRIX_GRAPH_HOLE.replace("[2,0,2]", "[3,0,2]"));
+}
+
+#[test]
+#[should_panic(expected = "node hole `1` is not allowed")]
+fn from_json_matrix_graph_edge_to_hole() {
+    let _: DiMatrix<&str, i32> = fromjson!(&MATRIX_GRAPH_HOLE.replace("[2,0,2]", "[2,1,2]"));
+}
+
+#[test]
+#[should_panic(expected = "parallel edge")]
+fn from_json_matrix_graph_parallel_edges() {
+    let _: UnMatrix<&str, i32> = fromjson!(&MATRIX_GRAPH_HOLE.replace("directed", "undirected"));
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn from_json_list_index_oob() {
+    let _: List<i32> = fromjson!(r#"{"successors":[[[1,5]],[[2,6]]]}"#);
+}
+
+#[test]
+fn json_list_format() {
+    let mut list = List::new();
+    let a = list.add_node();
+    let b = list.add_node();
+    list.add_edge(a, b, 5);
+    list.add_edge(b, b, 6);
+    list.add_edge(a, a, 7);
+    assert_eq!(tojson!(&list), r#"{"successors":[[[1,5],[0,7]],[[1,6]]]}"#);
+    let list2: List<i32> = rejson!(&list);
+    assert_eq!(list2.edge_count(), 3);
+    assert_eq!(
+        list2.edge_indices().collect::<Vec<_>>(),
+        list.edge_indices().collect::<Vec<_>>()
+    );
+}
@@ -1,6 +1,9 @@
 //! A wrapper around graph types that enforces an acyclicity invariant.
 
-use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 use core::{
     cell::RefCell,
     cmp::Ordering,
@@ -10,7 +13,7 @@ use core::{
 
 use crate::{
     adj::IndexType,
-    algo::Cycle,
+    algo::{tarjan_scc, Cycle},
     data::{Build, Create, DataMap, DataMapMut},
     graph::NodeIndex,
     prelude::DiGraph,
@@ -18,7 +21,7 @@ use crate::{
         dfs_visitor, Control, Data, DfsEvent, EdgeCount, EdgeIndexable, GetAdjacencyMatrix,
         GraphBase, GraphProp, IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors,
         IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable,
-        NodeCount, NodeIndexable, Reversed, Time, Visitable,
+        NodeCount, NodeIndexable, Reversed, Time, VisitMap, Visitable,
     },
     Direction,
 };
@@ -26,6 +29,17 @@ use crate::{
 #[cfg(feature = "stable_graph")]
 use crate::stable_graph::StableDiGraph;
 
+#[cfg(any(feature = "graphmap", feature = "matrix_graph"))]
+use crate::Directed;
+#[cfg(any(feature = "graphmap", feature = "matrix_graph"))]
+use core::hash::BuildHasher;
+
+#[cfg(feature = "graphmap")]
+use crate::graphmap::{GraphMap, NodeTrait};
+
+#[cfg(feature = "matrix_graph")]
+use crate::matrix_graph::{MatrixGraph, Nullable};
+
 mod order_map;
 use fixedbitset::FixedBitSet;
 use order_map::OrderMap;
@@ -41,7 +55,9 @@ pub use order_map::TopologicalPosition;
 ///
 /// To be modifiable (and hence to be useful), the graphs of generic type `G`
 /// should implement the [`Build`] trait. Good candidates for `G` are thus
-/// [`crate::graph::DiGraph`] and [`crate::stable_graph::StableDiGraph`].
+/// [`crate::graph::DiGraph`], [`crate::stable_graph::StableDiGraph`],
+/// [`crate::graphmap::DiGraphMap`] and directed
+/// [`crate::matrix_graph::MatrixGraph`]s.
 ///
 /// ## Algorithm
 /// This implements the PK algorithm for dynamic topological sort described in
@@ -84,6 +100,9 @@ pub struct Acyclic<G: Visitable> {
 pub enum AcyclicEdgeError<N> {
     /// The edge would create a cycle.
     Cycle(Cycle<N>),
+    /// A batch of edges would create cycles, self-loops included. Each cycle
+    /// is reported once, by one of its nodes.
+    Cycles(Vec<Cycle<N>>),
     /// The edge would create a self-loop.
     SelfLoop,
     /// Could not successfully add the edge to the underlying graph.
@@ -144,9 +163,9 @@ where
 {
     /// Wrap a graph into an acyclic graph.
     ///
-    /// The graph types [`DiGraph`] and [`StableDiGraph`] also implement
-    /// [`TryFrom`], which can be used instead of this method and have looser
-    /// type bounds.
+    /// The graph types [`DiGraph`], [`StableDiGraph`], `DiGraphMap` and
+    /// directed `MatrixGraph` also implement [`TryFrom`], which can be used
+    /// instead of this method and have looser type bounds.
     pub fn try_from_graph(graph: G) -> Result<Self, Cycle<G::NodeId>> {
         let order_map = OrderMap::try_from_graph(&graph)?;
         let discovered = RefCell::new(FixedBitSet::with_capacity(graph.node_bound()));
@@ -184,7 +203,6 @@ where
     ) -> Result<G::EdgeId, AcyclicEdgeError<G::NodeId>>
     where
         G: Build,
-        G::NodeId: IndexType,
     {
         if a == b {
             // No self-loops allowed
@@ -214,7 +232,6 @@ where
     ) -> Result<G::EdgeId, AcyclicEdgeError<G::NodeId>>
     where
         G: Build,
-        G::NodeId: IndexType,
     {
         if a == b {
             // No self-loops allowed
@@ -224,13 +241,74 @@ where
         Ok(self.graph.update_edge(a, b, weight))
     }
 
-    /// Check if an edge would be valid, i.e. adding it would not create a cycle.
+    /// Add a batch of edges to the graph using [`Build::add_edge`], with a
+    /// single update of the topological order.
     ///
-    /// **Panics** if `a` or `b` are not found.
-    pub fn is_valid_edge(&self, a: G::NodeId, b: G::NodeId) -> bool
+    /// Either all the edges are added and their ids are returned in the order of
+    /// `edges`, or an error is returned and both the graph and its topological
+    /// order are left unchanged:
+    /// * [`AcyclicEdgeError::Cycles`], with every offending cycle, if the batch
+    ///   would create cycles or self-loops, which makes it possible to fix the
+    ///   whole batch at once.
+    /// * [`AcyclicEdgeError::InvalidEdge`] if the graph does not allow parallel
+    ///   edges, like `DiGraphMap` and `MatrixGraph`, and one of the edges already
+    ///   exists in the graph or appears more than once in the batch. On
+    ///   [`DiGraph`] and [`StableDiGraph`], such edges are added as parallel
+    ///   edges, as with [`Self::try_add_edge`].
+    ///
+    /// This is much faster than repeated calls to [`Self::try_add_edge`] on
+    /// large batches: the nodes between the first and the last position touched
+    /// by the edges that do not fit the current order are sorted once.
+    ///
+    /// **Panics** if any of the endpoints is not found.
+    #[track_caller]
+    pub fn try_add_edges<I>(
+        &mut self,
+        edges: I,
+    ) -> Result<Vec<G::EdgeId>, AcyclicEdgeError<G::NodeId>>
     where
-        G::NodeId: IndexType,
+        I: IntoIterator<Item = (G::NodeId, G::NodeId, G::EdgeWeight)>,
+        G: Build + private::EdgeStorage,
     {
+        let (endpoints, weights): (Vec<_>, Vec<_>) = edges
+            .into_iter()
+            .map(|(a, b, weight)| ((a, b), weight))
+            .unzip();
+
+        if !G::PARALLEL_EDGES {
+            // Check that every edge can be stored before touching the graph or the order.
+            let mut pairs: Vec<_> = endpoints
+                .iter()
+                .map(|&(a, b)| (self.graph.to_index(a), self.graph.to_index(b)))
+                .collect();
+            pairs.sort_unstable();
+            let repeated = pairs.windows(2).any(|w| w[0] == w[1]);
+            if repeated
+                || endpoints
+                    .iter()
+                    .any(|&(a, b)| self.graph.contains_edge(a, b))
+            {
+                return Err(AcyclicEdgeError::InvalidEdge);
+            }
+        }
+
+        self.update_ordering_batch(&endpoints)
+            .map_err(AcyclicEdgeError::Cycles)?;
+        endpoints
+            .into_iter()
+            .zip(weights)
+            .map(|((a, b), weight)| {
+                self.graph
+                    .add_edge(a, b, weight)
+                    .ok_or(AcyclicEdgeError::InvalidEdge)
+            })
+            .collect()
+    }
+
+    /// Check if an edge would be valid, i.e. adding it would not create a cycle.
+    ///
+    /// **Panics** if `a` or `b` are not found.
+    pub fn is_valid_edge(&self, a: G::NodeId, b: G::NodeId) -> bool {
         if a == b {
             false // No self-loops
         } else if self.get_position(a) < self.get_position(b) {
@@ -242,6 +320,130 @@ where
         }
     }
 
+    /// Check if there is a path from `a` to `b`, which is always the case if `a == b`.
+    ///
+    /// The search only visits the nodes between `a` and `b` in the topological order,
+    /// and answers without any search if `b` comes before `a`.
+    ///
+    /// **Panics** if `a` or `b` are not found.
+    pub fn is_reachable(&self, a: G::NodeId, b: G::NodeId) -> bool {
+        let target = self.get_position(b);
+        if self.get_position(a) > target {
+            return false;
+        }
+        self.cone(a, Direction::Outgoing, |position| position <= target)
+            .contains_key(&target)
+    }
+
+    /// Return the nodes that have a path to `n`, excluding `n`, in topological order.
+    ///
+    /// **Panics** if `n` is not found.
+    pub fn ancestors(&self, n: G::NodeId) -> Vec<G::NodeId> {
+        let mut cone = self.cone(n, Direction::Incoming, |_| true);
+        cone.remove(&self.get_position(n));
+        cone.into_values().collect()
+    }
+
+    /// Return the nodes that `n` has a path to, excluding `n`, in topological order.
+    ///
+    /// **Panics** if `n` is not found.
+    pub fn descendants(&self, n: G::NodeId) -> Vec<G::NodeId> {
+        let mut cone = self.cone(n, Direction::Outgoing, |_| true);
+        cone.remove(&self.get_position(n));
+        cone.into_values().collect()
+    }
+
+    /// Return the lowest common ancestors of `a` and `b`, in topological order.
+    ///
+    /// A common ancestor is a node that has a path to both `a` and `b`, counting each node
+    /// as an ancestor of itself. It is lowest if none of its descendants is a common
+    /// ancestor. A directed acyclic graph may have several lowest common ancestors, or none.
+    ///
+    /// The search of the ancestors of the node that comes last in the topological order
+    /// stops at the position of the first ancestor of the other node.
+    ///
+    /// **Panics** if `a` or `b` are not found.
+    pub fn lowest_common_ancestors(&self, a: G::NodeId, b: G::NodeId) -> Vec<G::NodeId> {
+        let (first, last) = if self.get_position(a) <= self.get_position(b) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let first_ancestors = self.cone(first, Direction::Incoming, |_| true);
+        let lowest = match first_ancestors.keys().next() {
+            Some(&position) => position,
+            None => return Vec::new(),
+        };
+        let last_ancestors = self.cone(last, Direction::Incoming, |position| position >= lowest);
+
+        // A common ancestor with a common ancestor as descendant also has one as child.
+        let is_common = |position| {
+            first_ancestors.contains_key(&position) && last_ancestors.contains_key(&position)
+        };
+        first_ancestors
+            .iter()
+            .filter(|&(&position, _)| is_common(position))
+            .filter(|&(_, &node)| {
+                self.graph
+                    .neighbors_directed(node, Direction::Outgoing)
+                    .all(|child| !is_common(self.get_position(child)))
+            })
+            .map(|(_, &node)| node)
+            .collect()
+    }
+
+    /// Collect the nodes that can be reached from `start` following the edges in the
+    /// given direction, without going through the nodes whose position is not `within`
+    /// the searched range.
+    fn cone(
+        &self,
+        start: G::NodeId,
+        direction: Direction,
+        mut within: impl FnMut(TopologicalPosition) -> bool,
+    ) -> BTreeMap<TopologicalPosition, G::NodeId> {
+        debug_assert!(self.discovered.borrow().is_clear());
+        debug_assert!(self.finished.borrow().is_clear());
+
+        // Prepare DFS scratch space: make sure the maps have enough capacity
+        if self.discovered.borrow().len() < self.graph.node_bound() {
+            self.discovered.borrow_mut().grow(self.graph.node_bound());
+            self.finished.borrow_mut().grow(self.graph.node_bound());
+        }
+
+        let mut cone = BTreeMap::new();
+        let valid_order = |order| Ok(within(order));
+        let discovered = &mut self.discovered.borrow_mut();
+        let finished = &mut self.finished.borrow_mut();
+        let result = match direction {
+            Direction::Outgoing => dfs(
+                &self.graph,
+                start,
+                &self.order_map,
+                valid_order,
+                &mut cone,
+                discovered,
+                finished,
+            ),
+            Direction::Incoming => dfs(
+                Reversed(&self.graph),
+                start,
+                &self.order_map,
+                valid_order,
+                &mut cone,
+                discovered,
+                finished,
+            ),
+        };
+        debug_assert!(result.is_ok());
+
+        // Cleanup: reset the visited nodes only.
+        for &v in cone.values() {
+            discovered.set(self.graph.to_index(v), false);
+            finished.set(self.graph.to_index(v), false);
+        }
+        cone
+    }
+
     /// Update the ordering of the nodes in the order map resulting from adding an
     /// edge a -> b.
     ///
@@ -249,10 +451,7 @@ where
     ///
     /// Implements the core update logic of the PK algorithm.
     #[track_caller]
-    fn update_ordering(&mut self, a: G::NodeId, b: G::NodeId) -> Result<(), Cycle<G::NodeId>>
-    where
-        G::NodeId: IndexType,
-    {
+    fn update_ordering(&mut self, a: G::NodeId, b: G::NodeId) -> Result<(), Cycle<G::NodeId>> {
         let min_order = self.get_position(b);
         let max_order = self.get_position(a);
         if min_order >= max_order {
@@ -278,6 +477,86 @@ where
         Ok(())
     }
 
+    /// Update the ordering of the nodes in the order map resulting from adding
+    /// all the edges a -> b in `edges`.
+    ///
+    /// Only the nodes between the first target and the last source of the edges
+    /// that go against the current order are reordered, following the strongly
+    /// connected components of the subgraph they induce together with the new
+    /// edges. If cycles are found, one node of each of them is returned and
+    /// `self` remains unchanged.
+    #[track_caller]
+    fn update_ordering_batch(
+        &mut self,
+        edges: &[(G::NodeId, G::NodeId)],
+    ) -> Result<(), Vec<Cycle<G::NodeId>>> {
+        let mut cycles = Vec::new();
+        let mut reordered_range = None;
+        for &(a, b) in edges {
+            let (a_position, b_position) = (self.get_position(a), self.get_position(b));
+            if a == b {
+                cycles.push((a_position, a));
+            } else if b_position < a_position {
+                reordered_range = Some(match reordered_range {
+                    Some((min_order, max_order)) => {
+                        (b_position.min(min_order), a_position.max(max_order))
+                    }
+                    None => (b_position, a_position),
+                });
+            }
+        }
+
+        if let Some((min_order, max_order)) = reordered_range {
+            let nodes: Vec<_> = self.order_map.range(min_order..=max_order).collect();
+            let positions: Vec<_> = nodes.iter().map(|&n| self.get_position(n)).collect();
+            let local_index = |position| positions.binary_search(&position).ok();
+
+            // The edges that end outside of the range are satisfied by any order
+            // of the range, so that only the induced subgraph matters.
+            let mut subgraph = DiGraph::<(), (), usize>::with_capacity(nodes.len(), 0);
+            for _ in &nodes {
+                subgraph.add_node(());
+            }
+            for (i, &u) in nodes.iter().enumerate() {
+                for v in self.graph.neighbors_directed(u, Direction::Outgoing) {
+                    if let Some(j) = local_index(self.get_position(v)) {
+                        subgraph.add_edge(NodeIndex::new(i), NodeIndex::new(j), ());
+                    }
+                }
+            }
+            for &(a, b) in edges {
+                let (i, j) = (self.get_position(a), self.get_position(b));
+                if let (Some(i), Some(j)) = (local_index(i), local_index(j)) {
+                    subgraph.add_edge(NodeIndex::new(i), NodeIndex::new(j), ());
+                }
+            }
+
+            let components = tarjan_scc(&subgraph);
+            for component in components.iter().filter(|component| component.len() > 1) {
+                let i = component.iter().min().unwrap().index();
+                cycles.push((positions[i], nodes[i]));
+            }
+            if cycles.is_empty() {
+                // The components are returned in reverse topological order.
+                let new_order = components
+                    .iter()
+                    .rev()
+                    .map(|component| nodes[component[0].index()]);
+                for (&position, node) in positions.iter().zip(new_order) {
+                    self.order_map.set_position(node, position, &self.graph);
+                }
+            }
+        }
+
+        if cycles.is_empty() {
+            Ok(())
+        } else {
+            cycles.sort_by_key(|&(position, _)| position);
+            cycles.dedup_by_key(|&mut (position, _)| position);
+            Err(cycles.into_iter().map(|(_, n)| Cycle(n)).collect())
+        }
+    }
+
     /// Use DFS to find the future causal cone of `min_node` and the past causal
     /// cone of `max_node`.
     ///
@@ -297,10 +576,7 @@ where
             BTreeMap<TopologicalPosition, G::NodeId>,
         ),
         Cycle<G::NodeId>,
-    >
-    where
-        G::NodeId: IndexType,
-    {
+    > {
         debug_assert!(self.discovered.borrow().is_clear());
         debug_assert!(self.finished.borrow().is_clear());
 
@@ -326,8 +602,12 @@ where
             // Get all nodes that can reach a with min_order < order <= max_order
             // These are disjoint from the nodes in the forward cone, otherwise
             // we would have a cycle.
-            self.past_cone(max_node, min_order, max_order, &mut backward_cone)
-                .expect("cycles already checked in future_cone");
+            if self
+                .past_cone(max_node, min_order, max_order, &mut backward_cone)
+                .is_err()
+            {
+                unreachable!("cycles already checked in future_cone");
+            }
 
             Ok(())
         };
@@ -337,8 +617,9 @@ where
         // Cleanup: reset map to 0. This is faster than a full reset, especially
         // on large sparse graphs.
         for &v in forward_cone.values().chain(backward_cone.values()) {
-            self.discovered.borrow_mut().set(v.index(), false);
-            self.finished.borrow_mut().set(v.index(), false);
+            let i = self.graph.to_index(v);
+            self.discovered.borrow_mut().set(i, false);
+            self.finished.borrow_mut().set(i, false);
         }
         debug_assert!(self.discovered.borrow().is_clear());
         debug_assert!(self.finished.borrow().is_clear());
@@ -355,10 +636,7 @@ where
         min_position: TopologicalPosition,
         max_position: TopologicalPosition,
         res: &mut BTreeMap<TopologicalPosition, G::NodeId>,
-    ) -> Result<(), Cycle<G::NodeId>>
-    where
-        G::NodeId: IndexType,
-    {
+    ) -> Result<(), Cycle<G::NodeId>> {
         dfs(
             &self.graph,
             start,
@@ -383,10 +661,7 @@ where
         min_position: TopologicalPosition,
         max_position: TopologicalPosition,
         res: &mut BTreeMap<TopologicalPosition, G::NodeId>,
-    ) -> Result<(), Cycle<G::NodeId>>
-    where
-        G::NodeId: IndexType,
-    {
+    ) -> Result<(), Cycle<G::NodeId>> {
         dfs(
             Reversed(&self.graph),
             start,
@@ -432,11 +707,13 @@ where
         + IntoNodeIdentifiers
         + Visitable<Map = G::Map>
         + GraphBase<NodeId = G::NodeId>,
-    G::NodeId: IndexType,
 {
     fn add_node(&mut self, weight: Self::NodeWeight) -> Self::NodeId {
         let n = self.graph.add_node(weight);
-        self.order_map.add_node(n, &self.graph);
+        // Graphs keyed by node weight, such as `GraphMap`, may return an existing node.
+        if !self.order_map.contains(n, &self.graph) {
+            self.order_map.add_node(n, &self.graph);
+        }
         n
     }
 
@@ -455,7 +732,11 @@ where
         b: Self::NodeId,
         weight: Self::EdgeWeight,
     ) -> Self::EdgeId {
-        self.try_update_edge(a, b, weight).unwrap()
+        match self.try_update_edge(a, b, weight) {
+            Ok(edge) => edge,
+            Err(AcyclicEdgeError::SelfLoop) => panic!("the edge would create a self-loop"),
+            Err(_) => panic!("the edge would create a cycle"),
+        }
     }
 }
 
@@ -465,7 +746,6 @@ where
         + IntoNodeIdentifiers
         + Visitable<Map = G::Map>
         + GraphBase<NodeId = G::NodeId>,
-    G::NodeId: IndexType,
 {
     fn with_capacity(nodes: usize, edges: usize) -> Self {
         let graph = G::with_capacity(nodes, edges);
@@ -501,10 +781,7 @@ fn dfs<G: NodeIndexable + IntoNeighborsDirected + IntoNodeIdentifiers + Visitabl
     res: &mut BTreeMap<TopologicalPosition, G::NodeId>,
     discovered: &mut FixedBitSet,
     finished: &mut FixedBitSet,
-) -> Result<(), Cycle<G::NodeId>>
-where
-    G::NodeId: IndexType,
-{
+) -> Result<(), Cycle<G::NodeId>> {
     dfs_visitor(
         graph,
         start,
@@ -528,14 +805,37 @@ where
                 _ => Ok(Control::Continue),
             }
         },
-        discovered,
-        finished,
+        &mut IndexedBitSet(graph, discovered),
+        &mut IndexedBitSet(graph, finished),
         &mut Time::default(),
     )?;
 
     Ok(())
 }
 
+/// A [`FixedBitSet`] used as a visit map through [`NodeIndexable::to_index`],
+/// for node ids that are not indices themselves, as in [`GraphMap`].
+///
+/// [`GraphMap`]: crate::graphmap::GraphMap
+struct IndexedBitSet<'b, G>(G, &'b mut FixedBitSet);
+
+impl<G: NodeIndexable> VisitMap<G::NodeId> for IndexedBitSet<'_, G> {
+    fn visit(&mut self, a: G::NodeId) -> bool {
+        !self.1.put(self.0.to_index(a))
+    }
+
+    fn is_visited(&self, a: &G::NodeId) -> bool {
+        self.1.contains(self.0.to_index(*a))
+    }
+
+    fn unvisit(&mut self, a: G::NodeId) -> bool {
+        let i = self.0.to_index(a);
+        let visited = self.1.contains(i);
+        self.1.set(i, false);
+        visited
+    }
+}
+
 /////////////////////// Pass-through graph traits ///////////////////////
 // We implement all the following traits by delegating to the inner graph:
 // - Data
@@ -551,8 +851,9 @@ where
 // - Visitable
 //
 // Furthermore, we also implement the `remove_node` and `remove_edge` methods,
-// as well as the following traits for `DiGraph` and `StableDiGraph` (these
-// are hard/impossible to implement generically):
+// as well as the following traits for `DiGraph`, `StableDiGraph`, `DiGraphMap`
+// and directed `MatrixGraph` (these are hard/impossible to implement
+// generically):
 // - TryFrom
 // - IntoEdgeReferences
 // - IntoEdges
@@ -657,7 +958,20 @@ impl<G: Visitable> Visitable for Acyclic<G> {
     }
 }
 
-macro_rules! impl_graph_traits {
+mod private {
+    use crate::visit::GraphBase;
+
+    /// How a graph type stores its edges, for [`Acyclic::try_add_edges`](super::Acyclic::try_add_edges).
+    pub trait EdgeStorage: GraphBase {
+        /// Whether the graph can store several edges from a node to another.
+        const PARALLEL_EDGES: bool;
+
+        /// Return `true` if there is an edge from `a` to `b`.
+        fn contains_edge(&self, a: Self::NodeId, b: Self::NodeId) -> bool;
+    }
+}
+
+macro_rules! impl_remove_methods {
     ($graph_type:ident) => {
         // Remove edge and node methods (not available through traits)
         impl<N, E, Ix: IndexType> Acyclic<$graph_type<N, E, Ix>> {
@@ -685,12 +999,22 @@ macro_rules! impl_graph_traits {
             }
         }
 
-        impl<N, E, Ix: IndexType> TryFrom<$graph_type<N, E, Ix>>
-            for Acyclic<$graph_type<N, E, Ix>>
-        {
-            type Error = Cycle<NodeIndex<Ix>>;
+        impl<N, E, Ix: IndexType> private::EdgeStorage for $graph_type<N, E, Ix> {
+            const PARALLEL_EDGES: bool = true;
+
+            fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+                self.contains_edge(a, b)
+            }
+        }
+    };
+}
+
+macro_rules! impl_graph_traits {
+    ([$($generics:tt)*] $graph_type:ty) => {
+        impl<$($generics)*> TryFrom<$graph_type> for Acyclic<$graph_type> {
+            type Error = Cycle<<$graph_type as GraphBase>::NodeId>;
 
-            fn try_from(graph: $graph_type<N, E, Ix>) -> Result<Self, Self::Error> {
+            fn try_from(graph: $graph_type) -> Result<Self, Self::Error> {
                 let order_map = OrderMap::try_from_graph(&graph)?;
                 let discovered = RefCell::new(FixedBitSet::with_capacity(graph.node_bound()));
                 let finished = RefCell::new(FixedBitSet::with_capacity(graph.node_bound()));
@@ -703,60 +1027,60 @@ macro_rules! impl_graph_traits {
             }
         }
 
-        impl<'a, N, E, Ix: IndexType> IntoEdgeReferences for &'a Acyclic<$graph_type<N, E, Ix>> {
-            type EdgeRef = <&'a $graph_type<N, E, Ix> as IntoEdgeReferences>::EdgeRef;
-            type EdgeReferences = <&'a $graph_type<N, E, Ix> as IntoEdgeReferences>::EdgeReferences;
+        impl<'a, $($generics)*> IntoEdgeReferences for &'a Acyclic<$graph_type> {
+            type EdgeRef = <&'a $graph_type as IntoEdgeReferences>::EdgeRef;
+            type EdgeReferences = <&'a $graph_type as IntoEdgeReferences>::EdgeReferences;
 
             fn edge_references(self) -> Self::EdgeReferences {
                 self.inner().edge_references()
             }
         }
 
-        impl<'a, N, E, Ix: IndexType> IntoEdges for &'a Acyclic<$graph_type<N, E, Ix>> {
-            type Edges = <&'a $graph_type<N, E, Ix> as IntoEdges>::Edges;
+        impl<'a, $($generics)*> IntoEdges for &'a Acyclic<$graph_type> {
+            type Edges = <&'a $graph_type as IntoEdges>::Edges;
 
             fn edges(self, a: Self::NodeId) -> Self::Edges {
                 self.inner().edges(a)
             }
         }
 
-        impl<'a, N, E, Ix: IndexType> IntoEdgesDirected for &'a Acyclic<$graph_type<N, E, Ix>> {
-            type EdgesDirected = <&'a $graph_type<N, E, Ix> as IntoEdgesDirected>::EdgesDirected;
+        impl<'a, $($generics)*> IntoEdgesDirected for &'a Acyclic<$graph_type> {
+            type EdgesDirected = <&'a $graph_type as IntoEdgesDirected>::EdgesDirected;
 
             fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
                 self.inner().edges_directed(a, dir)
             }
         }
 
-        impl<'a, N, E, Ix: IndexType> IntoNeighbors for &'a Acyclic<$graph_type<N, E, Ix>> {
-            type Neighbors = <&'a $graph_type<N, E, Ix> as IntoNeighbors>::Neighbors;
+        impl<'a, $($generics)*> IntoNeighbors for &'a Acyclic<$graph_type> {
+            type Neighbors = <&'a $graph_type as IntoNeighbors>::Neighbors;
 
             fn neighbors(self, a: Self::NodeId) -> Self::Neighbors {
                 self.inner().neighbors(a)
             }
         }
 
-        impl<'a, N, E, Ix: IndexType> IntoNeighborsDirected for &'a Acyclic<$graph_type<N, E, Ix>> {
+        impl<'a, $($generics)*> IntoNeighborsDirected for &'a Acyclic<$graph_type> {
             type NeighborsDirected =
-                <&'a $graph_type<N, E, Ix> as IntoNeighborsDirected>::NeighborsDirected;
+                <&'a $graph_type as IntoNeighborsDirected>::NeighborsDirected;
 
             fn neighbors_directed(self, n: Self::NodeId, d: Direction) -> Self::NeighborsDirected {
                 self.inner().neighbors_directed(n, d)
             }
         }
 
-        impl<'a, N, E, Ix: IndexType> IntoNodeIdentifiers for &'a Acyclic<$graph_type<N, E, Ix>> {
+        impl<'a, $($generics)*> IntoNodeIdentifiers for &'a Acyclic<$graph_type> {
             type NodeIdentifiers =
-                <&'a $graph_type<N, E, Ix> as IntoNodeIdentifiers>::NodeIdentifiers;
+                <&'a $graph_type as IntoNodeIdentifiers>::NodeIdentifiers;
 
             fn node_identifiers(self) -> Self::NodeIdentifiers {
                 self.inner().node_identifiers()
             }
         }
 
-        impl<'a, N, E, Ix: IndexType> IntoNodeReferences for &'a Acyclic<$graph_type<N, E, Ix>> {
-            type NodeRef = <&'a $graph_type<N, E, Ix> as IntoNodeReferences>::NodeRef;
-            type NodeReferences = <&'a $graph_type<N, E, Ix> as IntoNodeReferences>::NodeReferences;
+        impl<'a, $($generics)*> IntoNodeReferences for &'a Acyclic<$graph_type> {
+            type NodeRef = <&'a $graph_type as IntoNodeReferences>::NodeRef;
+            type NodeReferences = <&'a $graph_type as IntoNodeReferences>::NodeReferences;
 
             fn node_references(self) -> Self::NodeReferences {
                 self.inner().node_references()
@@ -765,9 +1089,102 @@ macro_rules! impl_graph_traits {
     };
 }
 
-impl_graph_traits!(DiGraph);
+impl_remove_methods!(DiGraph);
+impl_graph_traits!([N, E, Ix: IndexType] DiGraph<N, E, Ix>);
+
 #[cfg(feature = "stable_graph")]
-impl_graph_traits!(StableDiGraph);
+impl_remove_methods!(StableDiGraph);
+#[cfg(feature = "stable_graph")]
+impl_graph_traits!([N, E, Ix: IndexType] StableDiGraph<N, E, Ix>);
+
+#[cfg(feature = "graphmap")]
+impl<N: NodeTrait, E, S: BuildHasher> Acyclic<GraphMap<N, E, Directed, S>> {
+    /// Remove the edge from `a` to `b` and return its edge weight, or None if
+    /// it didn't exist.
+    ///
+    /// Pass through to underlying graph.
+    pub fn remove_edge(&mut self, a: N, b: N) -> Option<E> {
+        self.graph.remove_edge(a, b)
+    }
+
+    /// Remove a node from the graph and return `true` if it existed.
+    ///
+    /// This updates the order in O(v) runtime and removes the node in
+    /// the underlying graph.
+    pub fn remove_node(&mut self, n: N) -> bool {
+        if !self.graph.contains_node(n) {
+            return false;
+        }
+        // The graph moves its last node to the index of the removed one.
+        let last = NodeIndexable::from_index(&self.graph, self.graph.node_count() - 1);
+        let last_position = self.get_position(last);
+        self.order_map.remove_node(n, &self.graph);
+        self.graph.remove_node(n);
+        if last != n {
+            self.order_map
+                .set_position(last, last_position, &self.graph);
+        }
+        true
+    }
+}
+
+#[cfg(feature = "graphmap")]
+impl<N: NodeTrait, E, S: BuildHasher> private::EdgeStorage for GraphMap<N, E, Directed, S> {
+    const PARALLEL_EDGES: bool = false;
+
+    fn contains_edge(&self, a: N, b: N) -> bool {
+        self.contains_edge(a, b)
+    }
+}
+
+#[cfg(feature = "graphmap")]
+impl_graph_traits!([N: NodeTrait, E, S: BuildHasher] GraphMap<N, E, Directed, S>);
+
+#[cfg(feature = "matrix_graph")]
+impl<N, E, S: BuildHasher, Null: Nullable<Wrapped = E>, Ix: IndexType>
+    Acyclic<MatrixGraph<N, E, S, Directed, Null, Ix>>
+{
+    /// Remove the edge from `a` to `b` and return its edge weight.
+    ///
+    /// Pass through to underlying graph.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    /// **Panics** if no edge exists between `a` and `b`.
+    #[track_caller]
+    pub fn remove_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> E {
+        self.graph.remove_edge(a, b)
+    }
+
+    /// Remove a node from the graph and return its weight.
+    ///
+    /// This updates the order in O(v) runtime and removes the node in
+    /// the underlying graph.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> N {
+        let weight = self.graph.remove_node(a);
+        self.order_map.remove_node(a, &self.graph);
+        weight
+    }
+}
+
+#[cfg(feature = "matrix_graph")]
+impl<N, E, S: BuildHasher, Null: Nullable<Wrapped = E>, Ix: IndexType> private::EdgeStorage
+    for MatrixGraph<N, E, S, Directed, Null, Ix>
+{
+    const PARALLEL_EDGES: bool = false;
+
+    fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.has_edge(a, b)
+    }
+}
+
+#[cfg(feature = "matrix_graph")]
+impl_graph_traits!(
+    [N, E, S: BuildHasher, Null: Nullable<Wrapped = E>, Ix: IndexType]
+    MatrixGraph<N, E, S, Directed, Null, Ix>
+);
 
 #[cfg(test)]
 mod tests {
@@ -849,6 +1266,217 @@ mod tests {
         assert!(!acyclic.inner().contains_edge(a, c));
     }
 
+    #[test]
+    fn test_reachability_queries() {
+        // a -> b -> d, a -> c -> d, d -> e, and an isolated node f. The nodes are added in
+        // an order that differs from the topological one.
+        let mut acyclic = Acyclic::<DiGraph<(), ()>>::new();
+        let e = acyclic.add_node(());
+        let d = acyclic.add_node(());
+        let c = acyclic.add_node(());
+        let b = acyclic.add_node(());
+        let a = acyclic.add_node(());
+        let f = acyclic.add_node(());
+        for (x, y) in [(a, b), (a, c), (b, d), (c, d), (d, e)] {
+            acyclic.try_add_edge(x, y, ()).unwrap();
+        }
+
+        assert!(acyclic.is_reachable(a, e));
+        assert!(acyclic.is_reachable(c, c));
+        assert!(!acyclic.is_reachable(b, c));
+        assert!(!acyclic.is_reachable(e, a));
+        assert!(!acyclic.is_reachable(f, a));
+
+        assert_eq!(acyclic.ancestors(a), []);
+        assert_eq!(acyclic.descendants(e), []);
+        let ancestors = acyclic.ancestors(e);
+        assert_eq!(ancestors.len(), 4);
+        assert_eq!((ancestors[0], ancestors[3]), (a, d));
+        let descendants = acyclic.descendants(a);
+        assert_eq!(descendants.len(), 4);
+        assert_eq!((descendants[2], descendants[3]), (d, e));
+
+        assert_eq!(acyclic.lowest_common_ancestors(d, e), [d]);
+        assert_eq!(acyclic.lowest_common_ancestors(b, c), [a]);
+        assert_eq!(acyclic.lowest_common_ancestors(e, f), []);
+
+        // With a second root, `b` and `c` have two lowest common ancestors.
+        let g = acyclic.add_node(());
+        acyclic.try_add_edge(g, b, ()).unwrap();
+        acyclic.try_add_edge(g, c, ()).unwrap();
+        let mut lcas = acyclic.lowest_common_ancestors(c, b);
+        lcas.sort();
+        assert_eq!(lcas, [a, g]);
+        assert_eq!(acyclic.lowest_common_ancestors(e, b), [b]);
+    }
+
+    #[test]
+    fn test_try_add_edges() {
+        let mut acyclic = Acyclic::<DiGraph<(), ()>>::new();
+        let nodes: Vec<_> = (0..10).map(|_| acyclic.add_node(())).collect();
+        acyclic.try_add_edge(nodes[4], nodes[5], ()).unwrap();
+
+        // A chain against the current order, 4 -> 5 -> 3 -> 2 -> 1 -> 0.
+        let chain = (0..4).map(|i| (nodes[5 - i], nodes[3 - i], ()));
+        let edges = acyclic.try_add_edges(chain).unwrap();
+        assert_eq!(edges.len(), 4);
+        assert_eq!(acyclic.edge_count(), 5);
+        assert_valid_topological_order(&acyclic);
+        assert!(acyclic.is_reachable(nodes[4], nodes[0]));
+
+        // Three cycles: one through the chain, one in the batch only and a self-loop.
+        let order: Vec<_> = acyclic.nodes_iter().collect();
+        let batch = [(0, 6), (6, 4), (8, 9), (9, 8), (2, 2)];
+        let result = acyclic.try_add_edges(batch.iter().map(|&(a, b)| (nodes[a], nodes[b], ())));
+        let mut cycle_nodes: Vec<_> = match result {
+            Err(AcyclicEdgeError::Cycles(cycles)) => {
+                cycles.into_iter().map(|cycle| cycle.node_id()).collect()
+            }
+            _ => panic!("expected cycles, got {:?}", result),
+        };
+        cycle_nodes.sort();
+        assert_eq!(cycle_nodes, [nodes[2], nodes[4], nodes[8]]);
+
+        // The graph and its order are left unchanged.
+        assert_eq!(acyclic.edge_count(), 5);
+        assert!(acyclic.nodes_iter().eq(order));
+        assert!(acyclic.try_add_edges([]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_try_add_edges_parallel() {
+        let mut acyclic = Acyclic::<DiGraph<(), ()>>::new();
+        let nodes: Vec<_> = (0..3).map(|_| acyclic.add_node(())).collect();
+        acyclic.try_add_edge(nodes[2], nodes[1], ()).unwrap();
+
+        // An existing edge and a pair repeated within the batch are added as
+        // parallel edges, like with `try_add_edge`.
+        let batch = [(2, 1), (1, 0), (1, 0)];
+        let edges = acyclic
+            .try_add_edges(batch.iter().map(|&(a, b)| (nodes[a], nodes[b], ())))
+            .unwrap();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(acyclic.edge_count(), 4);
+        assert_eq!(acyclic.edges_connecting(nodes[2], nodes[1]).count(), 2);
+        assert_eq!(acyclic.edges_connecting(nodes[1], nodes[0]).count(), 2);
+        assert_valid_topological_order(&acyclic);
+    }
+
+    #[test]
+    #[cfg(feature = "graphmap")]
+    fn test_try_add_edges_invalid() {
+        use crate::prelude::DiGraphMap;
+
+        let mut acyclic = Acyclic::<DiGraphMap<u32, ()>>::new();
+        for n in 0..4 {
+            acyclic.add_node(n);
+        }
+        acyclic.try_add_edge(3, 2, ()).unwrap();
+        let order: Vec<_> = acyclic.nodes_iter().collect();
+
+        // An existing edge, after edges that would reorder the nodes.
+        let result = acyclic.try_add_edges([(2, 1, ()), (1, 0, ()), (3, 2, ())]);
+        assert_eq!(result, Err(AcyclicEdgeError::InvalidEdge));
+        assert_eq!(acyclic.edge_count(), 1);
+        assert!(acyclic.nodes_iter().eq(order.iter().copied()));
+
+        // A pair repeated within the batch.
+        let result = acyclic.try_add_edges([(2, 1, ()), (1, 0, ()), (2, 1, ())]);
+        assert_eq!(result, Err(AcyclicEdgeError::InvalidEdge));
+        assert_eq!(acyclic.edge_count(), 1);
+        assert!(!acyclic.contains_edge(2, 1));
+        assert!(acyclic.nodes_iter().eq(order.iter().copied()));
+
+        let edges = acyclic.try_add_edges([(2, 1, ()), (1, 0, ())]).unwrap();
+        assert_eq!(edges, [(2, 1), (1, 0)]);
+        assert_valid_topological_order(&acyclic);
+    }
+
+    #[test]
+    #[cfg(feature = "graphmap")]
+    fn test_acyclic_graphmap() {
+        use crate::algo::toposort;
+        use crate::prelude::DiGraphMap;
+
+        let mut acyclic = Acyclic::<DiGraphMap<&str, u32>>::new();
+        for task in ["deploy", "test", "build", "fetch"] {
+            acyclic.add_node(task);
+        }
+        // Adding an existing node does not add it twice to the order.
+        acyclic.add_node("test");
+        assert_eq!(acyclic.nodes_iter().count(), 4);
+
+        acyclic.try_add_edge("fetch", "build", 1).unwrap();
+        acyclic.try_add_edge("build", "test", 2).unwrap();
+        acyclic.try_add_edge("test", "deploy", 3).unwrap();
+        assert!(matches!(
+            acyclic.try_add_edge("deploy", "build", 4),
+            Err(AcyclicEdgeError::Cycle(_))
+        ));
+        assert_eq!(
+            acyclic.try_add_edge("fetch", "build", 5),
+            Err(AcyclicEdgeError::InvalidEdge)
+        );
+        assert_valid_topological_order(&acyclic);
+        assert_eq!(
+            toposort(&acyclic, None).unwrap(),
+            ["fetch", "build", "test", "deploy"]
+        );
+        assert!(acyclic.is_reachable("fetch", "deploy"));
+
+        // Removing a node moves the last one to its index in the graph map.
+        assert!(acyclic.remove_node("test"));
+        assert!(!acyclic.remove_node("test"));
+        assert_valid_topological_order(&acyclic);
+        assert!(acyclic.try_add_edge("deploy", "fetch", 6).is_ok());
+        assert_eq!(acyclic.remove_edge("fetch", "build"), Some(1));
+        assert!(acyclic.try_add_edge("build", "deploy", 7).is_ok());
+        assert_eq!(
+            acyclic.nodes_iter().collect::<Vec<_>>(),
+            ["build", "deploy", "fetch"]
+        );
+        assert_eq!(acyclic.edges("deploy").count(), 1);
+
+        let graph = acyclic.into_inner();
+        assert!(Acyclic::try_from(graph.clone()).is_ok());
+        let mut cyclic = graph;
+        cyclic.add_edge("fetch", "build", 8);
+        assert!(Acyclic::try_from(cyclic).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "matrix_graph")]
+    fn test_acyclic_matrix_graph() {
+        use crate::algo::has_path_connecting;
+        use crate::matrix_graph::DiMatrix;
+
+        let mut acyclic = Acyclic::<DiMatrix<(), ()>>::new();
+        let c = acyclic.add_node(());
+        let b = acyclic.add_node(());
+        let a = acyclic.add_node(());
+        acyclic.try_add_edge(a, b, ()).unwrap();
+        acyclic.try_add_edge(b, c, ()).unwrap();
+        assert!(acyclic.try_add_edge(c, a, ()).is_err());
+        assert_eq!(
+            acyclic.try_add_edge(a, b, ()),
+            Err(AcyclicEdgeError::InvalidEdge)
+        );
+        assert_valid_topological_order(&acyclic);
+        assert!(has_path_connecting(&acyclic, a, c, None));
+
+        acyclic.remove_edge(b, c);
+        assert!(acyclic.try_add_edge(c, b, ()).is_ok());
+        acyclic.remove_node(a);
+        assert_valid_topological_order(&acyclic);
+
+        // The index of the removed node is reused.
+        let d = acyclic.add_node(());
+        assert_eq!(d, a);
+        acyclic.try_add_edge(b, d, ()).unwrap();
+        assert_eq!(acyclic.nodes_iter().collect::<Vec<_>>(), [c, b, d]);
+        assert_valid_topological_order(&acyclic);
+    }
+
     fn assert_valid_topological_order<'a, G>(acyclic: &'a Acyclic<G>)
     where
         G: Visitable + NodeCount + NodeIndexable,
@@ -100,6 +100,15 @@ impl<N: Copy> OrderMap<N> {
         self.node_to_pos[idx]
     }
 
+    /// Return whether the node has a position in the order map.
+    pub(super) fn contains(&self, id: N, graph: impl NodeIndexable<NodeId = N>) -> bool
+    where
+        N: PartialEq,
+    {
+        let idx = graph.to_index(id);
+        idx < self.node_to_pos.len() && self.at_position(self.node_to_pos[idx]) == Some(id)
+    }
+
     /// Map a position in the topological order to a node, if it exists.
     pub(super) fn at_position(&self, pos: TopologicalPosition) -> Option<N> {
         self.pos_to_node.get(&pos).copied()
@@ -4,7 +4,7 @@ use core::{fmt, ops::Range};
 
 use fixedbitset::FixedBitSet;
 
-use crate::data::{Build, DataMap, DataMapMut};
+use crate::data::{Build, Create, DataMap, DataMapMut};
 use crate::iter_format::NoPretty;
 use crate::visit::{
     self, EdgeCount, EdgeRef, GetAdjacencyMatrix, IntoEdgeReferences, IntoNeighbors, NodeCount,
@@ -13,6 +13,9 @@ use crate::visit::{
 #[doc(no_inline)]
 pub use crate::graph::{DefaultIx, IndexType};
 
+#[cfg(feature = "serde-1")]
+mod serialization;
+
 /// Adjacency list node index type, a plain integer.
 pub type NodeIndex<Ix = DefaultIx> = Ix;
 
@@ -153,7 +156,7 @@ iterator_wrap! {
 /// graph until it is dropped or [`clear`](#method.clear) is called.
 ///
 /// Space consumption: **O(|E|)**.
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct List<E, Ix = DefaultIx>
 where
     Ix: IndexType,
@@ -161,6 +164,12 @@ where
     suc: Vec<Row<E, Ix>>,
 }
 
+impl<E, Ix: IndexType> Default for List<E, Ix> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<E, Ix: IndexType> List<E, Ix> {
     /// Creates a new, empty adjacency list.
     pub fn new() -> List<E, Ix> {
@@ -374,6 +383,12 @@ impl<E, Ix: IndexType> Build for List<E, Ix> {
     }
 }
 
+impl<E, Ix: IndexType> Create for List<E, Ix> {
+    fn with_capacity(nodes: usize, _edges: usize) -> Self {
+        Self::with_capacity(nodes)
+    }
+}
+
 impl<E, Ix> fmt::Debug for EdgeReferences<'_, E, Ix>
 where
     E: fmt::Debug,
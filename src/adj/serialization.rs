@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{IndexType, List, Row, WSuc};
+use crate::graph_impl::serialization::{invalid_length_err, invalid_node_err};
+use crate::serde_utils::{CollectSeqWithLength, FromDeserialized, IntoSerializable};
+
+/// Serialization representation for List
+/// Keep in sync with deserialization
+///
+/// The serialization format is as follows, in Pseudorust:
+///
+/// List {
+///     successors: [[(Ix, E)]],
+/// }
+///
+/// There is one list of successors per node, in node index order, and the
+/// successors of a node are in edge order, so that edge indices are kept.
+/// On deserialization, every successor must be a valid node index.
+#[derive(Serialize)]
+#[serde(rename = "List")]
+#[serde(bound(serialize = "E: Serialize, Ix: IndexType + Serialize"))]
+pub struct SerList<'a, E: 'a, Ix: 'a + IndexType> {
+    #[serde(serialize_with = "ser_list_successors")]
+    successors: &'a [Row<E, Ix>],
+}
+
+// Deserialization representation for List
+// Keep in sync with serialization
+#[derive(Deserialize)]
+#[serde(rename = "List")]
+#[serde(bound(deserialize = "E: Deserialize<'de>, Ix: Deserialize<'de>"))]
+pub struct DeserList<E, Ix> {
+    successors: Vec<Vec<(Ix, E)>>,
+}
+
+/// The successors of one node, serialized as `(successor, weight)` pairs.
+struct SerRow<'a, E, Ix: IndexType>(&'a [WSuc<E, Ix>]);
+
+impl<'a, E, Ix> Serialize for SerRow<'a, E, Ix>
+where
+    E: Serialize,
+    Ix: IndexType + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq_exact(self.0.iter().map(|wsuc| (wsuc.suc, &wsuc.weight)))
+    }
+}
+
+fn ser_list_successors<S, E, Ix>(rows: &&[Row<E, Ix>], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    E: Serialize,
+    Ix: IndexType + Serialize,
+{
+    serializer.collect_seq_exact(rows.iter().map(|row| SerRow(row)))
+}
+
+impl<'a, E, Ix> IntoSerializable for &'a List<E, Ix>
+where
+    Ix: IndexType,
+{
+    type Output = SerList<'a, E, Ix>;
+    fn into_serializable(self) -> Self::Output {
+        SerList {
+            successors: &self.suc,
+        }
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+impl<E, Ix> Serialize for List<E, Ix>
+where
+    Ix: IndexType + Serialize,
+    E: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.into_serializable().serialize(serializer)
+    }
+}
+
+impl<E, Ix> FromDeserialized for List<E, Ix>
+where
+    Ix: IndexType,
+{
+    type Input = DeserList<E, Ix>;
+    fn from_deserialized<E2>(input: Self::Input) -> Result<Self, E2>
+    where
+        E2: Error,
+    {
+        let node_count = input.successors.len();
+        if node_count >= <Ix as IndexType>::max().index() {
+            Err(invalid_length_err::<Ix, _>("node", node_count))?
+        }
+
+        let mut suc = Vec::with_capacity(node_count);
+        for row in input.successors {
+            let mut wsucs = Vec::with_capacity(row.len());
+            for (successor, weight) in row {
+                if successor.index() >= node_count {
+                    Err(invalid_node_err(successor.index(), node_count))?
+                }
+                wsucs.push(WSuc {
+                    suc: successor,
+                    weight,
+                });
+            }
+            suc.push(wsucs);
+        }
+        Ok(List { suc })
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+impl<'de, E, Ix> Deserialize<'de> for List<E, Ix>
+where
+    Ix: IndexType + Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::from_deserialized(DeserList::deserialize(deserializer)?)
+    }
+}
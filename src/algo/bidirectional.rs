@@ -0,0 +1,377 @@
+//! Bidirectional shortest path algorithms.
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::hash_map::{
+    Entry::{Occupied, Vacant},
+    HashMap,
+};
+
+use crate::algo::Measure;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, IntoEdges, IntoEdgesDirected, Reversed, VisitMap, Visitable};
+use crate::Direction::Outgoing;
+
+/// Bidirectional Dijkstra's shortest path algorithm.
+///
+/// Computes the shortest path from `start` to `finish`, including the total path cost.
+///
+/// Two searches run in alternation: one from `start` following the outgoing edges, and
+/// one from `finish` following the incoming edges (over [`Reversed`]). The search stops
+/// as soon as the sum of the smallest tentative costs of both frontiers is not smaller
+/// than the cost of the best path found so far, which is then known to be optimal. On
+/// point-to-point queries this usually visits far fewer nodes than [`fn@crate::algo::dijkstra`].
+///
+/// The function `edge_cost` should return the cost for a particular edge. Edge costs must be
+/// non-negative.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `start`: the start node.
+/// * `finish`: the finish node.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Some(K, Vec<G::NodeId>)` - the total cost and path from start to finish, if one was found.
+/// * `None` - if such a path was not found.
+///
+/// # Complexity
+/// * Time complexity: **O((|V|+|E|)log(|V|))**.
+/// * Auxiliary space: **O(|V|+|E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::algo::bidirectional_dijkstra;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// let f = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, 2),
+///     (a, d, 4),
+///     (b, c, 1),
+///     (b, f, 7),
+///     (c, e, 5),
+///     (e, f, 1),
+///     (d, e, 1),
+/// ]);
+///
+/// // Graph represented with the weight of each edge
+/// // Edges with '*' are part of the optimal path.
+/// //
+/// //     2       1
+/// // a ----- b ----- c
+/// // | 4*    | 7     |
+/// // d       f       | 5
+/// // | 1*    | 1*    |
+/// // \------ e ------/
+///
+/// let path = bidirectional_dijkstra(&g, a, f, |e| *e.weight());
+/// assert_eq!(path, Some((6, vec![a, d, e, f])));
+/// ```
+pub fn bidirectional_dijkstra<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    finish: G::NodeId,
+    edge_cost: F,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    bidirectional_search(
+        graph,
+        start,
+        finish,
+        edge_cost,
+        |_| K::default(),
+        |_| K::default(),
+        |forward_top, backward_top, best| forward_top + backward_top >= best,
+    )
+}
+
+/// Bidirectional A* shortest path algorithm.
+///
+/// Computes the shortest path from `start` to `finish`, including the total path cost.
+///
+/// Two A* searches run in alternation: one from `start` following the outgoing edges and
+/// guided by `estimate_cost`, and one from `finish` following the incoming edges (over
+/// [`Reversed`]) and guided by `reverse_estimate_cost`. The search stops as soon as the
+/// smallest estimated path cost of either frontier is not smaller than the cost of the
+/// best path found so far.
+///
+/// The function `edge_cost` should return the cost for a particular edge. Edge costs must be
+/// non-negative.
+///
+/// The function `estimate_cost` should return the estimated cost from a particular node to
+/// `finish`, and `reverse_estimate_cost` the estimated cost from `start` to a particular node.
+/// Both must be non-negative and *consistent*: for every edge from `u` to `v`, the estimate of
+/// `u` must not exceed the edge cost plus the estimate of `v` (and conversely for the reverse
+/// estimate). Consistency is a stronger requirement than the admissibility required by
+/// [`fn@crate::algo::astar`], and any distance metric satisfies it.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `start`: the start node.
+/// * `finish`: the finish node.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+/// * `estimate_cost`: closure that returns the estimated cost to `finish` for particular node.
+/// * `reverse_estimate_cost`: closure that returns the estimated cost from `start` for
+///   particular node.
+///
+/// # Returns
+/// * `Some(K, Vec<G::NodeId>)` - the total cost and path from start to finish, if one was found.
+/// * `None` - if such a path was not found.
+///
+/// # Complexity
+/// The time complexity largely depends on the heuristics used.
+///
+/// With trivial heuristics, the algorithm explores the same nodes as
+/// [`bidirectional_dijkstra`], but may stop later.
+///
+/// # Example
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::algo::bidirectional_astar;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node((0, 0));
+/// let b = g.add_node((2, 0));
+/// let c = g.add_node((1, 1));
+/// let d = g.add_node((2, 2));
+/// let e = g.add_node((3, 3));
+/// let f = g.add_node((4, 2));
+/// g.extend_with_edges(&[
+///     (a, b, 2),
+///     (a, d, 4),
+///     (b, c, 1),
+///     (b, f, 7),
+///     (c, e, 5),
+///     (e, f, 1),
+///     (d, e, 1),
+/// ]);
+///
+/// // Graph represented with the weight of each edge
+/// // Edges with '*' are part of the optimal path.
+/// //
+/// //     2       1
+/// // a ----- b ----- c
+/// // | 4*    | 7     |
+/// // d       f       | 5
+/// // | 1*    | 1*    |
+/// // \------ e ------/
+///
+/// // Chebyshev distances never exceed the edge costs between two positions.
+/// let distance = |(x1, y1): (i32, i32), (x2, y2): (i32, i32)| (x1 - x2).abs().max((y1 - y2).abs());
+/// let path = bidirectional_astar(
+///     &g,
+///     a,
+///     f,
+///     |e| *e.weight(),
+///     |n| distance(g[n], g[f]),
+///     |n| distance(g[a], g[n]),
+/// );
+/// assert_eq!(path, Some((6, vec![a, d, e, f])));
+/// ```
+pub fn bidirectional_astar<G, F, H, R, K>(
+    graph: G,
+    start: G::NodeId,
+    finish: G::NodeId,
+    edge_cost: F,
+    estimate_cost: H,
+    reverse_estimate_cost: R,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    H: FnMut(G::NodeId) -> K,
+    R: FnMut(G::NodeId) -> K,
+    K: Measure + Copy,
+{
+    bidirectional_search(
+        graph,
+        start,
+        finish,
+        edge_cost,
+        estimate_cost,
+        reverse_estimate_cost,
+        |forward_top, backward_top, best| forward_top >= best || backward_top >= best,
+    )
+}
+
+/// State of one direction of a bidirectional search.
+struct SearchSide<N, K, VM> {
+    visited: VM,
+    scores: HashMap<N, K>,
+    predecessors: HashMap<N, N>,
+    visit_next: BinaryHeap<MinScored<K, N>>,
+}
+
+impl<N, K, VM> SearchSide<N, K, VM>
+where
+    N: Copy + Eq + Hash,
+    K: Measure + Copy,
+    VM: VisitMap<N>,
+{
+    fn new(visited: VM, origin: N, estimate: K) -> Self {
+        let mut scores = HashMap::new();
+        scores.insert(origin, K::default());
+        let mut visit_next = BinaryHeap::new();
+        visit_next.push(MinScored(estimate, origin));
+        SearchSide {
+            visited,
+            scores,
+            predecessors: HashMap::new(),
+            visit_next,
+        }
+    }
+
+    /// Pop the next node to expand and return it together with its final score.
+    fn pop(&mut self) -> Option<(N, K)> {
+        let MinScored(_, node) = self.visit_next.pop()?;
+        if !self.visited.visit(node) {
+            return None;
+        }
+        Some((node, self.scores[&node]))
+    }
+
+    /// Relax the edge from `node` to `next` and record a new best path if
+    /// `next` has already been reached by the `other` side.
+    fn relax<H>(
+        &mut self,
+        node: N,
+        next: N,
+        next_score: K,
+        estimate_cost: &mut H,
+        other: &Self,
+        best: &mut Option<(K, N)>,
+    ) where
+        H: FnMut(N) -> K,
+    {
+        if self.visited.is_visited(&next) {
+            return;
+        }
+        match self.scores.entry(next) {
+            Occupied(mut entry) => {
+                if *entry.get() <= next_score {
+                    return;
+                }
+                entry.insert(next_score);
+            }
+            Vacant(entry) => {
+                entry.insert(next_score);
+            }
+        }
+        self.predecessors.insert(next, node);
+        self.visit_next
+            .push(MinScored(next_score + estimate_cost(next), next));
+
+        if let Some(&other_score) = other.scores.get(&next) {
+            let total = next_score + other_score;
+            if best.map_or(true, |(best_score, _)| total < best_score) {
+                *best = Some((total, next));
+            }
+        }
+    }
+}
+
+fn bidirectional_search<G, F, H, R, K, S>(
+    graph: G,
+    start: G::NodeId,
+    finish: G::NodeId,
+    mut edge_cost: F,
+    mut estimate_cost: H,
+    mut reverse_estimate_cost: R,
+    mut is_done: S,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    H: FnMut(G::NodeId) -> K,
+    R: FnMut(G::NodeId) -> K,
+    K: Measure + Copy,
+    S: FnMut(K, K, K) -> bool,
+{
+    if start == finish {
+        return Some((K::default(), vec![start]));
+    }
+
+    let mut forward = SearchSide::new(graph.visit_map(), start, estimate_cost(start));
+    let mut backward = SearchSide::new(graph.visit_map(), finish, reverse_estimate_cost(finish));
+    // The cost of the best path found so far and the node where both searches met.
+    let mut best: Option<(K, G::NodeId)> = None;
+
+    // Once one side is exhausted, every path it could take part in has been seen.
+    while let (Some(&MinScored(forward_top, _)), Some(&MinScored(backward_top, _))) =
+        (forward.visit_next.peek(), backward.visit_next.peek())
+    {
+        if let Some((best_score, _)) = best {
+            if is_done(forward_top, backward_top, best_score) {
+                break;
+            }
+        }
+
+        if forward_top <= backward_top {
+            let (node, node_score) = match forward.pop() {
+                Some(popped) => popped,
+                None => continue,
+            };
+            for edge in graph.edges_directed(node, Outgoing) {
+                let next = edge.target();
+                let next_score = node_score + edge_cost(edge);
+                forward.relax(
+                    node,
+                    next,
+                    next_score,
+                    &mut estimate_cost,
+                    &backward,
+                    &mut best,
+                );
+            }
+        } else {
+            let (node, node_score) = match backward.pop() {
+                Some(popped) => popped,
+                None => continue,
+            };
+            for edge in Reversed(graph).edges(node) {
+                let next = edge.target();
+                let next_score = node_score + edge_cost(edge.into_unreversed());
+                backward.relax(
+                    node,
+                    next,
+                    next_score,
+                    &mut reverse_estimate_cost,
+                    &forward,
+                    &mut best,
+                );
+            }
+        }
+    }
+
+    let (cost, meeting) = best?;
+    let mut path = vec![meeting];
+    let mut current = meeting;
+    while let Some(&previous) = forward.predecessors.get(&current) {
+        path.push(previous);
+        current = previous;
+    }
+    path.reverse();
+    current = meeting;
+    while let Some(&next) = backward.predecessors.get(&current) {
+        path.push(next);
+        current = next;
+    }
+    Some((cost, path))
+}
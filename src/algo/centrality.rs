@@ -0,0 +1,1052 @@
+//! Centrality measures, based on shortest paths or on power iteration.
+
+use alloc::{collections::BinaryHeap, collections::VecDeque, vec, vec::Vec};
+use core::hash::Hash;
+
+use super::{dijkstra, Measure, UnitMeasure};
+use crate::scored::MinScored;
+use crate::visit::{
+    EdgeIndexable, EdgeRef, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+    Visitable,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Brandes' betweenness centrality algorithm.
+///
+/// Computes the [betweenness centrality][bc] of every node, the sum over all pairs of
+/// other nodes `s` and `t` of the fraction of shortest paths from `s` to `t` that pass
+/// through it. Path lengths are the number of edges, see
+/// [`weighted_betweenness_centrality`] for weighted graphs.
+///
+/// In undirected graphs, the paths from `s` to `t` and from `t` to `s` are only
+/// counted once. Parallel edges give rise to distinct paths.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `normalized`: whether to divide the centralities by the number of pairs of other
+///   nodes, **(|V| - 1)(|V| - 2)** for directed graphs and half of it for undirected ones.
+///
+/// # Returns
+/// * A `Vec` mapping each node index to its centrality.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [bc]: https://en.wikipedia.org/wiki/Betweenness_centrality
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::betweenness_centrality;
+///
+/// // a - b - c
+/// //  \     /
+/// //   - d -
+/// let mut graph = Graph::<(), (), _>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (b, c), (c, d), (d, a)]);
+///
+/// // Half of the shortest paths between the opposite corners go through each other node.
+/// assert_eq!(betweenness_centrality(&graph, false), vec![0.5, 0.5, 0.5, 0.5]);
+/// assert_eq!(betweenness_centrality(&graph, true), vec![1. / 6.; 4]);
+/// ```
+pub fn betweenness_centrality<G>(graph: G, normalized: bool) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+{
+    let mut centrality = vec![0.; graph.node_bound()];
+    for source in graph.node_identifiers() {
+        let basis = bfs_basis(graph, source);
+        basis.accumulate(graph.to_index(source), &mut centrality, |_, _| {});
+    }
+    rescale_nodes(graph, &mut centrality, normalized);
+    centrality
+}
+
+/// Brandes' betweenness centrality algorithm for weighted graphs.
+///
+/// Computes the [betweenness centrality][bc] of every node like
+/// [`betweenness_centrality`], where path lengths are the sum of the edge costs.
+///
+/// The function `edge_cost` should return the cost for a particular edge. Edge costs
+/// must be non-negative.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `normalized`: whether to divide the centralities by the number of pairs of other nodes.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * A `Vec` mapping each node index to its centrality.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E|log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [bc]: https://en.wikipedia.org/wiki/Betweenness_centrality
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::weighted_betweenness_centrality;
+///
+/// // a -1-> b -1-> c
+/// //  \            ^
+/// //   ----- 3 ---/
+/// let mut graph = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (b, c, 1), (a, c, 3)]);
+///
+/// let centrality = weighted_betweenness_centrality(&graph, false, |e| *e.weight());
+/// assert_eq!(centrality, vec![0., 1., 0.]);
+/// ```
+pub fn weighted_betweenness_centrality<G, F, K>(
+    graph: G,
+    normalized: bool,
+    mut edge_cost: F,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut centrality = vec![0.; graph.node_bound()];
+    for source in graph.node_identifiers() {
+        let basis = dijkstra_basis(graph, source, &mut edge_cost);
+        basis.accumulate(graph.to_index(source), &mut centrality, |_, _| {});
+    }
+    rescale_nodes(graph, &mut centrality, normalized);
+    centrality
+}
+
+/// Brandes' edge betweenness centrality algorithm.
+///
+/// Computes the [betweenness centrality][bc] of every edge, the sum over all pairs of
+/// nodes `s` and `t` of the fraction of shortest paths from `s` to `t` that use it.
+/// Path lengths are the number of edges, see [`weighted_edge_betweenness_centrality`]
+/// for weighted graphs.
+///
+/// In undirected graphs, the paths from `s` to `t` and from `t` to `s` are only
+/// counted once.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `normalized`: whether to divide the centralities by the number of pairs of nodes,
+///   **|V|(|V| - 1)** for directed graphs and half of it for undirected ones.
+///
+/// # Returns
+/// * A `Vec` mapping each edge index to its centrality.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [bc]: https://en.wikipedia.org/wiki/Betweenness_centrality
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::edge_betweenness_centrality;
+///
+/// // a - b - c
+/// let mut graph = Graph::<(), (), _>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let ab = graph.add_edge(a, b, ());
+/// let bc = graph.add_edge(b, c, ());
+///
+/// // Each edge is on the shortest path between its endpoints and on the one from a to c.
+/// let centrality = edge_betweenness_centrality(&graph, false);
+/// assert_eq!(centrality[ab.index()], 2.);
+/// assert_eq!(centrality[bc.index()], 2.);
+/// ```
+pub fn edge_betweenness_centrality<G>(graph: G, normalized: bool) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + NodeCount + GraphProp,
+{
+    let mut centrality = vec![0.; graph.node_bound()];
+    let mut edge_centrality = vec![0.; graph.edge_bound()];
+    for source in graph.node_identifiers() {
+        let basis = bfs_basis(graph, source);
+        basis.accumulate(
+            NodeIndexable::to_index(&graph, source),
+            &mut centrality,
+            |edge, c| {
+                edge_centrality[EdgeIndexable::to_index(&graph, edge)] += c;
+            },
+        );
+    }
+    rescale_edges(graph, &mut edge_centrality, normalized);
+    edge_centrality
+}
+
+/// Brandes' edge betweenness centrality algorithm for weighted graphs.
+///
+/// Computes the [betweenness centrality][bc] of every edge like
+/// [`edge_betweenness_centrality`], where path lengths are the sum of the edge costs.
+///
+/// The function `edge_cost` should return the cost for a particular edge. Edge costs
+/// must be non-negative.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `normalized`: whether to divide the centralities by the number of pairs of nodes.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * A `Vec` mapping each edge index to its centrality.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E|log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [bc]: https://en.wikipedia.org/wiki/Betweenness_centrality
+pub fn weighted_edge_betweenness_centrality<G, F, K>(
+    graph: G,
+    normalized: bool,
+    mut edge_cost: F,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + NodeCount + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut centrality = vec![0.; graph.node_bound()];
+    let mut edge_centrality = vec![0.; graph.edge_bound()];
+    for source in graph.node_identifiers() {
+        let basis = dijkstra_basis(graph, source, &mut edge_cost);
+        basis.accumulate(
+            NodeIndexable::to_index(&graph, source),
+            &mut centrality,
+            |edge, c| {
+                edge_centrality[EdgeIndexable::to_index(&graph, edge)] += c;
+            },
+        );
+    }
+    rescale_edges(graph, &mut edge_centrality, normalized);
+    edge_centrality
+}
+
+/// Closeness centrality.
+///
+/// Computes the [closeness centrality][cc] of every node `u`: the number of other nodes
+/// reachable from `u`, divided by the sum of their distances from `u`. Nodes that reach
+/// no other node have a centrality of zero.
+///
+/// If `wf_improved` is `true`, the centrality is further multiplied by the fraction of
+/// the other nodes that are reachable, as proposed by Wasserman and Faust, so that
+/// nodes of small components get smaller centralities.
+///
+/// The distances are measured along outgoing edges. To compute the centrality with
+/// incoming distances in a directed graph, use [`Reversed`](crate::visit::Reversed).
+///
+/// The function `edge_cost` should return the cost for a particular edge. Edge costs
+/// must be non-negative. For unweighted graphs, use `|_| 1.`.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `wf_improved`: whether to scale the centralities by the fraction of reachable nodes.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * A `Vec` mapping each node index to its centrality.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E|log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [cc]: https://en.wikipedia.org/wiki/Closeness_centrality
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::closeness_centrality;
+///
+/// // a - b - c
+/// let mut graph = Graph::<(), (), _>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (b, c)]);
+///
+/// let centrality = closeness_centrality(&graph, false, |_| 1.);
+/// assert_eq!(centrality, vec![2. / 3., 1., 2. / 3.]);
+/// ```
+pub fn closeness_centrality<G, F, D>(graph: G, wf_improved: bool, mut edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let mut centrality = vec![D::zero(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        centrality[graph.to_index(node)] = closeness(graph, node, wf_improved, &mut edge_cost);
+    }
+    centrality
+}
+
+/// Harmonic centrality.
+///
+/// Computes the [harmonic centrality][hc] of every node `u`: the sum of the inverses of
+/// the distances from `u` to the other nodes. Unreachable nodes, and nodes at distance
+/// zero, do not contribute. Unlike closeness centrality, it is well-behaved on
+/// disconnected graphs.
+///
+/// The distances are measured along outgoing edges. To compute the centrality with
+/// incoming distances in a directed graph, use [`Reversed`](crate::visit::Reversed).
+///
+/// The function `edge_cost` should return the cost for a particular edge. Edge costs
+/// must be non-negative. For unweighted graphs, use `|_| 1.`.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * A `Vec` mapping each node index to its centrality.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E|log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [hc]: https://en.wikipedia.org/wiki/Closeness_centrality#Harmonic_centrality
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::harmonic_centrality;
+///
+/// // a -> b -> c
+/// let mut graph = Graph::<(), ()>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (b, c)]);
+///
+/// let centrality = harmonic_centrality(&graph, |_| 1.);
+/// assert_eq!(centrality, vec![1.5, 1., 0.]);
+/// ```
+pub fn harmonic_centrality<G, F, D>(graph: G, mut edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let mut centrality = vec![D::zero(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        centrality[graph.to_index(node)] = harmonic(graph, node, &mut edge_cost);
+    }
+    centrality
+}
+
+/// Eigenvector centrality.
+///
+/// Computes the [eigenvector centrality][ec] of every node by power iteration: the
+/// score of a node is proportional to the sum of the scores of the nodes with an edge
+/// to it, weighted by `edge_weight`. In a directed graph, the centrality is thus based
+/// on the incoming edges; use [`Reversed`](crate::visit::Reversed) for outgoing edges.
+///
+/// The iteration stops when the sum of the absolute differences between two successive
+/// score vectors is at most `|V| * tol`, or after `max_iter` iterations. Each iteration
+/// adds the previous scores to the new ones, which does not change the eigenvectors but
+/// ensures convergence on bipartite graphs. Edge weights must be non-negative.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+/// * `max_iter`: the maximum number of iterations.
+/// * `tol`: the tolerance, [`UnitMeasure::default_tol`] if `None`.
+///
+/// # Returns
+/// Returns a tuple of two values:
+/// * `Vec<D>`: the centrality of each node, indexed by node index. The centralities sum to one.
+/// * `bool`: whether the iteration converged within `max_iter` iterations.
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **k** is the number of iterations, **|V|** is the number of nodes and **|E|** is
+/// the number of edges.
+///
+/// [ec]: https://en.wikipedia.org/wiki/Eigenvector_centrality
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::eigenvector_centrality;
+///
+/// // a - b - c
+/// let mut graph = Graph::<(), (), _>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (b, c)]);
+///
+/// let (centrality, converged) = eigenvector_centrality(&graph, |_| 1., 100, Some(1e-9));
+/// assert!(converged);
+/// // The principal eigenvector is (1, √2, 1).
+/// let expected = [1., 2_f64.sqrt(), 1.].map(|x| x / (2. + 2_f64.sqrt()));
+/// for (c, e) in centrality.iter().zip(expected) {
+///     assert!((c - e).abs() < 1e-6);
+/// }
+/// ```
+pub fn eigenvector_centrality<G, F, D>(
+    graph: G,
+    edge_weight: F,
+    max_iter: usize,
+    tol: Option<D>,
+) -> (Vec<D>, bool)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let arcs = weighted_arcs(graph, edge_weight);
+    let threshold = convergence_threshold(graph, tol);
+    let mut scores = initial_scores(graph);
+    for _ in 0..max_iter {
+        let mut next = scores.clone();
+        for &(u, v, weight) in &arcs {
+            next[v] = next[v] + weight * scores[u];
+        }
+        normalize(&mut next);
+        let delta = l1_distance(&next, &scores);
+        scores = next;
+        if delta <= threshold {
+            return (scores, true);
+        }
+    }
+    (scores, false)
+}
+
+/// Katz centrality.
+///
+/// Computes the [Katz centrality][kc] of every node by power iteration, the solution of
+/// `x[v] = alpha * Σ w(u, v) * x[u] + beta` where the sum ranges over the edges to `v`.
+/// It counts the walks ending at each node, the walks of length `k` being attenuated
+/// by `alpha^k`. In a directed graph, the centrality is thus based on the incoming
+/// edges; use [`Reversed`](crate::visit::Reversed) for outgoing edges.
+///
+/// The iteration stops when the sum of the absolute differences between two successive
+/// score vectors is at most `|V| * tol`, or after `max_iter` iterations. It only
+/// converges if `alpha` is smaller than the inverse of the largest eigenvalue of the
+/// weighted adjacency matrix. Edge weights must be non-negative.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `alpha`: the attenuation factor.
+/// * `beta`: the score every node gets regardless of its neighbors.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+/// * `max_iter`: the maximum number of iterations.
+/// * `tol`: the tolerance, [`UnitMeasure::default_tol`] if `None`.
+///
+/// # Returns
+/// Returns a tuple of two values:
+/// * `Vec<D>`: the centrality of each node, indexed by node index. The centralities are
+///   not normalized.
+/// * `bool`: whether the iteration converged within `max_iter` iterations.
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **k** is the number of iterations, **|V|** is the number of nodes and **|E|** is
+/// the number of edges.
+///
+/// [kc]: https://en.wikipedia.org/wiki/Katz_centrality
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::katz_centrality;
+///
+/// // a -> b -> c
+/// let mut graph = Graph::<(), ()>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (b, c)]);
+///
+/// let (centrality, converged) = katz_centrality(&graph, 0.5, 1., |_| 1., 100, None);
+/// assert!(converged);
+/// assert_eq!(centrality, vec![1., 1.5, 1.75]);
+/// ```
+pub fn katz_centrality<G, F, D>(
+    graph: G,
+    alpha: D,
+    beta: D,
+    edge_weight: F,
+    max_iter: usize,
+    tol: Option<D>,
+) -> (Vec<D>, bool)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let arcs = weighted_arcs(graph, edge_weight);
+    let threshold = convergence_threshold(graph, tol);
+    let mut base = vec![D::zero(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        base[graph.to_index(node)] = beta;
+    }
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for _ in 0..max_iter {
+        let mut next = base.clone();
+        for &(u, v, weight) in &arcs {
+            next[v] = next[v] + alpha * weight * scores[u];
+        }
+        let delta = l1_distance(&next, &scores);
+        scores = next;
+        if delta <= threshold {
+            return (scores, true);
+        }
+    }
+    (scores, false)
+}
+
+/// HITS algorithm.
+///
+/// Computes the hub and authority scores of every node with Kleinberg's
+/// [Hyperlink-Induced Topic Search][hits] by power iteration. The authority score of a
+/// node is the sum of the hub scores of the nodes with an edge to it, and its hub score
+/// is the sum of the authority scores of the nodes it has an edge to, both weighted by
+/// `edge_weight`.
+///
+/// The iteration stops when the sum of the absolute differences between two successive
+/// hub score vectors is at most `|V| * tol`, or after `max_iter` iterations. Edge
+/// weights must be non-negative.
+///
+/// # Arguments
+/// * `graph`: a directed graph.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+/// * `max_iter`: the maximum number of iterations.
+/// * `tol`: the tolerance, [`UnitMeasure::default_tol`] if `None`.
+///
+/// # Returns
+/// Returns a tuple of three values:
+/// * `Vec<D>`: the hub score of each node, indexed by node index. The scores sum to one,
+///   unless the graph has no edge.
+/// * `Vec<D>`: the authority score of each node, indexed by node index, normalized likewise.
+/// * `bool`: whether the iteration converged within `max_iter` iterations.
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **k** is the number of iterations, **|V|** is the number of nodes and **|E|** is
+/// the number of edges.
+///
+/// [hits]: https://en.wikipedia.org/wiki/HITS_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::hits;
+///
+/// // a -> c, b -> c, b -> d
+/// let mut graph = Graph::<(), ()>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, c), (b, c), (b, d)]);
+///
+/// let (hubs, authorities, converged) = hits(&graph, |_| 1_f64, 100, None);
+/// assert!(converged);
+/// // b is the best hub, and c the best authority.
+/// assert!(hubs[b.index()] > hubs[a.index()]);
+/// assert!(authorities[c.index()] > authorities[d.index()]);
+/// assert_eq!(hubs[c.index()], 0.);
+/// ```
+pub fn hits<G, F, D>(
+    graph: G,
+    edge_weight: F,
+    max_iter: usize,
+    tol: Option<D>,
+) -> (Vec<D>, Vec<D>, bool)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let arcs = weighted_arcs(graph, edge_weight);
+    let threshold = convergence_threshold(graph, tol);
+    let mut hubs = initial_scores(graph);
+    let mut authorities = vec![D::zero(); graph.node_bound()];
+    for _ in 0..max_iter {
+        authorities = vec![D::zero(); graph.node_bound()];
+        for &(u, v, weight) in &arcs {
+            authorities[v] = authorities[v] + weight * hubs[u];
+        }
+        let mut next = vec![D::zero(); graph.node_bound()];
+        for &(u, v, weight) in &arcs {
+            next[u] = next[u] + weight * authorities[v];
+        }
+        normalize(&mut authorities);
+        normalize(&mut next);
+        let delta = l1_distance(&next, &hubs);
+        hubs = next;
+        if delta <= threshold {
+            return (hubs, authorities, true);
+        }
+    }
+    (hubs, authorities, false)
+}
+
+/// Parallel betweenness centrality.
+///
+/// See [`betweenness_centrality`].
+#[cfg(feature = "rayon")]
+pub fn parallel_betweenness_centrality<G>(graph: G, normalized: bool) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp + Sync,
+{
+    let mut centrality = parallel_sum(graph, graph.node_bound(), |source, centrality| {
+        let basis = bfs_basis(graph, graph.from_index(source));
+        basis.accumulate(source, centrality, |_, _| {});
+    });
+    rescale_nodes(graph, &mut centrality, normalized);
+    centrality
+}
+
+/// Parallel weighted betweenness centrality.
+///
+/// See [`weighted_betweenness_centrality`].
+#[cfg(feature = "rayon")]
+pub fn parallel_weighted_betweenness_centrality<G, F, K>(
+    graph: G,
+    normalized: bool,
+    edge_cost: F,
+) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp + Sync,
+    F: Fn(G::EdgeRef) -> K + Sync,
+    K: Measure + Copy,
+{
+    let mut centrality = parallel_sum(graph, graph.node_bound(), |source, centrality| {
+        let basis = dijkstra_basis(graph, graph.from_index(source), &mut |e| edge_cost(e));
+        basis.accumulate(source, centrality, |_, _| {});
+    });
+    rescale_nodes(graph, &mut centrality, normalized);
+    centrality
+}
+
+/// Parallel edge betweenness centrality.
+///
+/// See [`edge_betweenness_centrality`].
+#[cfg(feature = "rayon")]
+pub fn parallel_edge_betweenness_centrality<G>(graph: G, normalized: bool) -> Vec<f64>
+where
+    G: IntoEdges
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeIndexable
+        + NodeCount
+        + GraphProp
+        + Sync,
+{
+    let mut edge_centrality = parallel_sum(graph, graph.edge_bound(), |source, edge_centrality| {
+        let mut centrality = vec![0.; graph.node_bound()];
+        let basis = bfs_basis(graph, NodeIndexable::from_index(&graph, source));
+        basis.accumulate(source, &mut centrality, |edge, c| {
+            edge_centrality[EdgeIndexable::to_index(&graph, edge)] += c;
+        });
+    });
+    rescale_edges(graph, &mut edge_centrality, normalized);
+    edge_centrality
+}
+
+/// Parallel weighted edge betweenness centrality.
+///
+/// See [`weighted_edge_betweenness_centrality`].
+#[cfg(feature = "rayon")]
+pub fn parallel_weighted_edge_betweenness_centrality<G, F, K>(
+    graph: G,
+    normalized: bool,
+    edge_cost: F,
+) -> Vec<f64>
+where
+    G: IntoEdges
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeIndexable
+        + NodeCount
+        + GraphProp
+        + Sync,
+    F: Fn(G::EdgeRef) -> K + Sync,
+    K: Measure + Copy,
+{
+    let mut edge_centrality = parallel_sum(graph, graph.edge_bound(), |source, edge_centrality| {
+        let mut centrality = vec![0.; graph.node_bound()];
+        let basis = dijkstra_basis(graph, NodeIndexable::from_index(&graph, source), &mut |e| {
+            edge_cost(e)
+        });
+        basis.accumulate(source, &mut centrality, |edge, c| {
+            edge_centrality[EdgeIndexable::to_index(&graph, edge)] += c;
+        });
+    });
+    rescale_edges(graph, &mut edge_centrality, normalized);
+    edge_centrality
+}
+
+/// Parallel closeness centrality.
+///
+/// See [`closeness_centrality`].
+#[cfg(feature = "rayon")]
+pub fn parallel_closeness_centrality<G, F, D>(graph: G, wf_improved: bool, edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + Visitable + Sync,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::EdgeRef) -> D + Sync,
+    D: UnitMeasure + Copy + Send,
+{
+    (0..graph.node_bound())
+        .into_par_iter()
+        .map(|i| {
+            if !node_exists(graph, i) {
+                return D::zero();
+            }
+            closeness(graph, graph.from_index(i), wf_improved, &mut |e| {
+                edge_cost(e)
+            })
+        })
+        .collect()
+}
+
+/// Parallel harmonic centrality.
+///
+/// See [`harmonic_centrality`].
+#[cfg(feature = "rayon")]
+pub fn parallel_harmonic_centrality<G, F, D>(graph: G, edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable + Sync,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::EdgeRef) -> D + Sync,
+    D: UnitMeasure + Copy + Send,
+{
+    (0..graph.node_bound())
+        .into_par_iter()
+        .map(|i| {
+            if !node_exists(graph, i) {
+                return D::zero();
+            }
+            harmonic(graph, graph.from_index(i), &mut |e| edge_cost(e))
+        })
+        .collect()
+}
+
+/// Return `true` if index `i` is used by a node. Indices of removed nodes may be
+/// reused by `from_index` in some graph types, so they are checked explicitly.
+#[cfg(feature = "rayon")]
+fn node_exists<G>(graph: G, i: usize) -> bool
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+{
+    graph
+        .node_identifiers()
+        .any(|node| graph.to_index(node) == i)
+}
+
+/// Sum, over all the nodes as source, the contributions computed by `run` into a
+/// `Vec` of length `len`.
+#[cfg(feature = "rayon")]
+fn parallel_sum<G, R>(graph: G, len: usize, run: R) -> Vec<f64>
+where
+    G: IntoNodeIdentifiers + NodeIndexable + Sync,
+    R: Fn(usize, &mut [f64]) + Sync,
+{
+    let sources: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    sources
+        .into_par_iter()
+        .fold(
+            || vec![0.; len],
+            |mut partial, source| {
+                run(source, &mut partial);
+                partial
+            },
+        )
+        .reduce(
+            || vec![0.; len],
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(x, y)| *x += y);
+                a
+            },
+        )
+}
+
+/// Shortest paths from a single source, as needed by Brandes' algorithm.
+struct ShortestPathBasis<E> {
+    /// The reached nodes, by non-decreasing distance.
+    order: Vec<usize>,
+    /// The predecessors of each node on its shortest paths, and the edge from them.
+    predecessors: Vec<Vec<(usize, E)>>,
+    /// The number of shortest paths to each node.
+    sigma: Vec<f64>,
+}
+
+impl<E: Copy> ShortestPathBasis<E> {
+    fn new(node_bound: usize, source: usize) -> Self {
+        let mut sigma = vec![0.; node_bound];
+        sigma[source] = 1.;
+        ShortestPathBasis {
+            order: Vec::new(),
+            predecessors: vec![Vec::new(); node_bound],
+            sigma,
+        }
+    }
+
+    /// Accumulate the dependencies of `source` on every other node into `centrality`,
+    /// and report the dependency of `source` on every edge to `edge_dependency`.
+    fn accumulate<H>(&self, source: usize, centrality: &mut [f64], mut edge_dependency: H)
+    where
+        H: FnMut(E, f64),
+    {
+        let mut delta = vec![0.; self.sigma.len()];
+        for &w in self.order.iter().rev() {
+            let coefficient = (1. + delta[w]) / self.sigma[w];
+            for &(v, edge) in &self.predecessors[w] {
+                let c = self.sigma[v] * coefficient;
+                edge_dependency(edge, c);
+                delta[v] += c;
+            }
+            if w != source {
+                centrality[w] += delta[w];
+            }
+        }
+    }
+}
+
+fn bfs_basis<G>(graph: G, source: G::NodeId) -> ShortestPathBasis<G::EdgeId>
+where
+    G: IntoEdges + NodeIndexable,
+{
+    let source = graph.to_index(source);
+    let mut basis = ShortestPathBasis::new(graph.node_bound(), source);
+    let mut distance = vec![usize::MAX; graph.node_bound()];
+    distance[source] = 0;
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    while let Some(v) = queue.pop_front() {
+        basis.order.push(v);
+        for edge in graph.edges(graph.from_index(v)) {
+            let w = graph.to_index(edge.target());
+            if distance[w] == usize::MAX {
+                distance[w] = distance[v] + 1;
+                queue.push_back(w);
+            }
+            if distance[w] == distance[v] + 1 {
+                basis.sigma[w] += basis.sigma[v];
+                basis.predecessors[w].push((v, edge.id()));
+            }
+        }
+    }
+    basis
+}
+
+fn dijkstra_basis<G, F, K>(
+    graph: G,
+    source: G::NodeId,
+    edge_cost: &mut F,
+) -> ShortestPathBasis<G::EdgeId>
+where
+    G: IntoEdges + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let source = graph.to_index(source);
+    let mut basis = ShortestPathBasis::new(graph.node_bound(), source);
+    let mut distance: Vec<Option<K>> = vec![None; graph.node_bound()];
+    let mut finished = vec![false; graph.node_bound()];
+    let mut visit_next = BinaryHeap::new();
+    distance[source] = Some(K::default());
+    visit_next.push(MinScored(K::default(), source));
+    while let Some(MinScored(v_distance, v)) = visit_next.pop() {
+        if finished[v] {
+            continue;
+        }
+        finished[v] = true;
+        basis.order.push(v);
+        // All the predecessors of v are finished, so its number of paths is final.
+        if v != source {
+            basis.sigma[v] = basis.predecessors[v]
+                .iter()
+                .map(|&(u, _)| basis.sigma[u])
+                .sum();
+        }
+        for edge in graph.edges(graph.from_index(v)) {
+            let w = graph.to_index(edge.target());
+            if finished[w] {
+                continue;
+            }
+            let w_distance = v_distance + edge_cost(edge);
+            match distance[w] {
+                Some(d) if w_distance > d => {}
+                Some(d) if w_distance == d => basis.predecessors[w].push((v, edge.id())),
+                _ => {
+                    distance[w] = Some(w_distance);
+                    basis.predecessors[w].clear();
+                    basis.predecessors[w].push((v, edge.id()));
+                    visit_next.push(MinScored(w_distance, w));
+                }
+            }
+        }
+    }
+    basis
+}
+
+fn rescale_nodes<G>(graph: G, centrality: &mut [f64], normalized: bool)
+where
+    G: NodeCount + GraphProp,
+{
+    let n = graph.node_count() as f64;
+    let scale = if normalized {
+        // Undirected paths are counted in both directions, which the
+        // normalization by ordered pairs compensates.
+        (n > 2.).then(|| 1. / ((n - 1.) * (n - 2.)))
+    } else {
+        (!graph.is_directed()).then_some(0.5)
+    };
+    if let Some(scale) = scale {
+        centrality.iter_mut().for_each(|c| *c *= scale);
+    }
+}
+
+fn rescale_edges<G>(graph: G, centrality: &mut [f64], normalized: bool)
+where
+    G: NodeCount + GraphProp,
+{
+    let n = graph.node_count() as f64;
+    let scale = if normalized {
+        (n > 1.).then(|| 1. / (n * (n - 1.)))
+    } else {
+        (!graph.is_directed()).then_some(0.5)
+    };
+    if let Some(scale) = scale {
+        centrality.iter_mut().for_each(|c| *c *= scale);
+    }
+}
+
+fn closeness<G, F, D>(graph: G, node: G::NodeId, wf_improved: bool, edge_cost: &mut F) -> D
+where
+    G: IntoEdges + NodeIndexable + NodeCount + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let distances = dijkstra(graph, node, None, edge_cost);
+    let total: D = distances.values().copied().sum();
+    let reachable = D::from_usize(distances.len() - 1);
+    if total <= D::zero() {
+        return D::zero();
+    }
+    let closeness = reachable / total;
+    if wf_improved {
+        closeness * reachable / D::from_usize(graph.node_count() - 1)
+    } else {
+        closeness
+    }
+}
+
+fn harmonic<G, F, D>(graph: G, node: G::NodeId, edge_cost: &mut F) -> D
+where
+    G: IntoEdges + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    dijkstra(graph, node, None, edge_cost)
+        .into_values()
+        .filter(|&distance| distance > D::zero())
+        .map(|distance| D::one() / distance)
+        .sum()
+}
+
+/// Collect the edges of `graph` as `(source index, target index, weight)`.
+fn weighted_arcs<G, F, D>(graph: G, mut edge_weight: F) -> Vec<(usize, usize, D)>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> D,
+{
+    let mut arcs = Vec::new();
+    for node in graph.node_identifiers() {
+        for edge in graph.edges(node) {
+            arcs.push((
+                graph.to_index(edge.source()),
+                graph.to_index(edge.target()),
+                edge_weight(edge),
+            ));
+        }
+    }
+    arcs
+}
+
+/// Uniform scores summing to one.
+fn initial_scores<G, D>(graph: G) -> Vec<D>
+where
+    G: IntoNodeIdentifiers + NodeIndexable + NodeCount,
+    D: UnitMeasure + Copy,
+{
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    let initial = D::one() / D::from_usize(graph.node_count());
+    for node in graph.node_identifiers() {
+        scores[graph.to_index(node)] = initial;
+    }
+    scores
+}
+
+fn convergence_threshold<G, D>(graph: G, tol: Option<D>) -> D
+where
+    G: NodeCount,
+    D: UnitMeasure + Copy,
+{
+    D::from_usize(graph.node_count()) * tol.unwrap_or_else(D::default_tol)
+}
+
+/// Scale non-negative `scores` so that they sum to one, unless they are all zero.
+fn normalize<D>(scores: &mut [D])
+where
+    D: UnitMeasure + Copy,
+{
+    let sum: D = scores.iter().copied().sum();
+    if sum > D::zero() {
+        scores.iter_mut().for_each(|score| *score = *score / sum);
+    }
+}
+
+fn l1_distance<D>(a: &[D], b: &[D]) -> D
+where
+    D: UnitMeasure + Copy,
+{
+    a.iter()
+        .zip(b)
+        .map(|(&x, &y)| if x > y { x - y } else { y - x })
+        .sum()
+}
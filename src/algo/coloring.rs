@@ -1,10 +1,13 @@
-use alloc::{collections::BinaryHeap, vec};
-use core::hash::Hash;
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::{cmp::Reverse, hash::Hash};
 
 use hashbrown::{HashMap, HashSet};
 
 use crate::scored::MaxScored;
-use crate::visit::{IntoEdges, IntoNodeIdentifiers, NodeIndexable, VisitMap, Visitable};
+use crate::util::SplitMix64;
+use crate::visit::{
+    EdgeRef, IntoEdgeReferences, IntoEdges, IntoNodeIdentifiers, NodeIndexable, VisitMap, Visitable,
+};
 
 /// [DStatur algorithm][1] to properly color a non weighted undirected graph.
 ///
@@ -106,3 +109,553 @@ where
 
     (colored, max_color + 1)
 }
+
+/// Order in which [`greedy_coloring`] colors the nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColoringStrategy {
+    /// Color the nodes by decreasing degree (Welsh–Powell).
+    LargestFirst,
+    /// Repeatedly remove a node of minimum degree, and color the nodes in the reverse
+    /// order of their removal (Matula–Beck). This uses at most one more color than the
+    /// degeneracy of the graph.
+    SmallestLast,
+    /// Color the nodes in a random order, drawn from the given seed.
+    RandomSequential(u64),
+}
+
+/// Greedy algorithm to properly color a non weighted undirected graph.
+///
+/// The nodes are visited in the order given by `strategy`, and each one gets the
+/// smallest color not used by its neighbors.
+///
+/// This is a heuristic. So, it does not necessarily return a minimum coloring.
+/// The graph must be undirected. Loops are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph.
+/// * `strategy`: the order in which the nodes are colored.
+///
+/// # Returns
+/// Returns a tuple of:
+/// * [`struct@hashbrown::HashMap`] that associates to each `NodeId` its color.
+/// * `usize`: the number of used colors.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|log(|V|) + |E|log(|E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{greedy_coloring, ColoringStrategy};
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // A crown graph, on which a bad order needs as many colors as there are pairs.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 3), (0, 5), (1, 2), (1, 4), (2, 5), (3, 4),
+/// ]);
+///
+/// let (coloring, nb_colors) = greedy_coloring(&graph, ColoringStrategy::SmallestLast);
+/// assert_eq!(nb_colors, 2);
+/// assert_ne!(coloring[&NodeIndex::new(0)], coloring[&NodeIndex::new(3)]);
+/// ```
+pub fn greedy_coloring<G>(
+    graph: G,
+    strategy: ColoringStrategy,
+) -> (HashMap<G::NodeId, usize>, usize)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let simple = SimpleGraph::new(graph);
+    let order = match strategy {
+        ColoringStrategy::LargestFirst => {
+            let mut order: Vec<usize> = (0..simple.nodes.len()).collect();
+            order.sort_by_key(|&v| Reverse(simple.neighbors[v].len()));
+            order
+        }
+        ColoringStrategy::SmallestLast => simple.smallest_last_order(),
+        ColoringStrategy::RandomSequential(seed) => {
+            let mut order: Vec<usize> = (0..simple.nodes.len()).collect();
+            SplitMix64::new(seed).shuffle(&mut order);
+            order
+        }
+    };
+    simple.coloring(&simple.greedy(&order))
+}
+
+/// Exact algorithm to find a minimum coloring of a non weighted undirected graph.
+///
+/// Finds the chromatic number of the graph by a branch and bound search, which colors
+/// the most saturated node first (as in [`dsatur_coloring`]), starts from a greedy
+/// coloring as upper bound, and stops as soon as it reaches the size of a large clique.
+///
+/// The search takes exponential time in the worst case, so this is meant for small
+/// graphs. The graph must be undirected. Loops are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph.
+///
+/// # Returns
+/// Returns a tuple of:
+/// * [`struct@hashbrown::HashMap`] that associates to each `NodeId` its color.
+/// * `usize`: the number of used colors, which is the chromatic number of the graph.
+///
+/// # Complexity
+/// * Time complexity: **O(k^|V| |V|)**, where **k** is the chromatic number.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::exact_coloring;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // A cycle of five nodes needs three colors.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+///
+/// let (coloring, nb_colors) = exact_coloring(&graph);
+/// assert_eq!(nb_colors, 3);
+/// assert_ne!(coloring[&NodeIndex::new(0)], coloring[&NodeIndex::new(4)]);
+/// ```
+pub fn exact_coloring<G>(graph: G) -> (HashMap<G::NodeId, usize>, usize)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let simple = SimpleGraph::new(graph);
+    let colors = simple.greedy(&simple.smallest_last_order());
+    let mut search = ColoringSearch::new(&simple.neighbors, colors);
+    search.run();
+    simple.coloring(&search.best)
+}
+
+/// [Misra–Gries algorithm][1] to properly color the edges of an undirected graph.
+///
+/// Colors the edges so that the edges sharing an endpoint have different colors, with at
+/// most **Δ + 1** colors, where **Δ** is the maximum degree of the graph. By Vizing's
+/// theorem, this is at most one more color than a minimum edge coloring.
+///
+/// The graph must be undirected. It should not contain parallel edges. Loops cannot be
+/// properly colored, so they are left out of the returned coloring.
+///
+/// # Arguments
+/// * `graph`: undirected graph without parallel edges.
+///
+/// # Returns
+/// Returns a tuple of:
+/// * [`struct@hashbrown::HashMap`] that associates to each `EdgeId` its color.
+/// * `usize`: the number of used colors.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|(|V| + Δ²))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **Δ** is the
+/// maximum degree.
+///
+/// [1]: https://en.wikipedia.org/wiki/Misra_%26_Gries_edge_coloring_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::misra_gries_edge_coloring;
+/// use petgraph::graph::UnGraph;
+///
+/// // The complete graph on four nodes splits into three perfect matchings.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+///
+/// let (coloring, nb_colors) = misra_gries_edge_coloring(&graph);
+/// assert!(nb_colors <= 4);
+/// assert_eq!(coloring.len(), 6);
+/// ```
+pub fn misra_gries_edge_coloring<G>(graph: G) -> (HashMap<G::EdgeId, usize>, usize)
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    let mut ids = Vec::new();
+    let mut ends = Vec::new();
+    for edge in graph.edge_references() {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if a != b {
+            ids.push(edge.id());
+            ends.push((a, b));
+        }
+    }
+
+    let mut degrees = vec![0; graph.node_bound()];
+    for &(a, b) in &ends {
+        degrees[a] += 1;
+        degrees[b] += 1;
+    }
+    let max_degree = degrees.into_iter().max().unwrap_or(0);
+
+    let mut coloring = EdgeColoring {
+        colors: vec![usize::MAX; ends.len()],
+        at: vec![HashMap::new(); graph.node_bound()],
+        palette: max_degree + 1,
+        ends,
+    };
+    for edge in 0..coloring.ends.len() {
+        coloring.color_edge(edge);
+    }
+
+    let nb_colors = coloring.colors.iter().map(|&color| color + 1).max();
+    let colored = ids.into_iter().zip(coloring.colors).collect();
+    (colored, nb_colors.unwrap_or(0))
+}
+
+/// Undirected view of a graph, with the nodes numbered in `0..nodes.len()`, and sorted
+/// neighbor lists without loops and duplicates.
+struct SimpleGraph<N> {
+    nodes: Vec<N>,
+    neighbors: Vec<Vec<usize>>,
+}
+
+impl<N: Copy + Eq + Hash> SimpleGraph<N> {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoEdges<NodeId = N> + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, &node) in nodes.iter().enumerate() {
+            position[graph.to_index(node)] = i;
+        }
+        let mut neighbors = vec![Vec::new(); nodes.len()];
+        for (i, &node) in nodes.iter().enumerate() {
+            for nbor in graph.neighbors(node) {
+                let j = position[graph.to_index(nbor)];
+                if i != j {
+                    neighbors[i].push(j);
+                    neighbors[j].push(i);
+                }
+            }
+        }
+        for list in &mut neighbors {
+            list.sort_unstable();
+            list.dedup();
+        }
+        SimpleGraph { nodes, neighbors }
+    }
+
+    /// Color the nodes in the given order, each with the smallest color not used by its
+    /// neighbors.
+    fn greedy(&self, order: &[usize]) -> Vec<usize> {
+        let n = self.nodes.len();
+        let mut colors = vec![usize::MAX; n];
+        // `taken[c] == v` when color `c` is used by a neighbor of `v`.
+        let mut taken = vec![usize::MAX; n + 1];
+        for &v in order {
+            for &w in &self.neighbors[v] {
+                if colors[w] != usize::MAX {
+                    taken[colors[w]] = v;
+                }
+            }
+            colors[v] = (0..n).find(|&c| taken[c] != v).unwrap_or(0);
+        }
+        colors
+    }
+
+    /// Return the nodes in the reverse order of their removal, when the node of minimum
+    /// degree is removed repeatedly.
+    fn smallest_last_order(&self) -> Vec<usize> {
+        let n = self.nodes.len();
+        let mut degrees: Vec<usize> = self.neighbors.iter().map(Vec::len).collect();
+        // Buckets of nodes by degree, where outdated entries are skipped.
+        let mut buckets = vec![Vec::new(); n];
+        for (v, &degree) in degrees.iter().enumerate() {
+            buckets[degree].push(v);
+        }
+        let mut removed = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        let mut degree = 0;
+        while order.len() < n {
+            match buckets[degree].pop() {
+                Some(v) if !removed[v] && degrees[v] == degree => {
+                    removed[v] = true;
+                    order.push(v);
+                    for &w in &self.neighbors[v] {
+                        if !removed[w] {
+                            degrees[w] -= 1;
+                            buckets[degrees[w]].push(w);
+                        }
+                    }
+                    // Removing a node lowers the minimum degree by at most one.
+                    degree = degree.saturating_sub(1);
+                }
+                Some(_) => {}
+                None => degree += 1,
+            }
+        }
+        order.reverse();
+        order
+    }
+
+    fn coloring(&self, colors: &[usize]) -> (HashMap<N, usize>, usize) {
+        let nb_colors = colors.iter().map(|&color| color + 1).max().unwrap_or(0);
+        let colored = self
+            .nodes
+            .iter()
+            .copied()
+            .zip(colors.iter().copied())
+            .collect();
+        (colored, nb_colors)
+    }
+}
+
+/// State of the branch and bound search of [`exact_coloring`].
+struct ColoringSearch<'a> {
+    neighbors: &'a [Vec<usize>],
+    /// Color of each node, or `usize::MAX` if it is not colored yet.
+    colors: Vec<usize>,
+    /// Number of colors of the best coloring, which bounds the colors of the search.
+    palette: usize,
+    /// `counts[v * palette + c]` is the number of neighbors of `v` colored with `c`.
+    counts: Vec<usize>,
+    /// Number of distinct colors among the neighbors of each node.
+    saturation: Vec<usize>,
+    best: Vec<usize>,
+    best_count: usize,
+    lower_bound: usize,
+}
+
+impl<'a> ColoringSearch<'a> {
+    fn new(neighbors: &'a [Vec<usize>], best: Vec<usize>) -> Self {
+        let n = neighbors.len();
+        let best_count = best.iter().map(|&color| color + 1).max().unwrap_or(0);
+        ColoringSearch {
+            neighbors,
+            colors: vec![usize::MAX; n],
+            palette: best_count,
+            counts: vec![0; n * best_count],
+            saturation: vec![0; n],
+            best,
+            best_count,
+            lower_bound: 0,
+        }
+    }
+
+    fn run(&mut self) {
+        // Any coloring gives distinct colors to the nodes of a clique, so they can be
+        // colored first, in a fixed way.
+        let clique = self.greedy_clique();
+        self.lower_bound = clique.len();
+        if self.best_count <= self.lower_bound {
+            return;
+        }
+        for (color, &v) in clique.iter().enumerate() {
+            self.assign(v, color);
+        }
+        self.search(clique.len(), clique.len());
+    }
+
+    /// Return the largest of the cliques built greedily from each node.
+    fn greedy_clique(&self) -> Vec<usize> {
+        let mut best = Vec::new();
+        let mut clique = Vec::new();
+        for v in 0..self.neighbors.len() {
+            clique.clear();
+            clique.push(v);
+            let mut candidates = self.neighbors[v].clone();
+            candidates.sort_by_key(|&w| Reverse(self.neighbors[w].len()));
+            for w in candidates {
+                if clique
+                    .iter()
+                    .all(|u| self.neighbors[w].binary_search(u).is_ok())
+                {
+                    clique.push(w);
+                }
+            }
+            if clique.len() > best.len() {
+                best.clone_from(&clique);
+            }
+        }
+        best
+    }
+
+    /// Extend the current coloring, which has `colored` nodes and uses `used` colors.
+    /// Return `true` once an optimal coloring is found.
+    fn search(&mut self, colored: usize, used: usize) -> bool {
+        let n = self.neighbors.len();
+        if colored == n {
+            self.best.clone_from(&self.colors);
+            self.best_count = used;
+            return used <= self.lower_bound;
+        }
+        let v = (0..n)
+            .filter(|&v| self.colors[v] == usize::MAX)
+            .max_by_key(|&v| (self.saturation[v], self.neighbors[v].len()))
+            .unwrap();
+        let mut color = 0;
+        // Only colorings with fewer colors than the best one are explored.
+        while color <= used && color + 1 < self.best_count {
+            if self.counts[v * self.palette + color] == 0 {
+                self.assign(v, color);
+                let done = self.search(colored + 1, used.max(color + 1));
+                self.unassign(v);
+                if done {
+                    return true;
+                }
+            }
+            color += 1;
+        }
+        false
+    }
+
+    fn assign(&mut self, v: usize, color: usize) {
+        self.colors[v] = color;
+        for &w in self.neighbors[v].iter() {
+            let count = &mut self.counts[w * self.palette + color];
+            if *count == 0 {
+                self.saturation[w] += 1;
+            }
+            *count += 1;
+        }
+    }
+
+    fn unassign(&mut self, v: usize) {
+        let color = self.colors[v];
+        self.colors[v] = usize::MAX;
+        for &w in self.neighbors[v].iter() {
+            let count = &mut self.counts[w * self.palette + color];
+            *count -= 1;
+            if *count == 0 {
+                self.saturation[w] -= 1;
+            }
+        }
+    }
+}
+
+/// State of [`misra_gries_edge_coloring`].
+struct EdgeColoring {
+    /// Endpoints of each edge.
+    ends: Vec<(usize, usize)>,
+    /// Color of each edge, or `usize::MAX` if it is not colored yet.
+    colors: Vec<usize>,
+    /// For each node, the edge of each color incident to it.
+    at: Vec<HashMap<usize, usize>>,
+    /// Upper bound of the used colors.
+    palette: usize,
+}
+
+impl EdgeColoring {
+    fn other(&self, edge: usize, node: usize) -> usize {
+        let (a, b) = self.ends[edge];
+        if a == node {
+            b
+        } else {
+            a
+        }
+    }
+
+    fn is_free(&self, node: usize, color: usize) -> bool {
+        !self.at[node].contains_key(&color)
+    }
+
+    fn free_color(&self, node: usize) -> usize {
+        (0..).find(|&color| self.is_free(node, color)).unwrap()
+    }
+
+    fn set(&mut self, edge: usize, color: usize) {
+        let (a, b) = self.ends[edge];
+        self.colors[edge] = color;
+        self.at[a].insert(color, edge);
+        self.at[b].insert(color, edge);
+        self.palette = self.palette.max(color + 1);
+    }
+
+    fn unset(&mut self, edge: usize) -> usize {
+        let (a, b) = self.ends[edge];
+        let color = core::mem::replace(&mut self.colors[edge], usize::MAX);
+        self.at[a].remove(&color);
+        self.at[b].remove(&color);
+        color
+    }
+
+    fn color_edge(&mut self, edge: usize) {
+        let (u, v) = self.ends[edge];
+
+        // Build a maximal fan of `u` starting with `edge`: each next edge has a color that
+        // is free on the far end of the previous one.
+        let mut fan = vec![edge];
+        let mut fan_colors = HashSet::new();
+        let mut last = v;
+        loop {
+            let next = (0..self.palette)
+                .filter(|&color| !fan_colors.contains(&color) && self.is_free(last, color))
+                .find_map(|color| self.at[u].get(&color).map(|&next| (color, next)));
+            match next {
+                Some((color, next)) => {
+                    fan.push(next);
+                    fan_colors.insert(color);
+                    last = self.other(next, u);
+                }
+                None => break,
+            }
+        }
+
+        let c = self.free_color(u);
+        let d = self.free_color(last);
+        self.invert_path(u, c, d);
+
+        // Find a prefix of the fan, which is still a fan, ending on a node where `d` is
+        // free. It always exists in a graph without parallel edges.
+        let mut end = None;
+        for i in 0..fan.len() {
+            if i > 0 {
+                let previous = self.other(fan[i - 1], u);
+                if !self.is_free(previous, self.colors[fan[i]]) {
+                    break;
+                }
+            }
+            if self.is_free(self.other(fan[i], u), d) {
+                end = Some(i);
+                break;
+            }
+        }
+
+        match end {
+            Some(end) => {
+                // Rotate the colors of the fan prefix, which frees `d` for its last edge.
+                for i in 0..end {
+                    let color = self.unset(fan[i + 1]);
+                    self.set(fan[i], color);
+                }
+                self.set(fan[end], d);
+            }
+            None => {
+                let color = (0..)
+                    .find(|&color| self.is_free(u, color) && self.is_free(v, color))
+                    .unwrap();
+                self.set(edge, color);
+            }
+        }
+    }
+
+    /// Swap the colors `c` and `d` on the path starting at `u`, on which `c` is free, and
+    /// alternating edges colored with `d` and `c`.
+    fn invert_path(&mut self, u: usize, c: usize, d: usize) {
+        if c == d {
+            return;
+        }
+        let mut path = Vec::new();
+        let (mut node, mut color) = (u, d);
+        while let Some(&edge) = self.at[node].get(&color) {
+            // The path is simple as long as the coloring is proper, which only parallel
+            // edges can break.
+            if path.len() == self.ends.len() {
+                break;
+            }
+            path.push(edge);
+            node = self.other(edge, node);
+            color = if color == d { c } else { d };
+        }
+        let colors: Vec<usize> = path.iter().map(|&edge| self.unset(edge)).collect();
+        for (edge, color) in path.into_iter().zip(colors) {
+            self.set(edge, if color == d { c } else { d });
+        }
+    }
+}
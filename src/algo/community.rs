@@ -0,0 +1,701 @@
+//! Community detection: modularity optimisation and label propagation.
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use hashbrown::HashMap;
+
+use crate::util::SplitMix64;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// Louvain community detection.
+///
+/// Partitions the nodes of a graph into communities with the [Louvain method][lv], a
+/// greedy optimisation of the [`modularity`]. Each node is repeatedly moved to the
+/// neighboring community that most increases the modularity, until no move improves
+/// it; then the communities are merged into single nodes, and the process is repeated
+/// on the aggregated graph until the partition does not change anymore.
+///
+/// Edge directions are ignored, and edge weights, given by `edge_weight`, must be
+/// non-negative. The nodes are visited in a random order drawn from `seed`, so that
+/// the result is deterministic for a given seed.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+/// * `resolution`: the resolution parameter of the modularity, `1.0` for the standard
+///   modularity. Higher values lead to more, smaller communities.
+/// * `seed`: the seed of the random node orders.
+///
+/// # Returns
+/// * A `Vec` mapping each node index to its community, numbered from `0` in node index
+///   order. Indices that are not used by a node are mapped to `usize::MAX`.
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))** per aggregation level in practice, where
+///   **k** is the number of passes over the nodes.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [lv]: https://en.wikipedia.org/wiki/Louvain_method
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::{louvain, modularity};
+///
+/// // Two triangles joined by a single edge.
+/// let mut graph = UnGraph::<(), ()>::new_undirected();
+/// for _ in 0..6 {
+///     graph.add_node(());
+/// }
+/// graph.extend_with_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)]);
+///
+/// let communities = louvain(&graph, |_| 1., 1., 42);
+/// assert_eq!(communities, vec![0, 0, 0, 1, 1, 1]);
+/// let score = modularity(&graph, &communities, |_| 1., 1.);
+/// assert!((score - 5. / 14.).abs() < 1e-9);
+/// ```
+pub fn louvain<G, F>(graph: G, edge_weight: F, resolution: f64, seed: u64) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (mut network, compact) = Network::new(graph, edge_weight);
+    let mut rng = SplitMix64::new(seed);
+    // The node of the current aggregated network that each node belongs to.
+    let mut membership: Vec<usize> = (0..network.len()).collect();
+    loop {
+        let mut partition = Partition::singletons(&network);
+        if !partition.move_nodes(&network, resolution, &mut rng) {
+            break;
+        }
+        let (communities, count) = renumber(&partition.community);
+        for m in &mut membership {
+            *m = communities[*m];
+        }
+        network = network.aggregate(&communities, count);
+    }
+    labeling(&compact, &membership)
+}
+
+/// Leiden community detection.
+///
+/// Partitions the nodes of a graph into communities with the [Leiden algorithm][ld],
+/// a refinement of [`louvain`] which guarantees that the communities are connected.
+/// Nodes are moved between communities like in the Louvain method, but with a queue
+/// of the nodes whose neighborhood changed. Before aggregation, each community is
+/// refined into well-connected sub-communities, which become the nodes of the
+/// aggregated graph, while their communities are kept as its initial partition. The
+/// refinement merges each node into the sub-community that most increases the
+/// modularity (the greedy variant of the original randomized refinement).
+///
+/// Edge directions are ignored, and edge weights, given by `edge_weight`, must be
+/// non-negative. The nodes are visited in a random order drawn from `seed`, so that
+/// the result is deterministic for a given seed.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+/// * `resolution`: the resolution parameter of the modularity, `1.0` for the standard
+///   modularity. Higher values lead to more, smaller communities.
+/// * `seed`: the seed of the random node orders.
+///
+/// # Returns
+/// * A `Vec` mapping each node index to its community, numbered from `0` in node index
+///   order. Indices that are not used by a node are mapped to `usize::MAX`.
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))** per aggregation level in practice, where
+///   **k** is the number of node moves per node.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [ld]: https://doi.org/10.1038/s41598-019-41695-z
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::leiden;
+///
+/// // Two squares with diagonals, joined by a single edge.
+/// let mut graph = UnGraph::<(), ()>::new_undirected();
+/// for _ in 0..8 {
+///     graph.add_node(());
+/// }
+/// graph.extend_with_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (0, 2),
+///     (4, 5), (5, 6), (6, 7), (7, 4), (4, 6),
+///     (3, 4),
+/// ]);
+///
+/// let communities = leiden(&graph, |_| 1., 1., 7);
+/// assert_eq!(communities, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+/// ```
+pub fn leiden<G, F>(graph: G, edge_weight: F, resolution: f64, seed: u64) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (mut network, compact) = Network::new(graph, edge_weight);
+    let mut rng = SplitMix64::new(seed);
+    let mut membership: Vec<usize> = (0..network.len()).collect();
+    let mut partition = Partition::singletons(&network);
+    loop {
+        partition.move_nodes_fast(&network, resolution, &mut rng);
+        let (communities, count) = renumber(&partition.community);
+        if count == network.len() {
+            break;
+        }
+        let (refined, refined_count) = refine(&network, &communities, resolution, &mut rng);
+        // If no sub-community could be formed, aggregate the communities themselves so
+        // that the network keeps shrinking.
+        let (refined, refined_count) = if refined_count == network.len() {
+            (communities.clone(), count)
+        } else {
+            (refined, refined_count)
+        };
+        let mut parents = vec![0; refined_count];
+        for (v, &r) in refined.iter().enumerate() {
+            parents[r] = communities[v];
+        }
+        for m in &mut membership {
+            *m = refined[*m];
+        }
+        network = network.aggregate(&refined, refined_count);
+        partition = Partition::new(&network, parents);
+    }
+    let (communities, _) = renumber(&partition.community);
+    for m in &mut membership {
+        *m = communities[*m];
+    }
+    labeling(&compact, &membership)
+}
+
+/// Asynchronous label propagation.
+///
+/// Partitions the nodes of a graph into communities with the asynchronous
+/// [label propagation algorithm][lpa]. Every node starts with its own label; then, in
+/// random order, each node adopts the label with the largest total edge weight among
+/// its neighbors, ties being broken at random. A node keeps its label if it is one of
+/// the best ones. The algorithm stops when every node has one of the best labels.
+///
+/// Edge directions and self-loops are ignored, and edge weights, given by `edge_weight`,
+/// must be non-negative. The random choices are drawn from `seed`, so that the result
+/// is deterministic for a given seed.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+/// * `seed`: the seed of the random node orders and tie breaks.
+///
+/// # Returns
+/// * A `Vec` mapping each node index to its community, numbered from `0` in node index
+///   order. Indices that are not used by a node are mapped to `usize::MAX`.
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V| + |E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **k** is the number of passes over the nodes, **|V|** is the number of nodes
+/// and **|E|** is the number of edges.
+///
+/// [lpa]: https://en.wikipedia.org/wiki/Label_propagation_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::label_propagation;
+///
+/// // Two 4-cliques joined by a single edge.
+/// let mut graph = UnGraph::<(), ()>::new_undirected();
+/// for _ in 0..8 {
+///     graph.add_node(());
+/// }
+/// graph.extend_with_edges(&[
+///     (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3),
+///     (4, 5), (4, 6), (4, 7), (5, 6), (5, 7), (6, 7),
+///     (3, 4),
+/// ]);
+///
+/// let communities = label_propagation(&graph, |_| 1., 3);
+/// assert_eq!(communities, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+/// ```
+pub fn label_propagation<G, F>(graph: G, edge_weight: F, seed: u64) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (network, compact) = Network::new(graph, edge_weight);
+    let mut rng = SplitMix64::new(seed);
+    let mut labels: Vec<usize> = (0..network.len()).collect();
+    let mut order = labels.clone();
+    let mut weights = NeighborWeights::new(network.len());
+    let mut best = Vec::new();
+    loop {
+        rng.shuffle(&mut order);
+        let mut changed = false;
+        for &v in &order {
+            weights.collect(&network, v, |u| labels[u]);
+            let max = weights
+                .touched
+                .iter()
+                .map(|&label| weights.weights[label])
+                .fold(f64::NEG_INFINITY, f64::max);
+            best.clear();
+            best.extend(
+                weights
+                    .touched
+                    .iter()
+                    .copied()
+                    .filter(|&label| weights.weights[label] == max),
+            );
+            if !best.is_empty() && !best.contains(&labels[v]) {
+                labels[v] = best[rng.gen_index(best.len())];
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let (labels, _) = renumber(&labels);
+    labeling(&compact, &labels)
+}
+
+/// Modularity of a partition.
+///
+/// Computes the [modularity][md] of a partition of the nodes of a graph into
+/// communities: the fraction of the edge weight that lies within communities, minus
+/// the fraction expected if the edges were placed at random with the same node degrees,
+/// scaled by `resolution`.
+///
+/// Edge directions are ignored, and edge weights, given by `edge_weight`, must be
+/// non-negative. A self-loop adds twice its weight to the degree of its node.
+///
+/// # Arguments
+/// * `graph`: a directed or undirected graph.
+/// * `partition`: a slice mapping each node index to its community label, like the
+///   ones returned by [`louvain`], [`leiden`] or [`label_propagation`]. The labels can
+///   be arbitrary numbers.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+/// * `resolution`: the resolution parameter, `1.0` for the standard modularity.
+///
+/// # Returns
+/// * The modularity of the partition, or `0.0` if the graph has no edge weight.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [md]: https://en.wikipedia.org/wiki/Modularity_(networks)
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::modularity;
+///
+/// // a - b   c - d
+/// let mut graph = UnGraph::<(), ()>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (c, d)]);
+///
+/// assert_eq!(modularity(&graph, &[0, 0, 1, 1], |_| 1., 1.), 0.5);
+/// assert_eq!(modularity(&graph, &[0, 0, 0, 0], |_| 1., 1.), 0.);
+/// ```
+pub fn modularity<G, F>(graph: G, partition: &[usize], mut edge_weight: F, resolution: f64) -> f64
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let mut internal = 0.;
+    let mut total = 0.;
+    let mut degrees: HashMap<usize, f64> = HashMap::new();
+    for edge in graph.edge_references() {
+        let source = partition[graph.to_index(edge.source())];
+        let target = partition[graph.to_index(edge.target())];
+        let weight = edge_weight(edge);
+        total += weight;
+        *degrees.entry(source).or_default() += weight;
+        *degrees.entry(target).or_default() += weight;
+        if source == target {
+            internal += weight;
+        }
+    }
+    if total == 0. {
+        return 0.;
+    }
+    let expected: f64 = degrees
+        .values()
+        .map(|degree| {
+            let fraction = degree / (2. * total);
+            fraction * fraction
+        })
+        .sum();
+    internal / total - resolution * expected
+}
+
+/// An undirected weighted network with compact node indices.
+struct Network {
+    /// The neighbors of each node, with the weights of the edges to them.
+    adjacency: Vec<Vec<(usize, f64)>>,
+    /// The total weight of the self-loops of each node.
+    self_loops: Vec<f64>,
+    /// The weighted degree of each node, where self-loops count twice.
+    degrees: Vec<f64>,
+    /// The sum of the degrees, twice the total edge weight.
+    total: f64,
+}
+
+impl Network {
+    /// Build the network of `graph`, with the compact index of each node index
+    /// (`usize::MAX` for unused indices).
+    fn new<G, F>(graph: G, mut edge_weight: F) -> (Self, Vec<usize>)
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> f64,
+    {
+        let mut compact = vec![usize::MAX; graph.node_bound()];
+        let mut n = 0;
+        for node in graph.node_identifiers() {
+            compact[graph.to_index(node)] = n;
+            n += 1;
+        }
+        let mut adjacency = vec![Vec::new(); n];
+        let mut self_loops = vec![0.; n];
+        for edge in graph.edge_references() {
+            let u = compact[graph.to_index(edge.source())];
+            let v = compact[graph.to_index(edge.target())];
+            let weight = edge_weight(edge);
+            if u == v {
+                self_loops[u] += weight;
+            } else {
+                adjacency[u].push((v, weight));
+                adjacency[v].push((u, weight));
+            }
+        }
+        (Network::from_parts(adjacency, self_loops), compact)
+    }
+
+    fn from_parts(adjacency: Vec<Vec<(usize, f64)>>, self_loops: Vec<f64>) -> Self {
+        let degrees: Vec<f64> = adjacency
+            .iter()
+            .zip(&self_loops)
+            .map(|(neighbors, &self_loop)| {
+                neighbors.iter().map(|&(_, weight)| weight).sum::<f64>() + 2. * self_loop
+            })
+            .collect();
+        let total = degrees.iter().sum();
+        Network {
+            adjacency,
+            self_loops,
+            degrees,
+            total,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.degrees.len()
+    }
+
+    /// Merge the nodes of each of the `count` communities into a single node.
+    fn aggregate(&self, communities: &[usize], count: usize) -> Self {
+        let mut adjacency = vec![Vec::new(); count];
+        let mut self_loops = vec![0.; count];
+        for (u, neighbors) in self.adjacency.iter().enumerate() {
+            let cu = communities[u];
+            self_loops[cu] += self.self_loops[u];
+            for &(v, weight) in neighbors {
+                let cv = communities[v];
+                if cu == cv {
+                    // Each internal edge is seen from both of its ends.
+                    self_loops[cu] += weight / 2.;
+                } else {
+                    adjacency[cu].push((cv, weight));
+                }
+            }
+        }
+        for neighbors in &mut adjacency {
+            neighbors.sort_unstable_by_key(|&(v, _)| v);
+            let mut merged: Vec<(usize, f64)> = Vec::with_capacity(neighbors.len());
+            for &(v, weight) in neighbors.iter() {
+                match merged.last_mut() {
+                    Some(last) if last.0 == v => last.1 += weight,
+                    _ => merged.push((v, weight)),
+                }
+            }
+            *neighbors = merged;
+        }
+        Network::from_parts(adjacency, self_loops)
+    }
+}
+
+/// The total weight of the edges from a node to each group of nodes, with the groups
+/// that were reached.
+struct NeighborWeights {
+    weights: Vec<f64>,
+    reached: Vec<bool>,
+    touched: Vec<usize>,
+}
+
+impl NeighborWeights {
+    fn new(len: usize) -> Self {
+        NeighborWeights {
+            weights: vec![0.; len],
+            reached: vec![false; len],
+            touched: Vec::new(),
+        }
+    }
+
+    /// Collect the weights of the edges from `v` to the groups of its neighbors, given
+    /// by `group`.
+    fn collect<H>(&mut self, network: &Network, v: usize, mut group: H)
+    where
+        H: FnMut(usize) -> usize,
+    {
+        for &c in &self.touched {
+            self.weights[c] = 0.;
+            self.reached[c] = false;
+        }
+        self.touched.clear();
+        for &(u, weight) in &network.adjacency[v] {
+            let c = group(u);
+            if !self.reached[c] {
+                self.reached[c] = true;
+                self.touched.push(c);
+            }
+            self.weights[c] += weight;
+        }
+    }
+}
+
+/// A partition of the nodes of a network, with the total degree of each community.
+struct Partition {
+    community: Vec<usize>,
+    totals: Vec<f64>,
+    sizes: Vec<usize>,
+    /// Community ids without nodes.
+    empty: Vec<usize>,
+}
+
+impl Partition {
+    fn singletons(network: &Network) -> Self {
+        Partition::new(network, (0..network.len()).collect())
+    }
+
+    /// The partition given by `community`, whose labels must be smaller than the
+    /// number of nodes.
+    fn new(network: &Network, community: Vec<usize>) -> Self {
+        let mut totals = vec![0.; network.len()];
+        let mut sizes = vec![0; network.len()];
+        for (v, &c) in community.iter().enumerate() {
+            totals[c] += network.degrees[v];
+            sizes[c] += 1;
+        }
+        let empty = (0..network.len())
+            .rev()
+            .filter(|&c| sizes[c] == 0)
+            .collect();
+        Partition {
+            community,
+            totals,
+            sizes,
+            empty,
+        }
+    }
+
+    /// Move `v` to the community that most increases the modularity, possibly an
+    /// empty one if `allow_empty` is set. Return `true` if it changed community.
+    fn move_node(
+        &mut self,
+        network: &Network,
+        weights: &mut NeighborWeights,
+        v: usize,
+        resolution: f64,
+        allow_empty: bool,
+    ) -> bool {
+        let current = self.community[v];
+        let degree = network.degrees[v];
+        let community = &self.community;
+        weights.collect(network, v, |u| community[u]);
+        self.totals[current] -= degree;
+        self.sizes[current] -= 1;
+
+        let scale = resolution * degree / network.total;
+        let gain = |c: usize| weights.weights[c] - scale * self.totals[c];
+        let mut best = current;
+        let mut best_gain = gain(current);
+        for &c in &weights.touched {
+            let c_gain = gain(c);
+            if c_gain > best_gain {
+                best = c;
+                best_gain = c_gain;
+            }
+        }
+        if allow_empty && best_gain < 0. && self.sizes[current] > 0 {
+            // The partition has at most as many communities as nodes, and the current
+            // one is not empty, so there is an empty community.
+            best = self.empty.pop().unwrap();
+        }
+
+        if self.sizes[current] == 0 && best != current {
+            self.empty.push(current);
+        }
+        self.community[v] = best;
+        self.totals[best] += degree;
+        self.sizes[best] += 1;
+        best != current
+    }
+
+    /// Louvain local moving phase. Return `true` if any node changed community.
+    fn move_nodes(&mut self, network: &Network, resolution: f64, rng: &mut SplitMix64) -> bool {
+        let mut weights = NeighborWeights::new(network.len());
+        let mut order: Vec<usize> = (0..network.len()).collect();
+        let mut moved = false;
+        loop {
+            rng.shuffle(&mut order);
+            let mut pass_moved = false;
+            for &v in &order {
+                if self.move_node(network, &mut weights, v, resolution, false) {
+                    pass_moved = true;
+                }
+            }
+            if !pass_moved {
+                return moved;
+            }
+            moved = true;
+        }
+    }
+
+    /// Leiden fast local moving phase, driven by a queue of the nodes to visit.
+    fn move_nodes_fast(&mut self, network: &Network, resolution: f64, rng: &mut SplitMix64) {
+        let mut weights = NeighborWeights::new(network.len());
+        let mut order: Vec<usize> = (0..network.len()).collect();
+        rng.shuffle(&mut order);
+        let mut queued = vec![true; network.len()];
+        let mut queue: VecDeque<usize> = order.into_iter().collect();
+        while let Some(v) = queue.pop_front() {
+            queued[v] = false;
+            if self.move_node(network, &mut weights, v, resolution, true) {
+                let community = self.community[v];
+                for &(u, _) in &network.adjacency[v] {
+                    if !queued[u] && self.community[u] != community {
+                        queued[u] = true;
+                        queue.push_back(u);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Leiden refinement phase: split each community into well-connected sub-communities,
+/// by merging singletons greedily. Return the dense sub-community labels and their number.
+fn refine(
+    network: &Network,
+    communities: &[usize],
+    resolution: f64,
+    rng: &mut SplitMix64,
+) -> (Vec<usize>, usize) {
+    let n = network.len();
+    let mut community_totals = vec![0.; n];
+    for (v, &c) in communities.iter().enumerate() {
+        community_totals[c] += network.degrees[v];
+    }
+    let mut refined: Vec<usize> = (0..n).collect();
+    let mut totals = network.degrees.clone();
+    let mut sizes = vec![1; n];
+    // The weight of the edges from each sub-community to the rest of its community.
+    let mut external: Vec<f64> = (0..n)
+        .map(|v| {
+            network.adjacency[v]
+                .iter()
+                .filter(|&&(u, _)| communities[u] == communities[v])
+                .map(|&(_, weight)| weight)
+                .sum()
+        })
+        .collect();
+
+    let mut weights = NeighborWeights::new(n);
+    let mut order: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut order);
+    for v in order {
+        if sizes[refined[v]] > 1 {
+            continue;
+        }
+        let community = communities[v];
+        let degree = network.degrees[v];
+        let community_total = community_totals[community];
+        let well_connected = |external: f64, total: f64| {
+            external >= resolution * total * (community_total - total) / network.total
+        };
+        if !well_connected(external[v], degree) {
+            continue;
+        }
+
+        weights.collect(network, v, |u| {
+            if communities[u] == community {
+                refined[u]
+            } else {
+                // Nodes of other communities are grouped with v itself, and ignored.
+                refined[v]
+            }
+        });
+        let own = refined[v];
+        let scale = resolution * degree / network.total;
+        let mut best = None;
+        let mut best_gain = 0.;
+        for &c in &weights.touched {
+            if c == own || !well_connected(external[c], totals[c]) {
+                continue;
+            }
+            let gain = weights.weights[c] - scale * totals[c];
+            if gain > best_gain {
+                best = Some(c);
+                best_gain = gain;
+            }
+        }
+        if let Some(c) = best {
+            refined[v] = c;
+            totals[c] += degree;
+            sizes[c] += 1;
+            sizes[own] = 0;
+            external[c] = external[c] + external[v] - 2. * weights.weights[c];
+        }
+    }
+    renumber(&refined)
+}
+
+/// Relabel `labels` with consecutive numbers, in order of first appearance. Return the
+/// new labels and their number.
+fn renumber(labels: &[usize]) -> (Vec<usize>, usize) {
+    let mut new_labels: HashMap<usize, usize> = HashMap::new();
+    let renumbered = labels
+        .iter()
+        .map(|&label| {
+            let next = new_labels.len();
+            *new_labels.entry(label).or_insert(next)
+        })
+        .collect();
+    (renumbered, new_labels.len())
+}
+
+/// Map each node index to the community of its compact index.
+fn labeling(compact: &[usize], communities: &[usize]) -> Vec<usize> {
+    compact
+        .iter()
+        .map(|&v| {
+            if v == usize::MAX {
+                usize::MAX
+            } else {
+                communities[v]
+            }
+        })
+        .collect()
+}
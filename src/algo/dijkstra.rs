@@ -1,10 +1,11 @@
-use alloc::collections::BinaryHeap;
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
 use core::hash::Hash;
 
 use hashbrown::hash_map::{
     Entry::{Occupied, Vacant},
     HashMap,
 };
+use hashbrown::HashSet;
 
 use crate::algo::Measure;
 use crate::scored::MinScored;
@@ -136,3 +137,169 @@ where
     }
     scores
 }
+
+/// Shortest path costs and predecessors computed by [`dijkstra_with_paths`].
+#[derive(Debug, Clone)]
+pub struct DijkstraPaths<N, E, K>
+where
+    N: Eq + Hash,
+{
+    /// Maps every reached node to its path cost.
+    pub scores: HashMap<N, K>,
+    /// Maps every reached node, except the start nodes, to its predecessor
+    /// node and the edge leading from it.
+    pub predecessors: HashMap<N, (N, E)>,
+}
+
+impl<N, E, K> DijkstraPaths<N, E, K>
+where
+    N: Copy + Eq + Hash,
+    E: Copy,
+    K: Copy,
+{
+    /// Return the path cost of `target`, if it was reached.
+    pub fn cost(&self, target: N) -> Option<K> {
+        self.scores.get(&target).copied()
+    }
+
+    /// Return the path from one of the start nodes to `target` as a sequence of
+    /// nodes and the sequence of edges connecting them.
+    ///
+    /// The node sequence starts with a start node and ends with `target`, so it
+    /// always holds one more element than the edge sequence.
+    ///
+    /// Return `None` if `target` was not reached.
+    pub fn path_to(&self, target: N) -> Option<(Vec<N>, Vec<E>)> {
+        if !self.scores.contains_key(&target) {
+            return None;
+        }
+
+        let mut nodes = vec![target];
+        let mut edges = Vec::new();
+        let mut current = target;
+        while let Some(&(previous, edge)) = self.predecessors.get(&current) {
+            nodes.push(previous);
+            edges.push(edge);
+            current = previous;
+        }
+
+        nodes.reverse();
+        edges.reverse();
+        Some((nodes, edges))
+    }
+}
+
+/// Dijkstra's shortest path algorithm with path reconstruction.
+///
+/// Compute the length of the shortest path from the closest of the `starts` nodes to
+/// every reachable node, recording the predecessor edge of each node so that the
+/// actual paths can be recovered with [`DijkstraPaths::path_to`].
+///
+/// The function `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative.
+///
+/// If `goals` is not empty, then the algorithm terminates once the cost of every
+/// `goals` node is calculated. Costs and paths of the nodes that were not finalized by
+/// then are upper bounds.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `starts`: the start nodes.
+/// * `goals`: the *goal* nodes, possibly empty.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * [`DijkstraPaths`]: the path cost and predecessor of every reached node.
+///
+/// # Complexity
+/// * Time complexity: **O((|V|+|E|)log(|V|))**.
+/// * Auxiliary space: **O(|V|+|E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::dijkstra_with_paths;
+///
+/// let mut graph = Graph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let ab = graph.add_edge(a, b, 1);
+/// let bc = graph.add_edge(b, c, 2);
+/// graph.add_edge(a, c, 4);
+/// let cd = graph.add_edge(c, d, 1);
+///
+/// // a --1-- b --2-- c --1-- d
+/// //  \______4______/
+///
+/// let paths = dijkstra_with_paths(&graph, [a], [], |e| *e.weight());
+/// assert_eq!(paths.cost(d), Some(4));
+/// assert_eq!(paths.path_to(d), Some((vec![a, b, c, d], vec![ab, bc, cd])));
+///
+/// // With several start nodes, every path begins at the closest one.
+/// let paths = dijkstra_with_paths(&graph, [a, d], [b], |e| *e.weight());
+/// assert_eq!(paths.path_to(b), Some((vec![a, b], vec![ab])));
+/// ```
+pub fn dijkstra_with_paths<G, S, T, F, K>(
+    graph: G,
+    starts: S,
+    goals: T,
+    mut edge_cost: F,
+) -> DijkstraPaths<G::NodeId, G::EdgeId, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    S: IntoIterator<Item = G::NodeId>,
+    T: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut visited = graph.visit_map();
+    let mut scores = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut visit_next = BinaryHeap::new();
+    let mut goals: HashSet<G::NodeId> = goals.into_iter().collect();
+    let has_goals = !goals.is_empty();
+    let zero_score = K::default();
+    for start in starts {
+        scores.insert(start, zero_score);
+        visit_next.push(MinScored(zero_score, start));
+    }
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+        if has_goals && goals.remove(&node) && goals.is_empty() {
+            break;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            match scores.entry(next) {
+                Occupied(ent) => {
+                    if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                        visit_next.push(MinScored(next_score, next));
+                        predecessors.insert(next, (node, edge.id()));
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                    visit_next.push(MinScored(next_score, next));
+                    predecessors.insert(next, (node, edge.id()));
+                }
+            }
+        }
+        visited.visit(node);
+    }
+    DijkstraPaths {
+        scores,
+        predecessors,
+    }
+}
@@ -1,11 +1,16 @@
 use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::fmt;
 use core::hash::Hash;
 
-use hashbrown::HashMap;
+use hashbrown::hash_map::{
+    Entry::{Occupied, Vacant},
+    HashMap,
+};
+use hashbrown::HashSet;
 
 use crate::algo::Measure;
 use crate::scored::MinScored;
-use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable, Visitable};
+use crate::visit::{EdgeRef, GraphBase, IntoEdges, NodeCount, NodeIndexable, VisitMap, Visitable};
 
 /// k'th shortest path algorithm.
 ///
@@ -124,3 +129,320 @@ where
     }
     scores
 }
+
+/// Yen's k shortest simple paths algorithm.
+///
+/// Return an iterator over the simple paths (paths without repeating nodes) from `source` to
+/// `target`, in order of increasing cost. Paths are computed lazily: each call to `next`
+/// computes only the next best path, so the first **k** paths can be obtained with
+/// [`Iterator::take`].
+///
+/// Paths are told apart by their edges, so parallel edges give rise to distinct paths.
+///
+/// The iterator yields tuples `(cost, nodes, edges)`, where `nodes` is the sequence of nodes
+/// from `source` to `target` and `edges` the sequence of edges connecting them.
+///
+/// # Arguments
+/// * `graph`: an input graph.
+/// * `source`: the *source* node.
+/// * `target`: the *target* node.
+/// * `edge_cost`: closure that should return the cost for a particular edge, which is used
+///   to compute path costs. Edge costs must be non-negative.
+///
+/// # Returns
+/// * [`ShortestSimplePaths`]: an iterator over the simple paths from `source` to `target`,
+///   cheapest first.
+///
+/// # Complexity
+/// * Time complexity: for computing the first **k** paths, **O(k|V|(|E| + |V|log(|V|)))**.
+/// * Auxiliary space: **O(k|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::shortest_simple_paths;
+///
+/// let mut graph = Graph::new();
+/// let c = graph.add_node("C");
+/// let d = graph.add_node("D");
+/// let e = graph.add_node("E");
+/// let f = graph.add_node("F");
+/// let g = graph.add_node("G");
+/// let h = graph.add_node("H");
+/// graph.extend_with_edges(&[
+///     (c, d, 3),
+///     (c, e, 2),
+///     (d, f, 4),
+///     (e, d, 1),
+///     (e, f, 2),
+///     (e, g, 3),
+///     (f, g, 2),
+///     (f, h, 1),
+///     (g, h, 2),
+/// ]);
+///
+/// let paths: Vec<_> = shortest_simple_paths(&graph, c, h, |e| *e.weight())
+///     .take(3)
+///     .map(|(cost, nodes, _edges)| (cost, nodes))
+///     .collect();
+/// assert_eq!(
+///     paths,
+///     vec![
+///         (5, vec![c, e, f, h]),
+///         (7, vec![c, e, g, h]),
+///         (8, vec![c, d, f, h]),
+///     ]
+/// );
+/// ```
+pub fn shortest_simple_paths<G, F, K>(
+    graph: G,
+    source: G::NodeId,
+    target: G::NodeId,
+    edge_cost: F,
+) -> ShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    ShortestSimplePaths {
+        graph,
+        source,
+        target,
+        edge_cost,
+        started: false,
+        found: Vec::new(),
+        candidates: BinaryHeap::new(),
+        seen: HashSet::new(),
+    }
+}
+
+/// A path found by [`ShortestSimplePaths`], along with the cost of each of its prefixes.
+#[derive(Clone)]
+struct CostedPath<N, E, K> {
+    nodes: Vec<N>,
+    edges: Vec<E>,
+    /// `costs[i]` is the cost of the path from the source to `nodes[i]`.
+    costs: Vec<K>,
+}
+
+impl<N, E, K: Copy> CostedPath<N, E, K> {
+    fn cost(&self) -> K {
+        self.costs[self.costs.len() - 1]
+    }
+}
+
+type Candidate<G, K> =
+    MinScored<K, CostedPath<<G as GraphBase>::NodeId, <G as GraphBase>::EdgeId, K>>;
+
+/// An iterator over the simple paths between two nodes, cheapest first.
+///
+/// Created by [`shortest_simple_paths`].
+pub struct ShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges,
+{
+    graph: G,
+    source: G::NodeId,
+    target: G::NodeId,
+    edge_cost: F,
+    started: bool,
+    /// The paths yielded so far.
+    found: Vec<CostedPath<G::NodeId, G::EdgeId, K>>,
+    /// Candidates for the next path.
+    candidates: BinaryHeap<Candidate<G, K>>,
+    /// The edge sequences of all paths that were ever found or proposed as candidates.
+    seen: HashSet<Vec<G::EdgeId>>,
+}
+
+impl<G, F, K> fmt::Debug for ShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges,
+    G::NodeId: fmt::Debug,
+    G::EdgeId: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShortestSimplePaths")
+            .field("source", &self.source)
+            .field("target", &self.target)
+            .field("started", &self.started)
+            .field("found", &self.found.len())
+            .field("candidates", &self.candidates.len())
+            .finish()
+    }
+}
+
+impl<G, F, K> ShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    /// Compute the deviations of the last found path and add them to the candidates.
+    fn add_candidates(&mut self) {
+        let last = match self.found.last() {
+            Some(last) => last.clone(),
+            None => return,
+        };
+
+        let mut blocked_nodes = HashSet::new();
+        let mut blocked_edges = HashSet::new();
+        for i in 0..last.edges.len() {
+            let spur_node = last.nodes[i];
+            let root_edges = &last.edges[..i];
+
+            // Block the edges leaving the spur node along every found path sharing this root.
+            blocked_edges.clear();
+            for path in &self.found {
+                if path.edges.len() > i && path.edges[..i] == *root_edges {
+                    blocked_edges.insert(path.edges[i]);
+                }
+            }
+
+            if let Some(spur) = restricted_dijkstra(
+                self.graph,
+                spur_node,
+                self.target,
+                &mut self.edge_cost,
+                &blocked_nodes,
+                &blocked_edges,
+            ) {
+                let root_cost = last.costs[i];
+                let mut edges = root_edges.to_vec();
+                edges.extend_from_slice(&spur.edges);
+                if self.seen.insert(edges.clone()) {
+                    let mut nodes = last.nodes[..i].to_vec();
+                    nodes.extend_from_slice(&spur.nodes);
+                    let mut costs = last.costs[..i].to_vec();
+                    costs.extend(spur.costs.iter().map(|&cost| root_cost + cost));
+                    let path = CostedPath {
+                        nodes,
+                        edges,
+                        costs,
+                    };
+                    self.candidates.push(MinScored(path.cost(), path));
+                }
+            }
+
+            // The root of the following deviations passes through the spur node.
+            blocked_nodes.insert(spur_node);
+        }
+    }
+}
+
+impl<G, F, K> Iterator for ShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    type Item = (K, Vec<G::NodeId>, Vec<G::EdgeId>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let path = if !self.started {
+            self.started = true;
+            let path = restricted_dijkstra(
+                self.graph,
+                self.source,
+                self.target,
+                &mut self.edge_cost,
+                &HashSet::new(),
+                &HashSet::new(),
+            )?;
+            self.seen.insert(path.edges.clone());
+            path
+        } else {
+            self.add_candidates();
+            self.candidates.pop()?.1
+        };
+
+        let item = (path.cost(), path.nodes.clone(), path.edges.clone());
+        self.found.push(path);
+        Some(item)
+    }
+}
+
+/// Dijkstra's shortest path from `start` to `goal` avoiding the given nodes and edges.
+fn restricted_dijkstra<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    edge_cost: &mut F,
+    blocked_nodes: &HashSet<G::NodeId>,
+    blocked_edges: &HashSet<G::EdgeId>,
+) -> Option<CostedPath<G::NodeId, G::EdgeId, K>>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut visited = graph.visit_map();
+    let mut scores = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut visit_next = BinaryHeap::new();
+    let zero_score = K::default();
+    scores.insert(start, zero_score);
+    visit_next.push(MinScored(zero_score, start));
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if node == goal {
+            break;
+        }
+        if !visited.visit(node) {
+            continue;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next)
+                || blocked_nodes.contains(&next)
+                || blocked_edges.contains(&edge.id())
+            {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            match scores.entry(next) {
+                Occupied(ent) => {
+                    if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                        visit_next.push(MinScored(next_score, next));
+                        predecessors.insert(next, (node, edge.id()));
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                    visit_next.push(MinScored(next_score, next));
+                    predecessors.insert(next, (node, edge.id()));
+                }
+            }
+        }
+    }
+
+    if !scores.contains_key(&goal) {
+        return None;
+    }
+    let mut nodes = vec![goal];
+    let mut edges = Vec::new();
+    let mut current = goal;
+    while let Some(&(previous, edge)) = predecessors.get(&current) {
+        nodes.push(previous);
+        edges.push(edge);
+        current = previous;
+    }
+    nodes.reverse();
+    edges.reverse();
+    let costs = nodes.iter().map(|node| scores[node]).collect();
+    Some(CostedPath {
+        nodes,
+        edges,
+        costs,
+    })
+}
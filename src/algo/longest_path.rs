@@ -0,0 +1,220 @@
+//! Longest paths and critical path analysis on directed acyclic graphs.
+
+use alloc::{vec, vec::Vec};
+use core::ops::Sub;
+
+use crate::algo::{toposort, Cycle, Measure};
+use crate::visit::{
+    EdgeRef, IntoEdges, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable, Visitable,
+};
+
+/// The result of [`critical_path`].
+///
+/// The vectors are indexed by the graph's node indices, as given by
+/// [`NodeIndexable::to_index`]; entries of indices without a node are zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CriticalPath<N, K> {
+    /// The length of the longest path, i.e. the duration of the whole schedule.
+    pub length: K,
+    /// The nodes of a longest path, in order.
+    pub path: Vec<N>,
+    /// The earliest start of each node: the length of the longest path ending at it.
+    pub earliest_start: Vec<K>,
+    /// The latest start of each node that does not delay the whole schedule.
+    pub latest_start: Vec<K>,
+    /// The slack of each node, i.e. the difference between its latest and earliest start.
+    pub slack: Vec<K>,
+}
+
+/// Compute a longest path of a directed acyclic graph.
+///
+/// Paths may start and end at any node. A single node is a path of length zero,
+/// so that the length of a longest path is never negative.
+///
+/// # Arguments
+/// * `graph`: a directed acyclic graph.
+/// * `edge_weight`: closure that returns the weight of an edge.
+///
+/// # Returns
+/// * `Ok`: the length of a longest path and its nodes, in order. The path is empty
+///   if the graph has no nodes.
+/// * `Err`: [`Cycle`] if the graph is not acyclic.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::longest_path;
+/// use petgraph::prelude::*;
+///
+/// let mut graph = DiGraph::<(), u32>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges([(a, b, 3), (a, c, 1), (b, d, 2), (c, d, 5)]);
+///
+/// assert_eq!(longest_path(&graph, |e| *e.weight()), Ok((6, vec![a, c, d])));
+///
+/// graph.add_edge(d, a, 1);
+/// assert!(longest_path(&graph, |e| *e.weight()).is_err());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn longest_path<G, F, K>(
+    graph: G,
+    edge_weight: F,
+) -> Result<(K, Vec<G::NodeId>), Cycle<G::NodeId>>
+where
+    G: IntoEdges + IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let order = toposort(graph, None)?;
+    let (earliest_start, path) = earliest_starts(graph, &order, edge_weight);
+    let length = path
+        .last()
+        .map_or_else(K::default, |&n| earliest_start[graph.to_index(n)]);
+    Ok((length, path))
+}
+
+/// Compute the critical path method schedule of a directed acyclic graph.
+///
+/// The nodes are events and the edges are tasks whose duration is given by
+/// `edge_weight`: a task can start once all the tasks leading to its source are
+/// done. Every node is given its earliest start, the latest start that does not
+/// delay the whole schedule, and the slack between them. With non-negative
+/// durations, the nodes of a longest path, the *critical path*, have no slack.
+///
+/// # Arguments
+/// * `graph`: a directed acyclic graph.
+/// * `edge_weight`: closure that returns the duration of an edge.
+///
+/// # Returns
+/// * `Ok`: a [`CriticalPath`] with the schedule.
+/// * `Err`: [`Cycle`] if the graph is not acyclic.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::critical_path;
+/// use petgraph::prelude::*;
+///
+/// // Laying the foundation takes 3 days, then the walls and the plumbing
+/// // take 4 and 2 days respectively, before the roof can be done in 2 days.
+/// let mut graph = DiGraph::<&str, u32>::new();
+/// let start = graph.add_node("start");
+/// let foundation = graph.add_node("foundation laid");
+/// let walls = graph.add_node("walls built");
+/// let plumbing = graph.add_node("plumbing installed");
+/// let end = graph.add_node("roof done");
+/// graph.extend_with_edges([
+///     (start, foundation, 3),
+///     (foundation, walls, 4),
+///     (foundation, plumbing, 2),
+///     (walls, end, 2),
+///     (plumbing, end, 0),
+/// ]);
+///
+/// let schedule = critical_path(&graph, |e| *e.weight()).unwrap();
+/// assert_eq!(schedule.length, 9);
+/// assert_eq!(schedule.path, vec![start, foundation, walls, end]);
+/// assert_eq!(schedule.earliest_start, vec![0, 3, 7, 5, 9]);
+/// assert_eq!(schedule.latest_start, vec![0, 3, 7, 9, 9]);
+/// assert_eq!(schedule.slack, vec![0, 0, 0, 4, 0]);
+/// ```
+pub fn critical_path<G, F, K>(
+    graph: G,
+    mut edge_weight: F,
+) -> Result<CriticalPath<G::NodeId, K>, Cycle<G::NodeId>>
+where
+    G: IntoEdges + IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let order = toposort(graph, None)?;
+    let (earliest_start, path) = earliest_starts(graph, &order, &mut edge_weight);
+    let length = path
+        .last()
+        .map_or_else(K::default, |&n| earliest_start[graph.to_index(n)]);
+
+    // Go backwards from the end of the schedule, for the latest starts.
+    let mut latest_start = vec![K::default(); graph.node_bound()];
+    for &node in order.iter().rev() {
+        let mut latest = length;
+        for edge in graph.edges(node) {
+            let start = latest_start[graph.to_index(edge.target())] - edge_weight(edge);
+            if start < latest {
+                latest = start;
+            }
+        }
+        latest_start[graph.to_index(node)] = latest;
+    }
+
+    let mut slack = vec![K::default(); graph.node_bound()];
+    for &node in &order {
+        let i = graph.to_index(node);
+        slack[i] = latest_start[i] - earliest_start[i];
+    }
+
+    Ok(CriticalPath {
+        length,
+        path,
+        earliest_start,
+        latest_start,
+        slack,
+    })
+}
+
+/// Compute the length of the longest path ending at each node, indexed by node
+/// index, and a longest path of the graph, given a topological `order` of it.
+fn earliest_starts<G, F, K>(
+    graph: G,
+    order: &[G::NodeId],
+    mut edge_weight: F,
+) -> (Vec<K>, Vec<G::NodeId>)
+where
+    G: IntoEdges + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut earliest_start = vec![K::default(); graph.node_bound()];
+    let mut predecessor = vec![None; graph.node_bound()];
+    for &node in order {
+        let start = earliest_start[graph.to_index(node)];
+        for edge in graph.edges(node) {
+            let j = graph.to_index(edge.target());
+            let next_start = start + edge_weight(edge);
+            if next_start > earliest_start[j] {
+                earliest_start[j] = next_start;
+                predecessor[j] = Some(node);
+            }
+        }
+    }
+
+    // The first node of the topological order with the latest start ends a longest path.
+    let mut last = match order.first() {
+        Some(&node) => node,
+        None => return (earliest_start, Vec::new()),
+    };
+    for &node in order {
+        if earliest_start[graph.to_index(node)] > earliest_start[graph.to_index(last)] {
+            last = node;
+        }
+    }
+    let mut path = vec![last];
+    while let Some(node) = predecessor[graph.to_index(last)] {
+        path.push(node);
+        last = node;
+    }
+    path.reverse();
+    (earliest_start, path)
+}
@@ -1,12 +1,17 @@
 use alloc::{collections::VecDeque, vec, vec::Vec};
 use core::hash::Hash;
+use core::ops::{Div, Sub};
 
 use crate::visit::{
-    EdgeCount, EdgeIndexable, EdgeRef, GraphBase, IntoEdges, IntoNeighbors, IntoNodeIdentifiers,
-    NodeCount, NodeIndexable, VisitMap, Visitable,
+    EdgeCount, EdgeIndexable, EdgeRef, GraphBase, IntoEdgeReferences, IntoEdges, IntoNeighbors,
+    IntoNodeIdentifiers, NodeCount, NodeIndexable, VisitMap, Visitable,
 };
 
-use crate::{algo::ford_fulkerson, graph::NodeIndex, Directed, Graph};
+use crate::{
+    algo::{ford_fulkerson, BoundedMeasure, Measure},
+    graph::NodeIndex,
+    Directed, Graph,
+};
 
 /// Computed
 /// [*matching*](https://en.wikipedia.org/wiki/Matching_(graph_theory)#Definitions)
@@ -657,6 +662,1046 @@ where
     Matching::new(graph, mate, n_edges)
 }
 
+/// Compute a [*maximum weight matching*][1] of a general graph using
+/// [Edmonds' blossom algorithm][2], in the primal-dual formulation of Galil.
+///
+/// The function `edge_weight` should return the weight of a particular edge.
+/// The matching maximizes the total weight of its edges, so edges with a
+/// non-positive weight are never matched.
+///
+/// If `max_cardinality` is `true`, the matching is instead a maximum weight
+/// matching among the matchings of maximum cardinality, i.e. it has as many
+/// edges as [`maximum_matching`] and maximizes their total weight. The dual
+/// variables may then become negative, so the weight type must be signed.
+///
+/// Computations are exact for integer weights. The graph is considered
+/// undirected and self-loops are ignored.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+/// * `max_cardinality`: whether to only consider matchings of maximum cardinality.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+///
+/// # Returns
+/// * [`struct@Matching`]: computed maximum weight matching.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|³)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Maximum_weight_matching
+/// [2]: https://en.wikipedia.org/wiki/Blossom_algorithm
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::maximum_weight_matching;
+///
+/// // The example graph:
+/// //
+/// //      2       5       2
+/// //  a ----- b ----- c ----- d
+///
+/// let mut graph: UnGraph<(), i32> = UnGraph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 2), (b, c, 5), (c, d, 2)]);
+///
+/// // The single middle edge is heavier than both outer edges together...
+/// let matching = maximum_weight_matching(&graph, false, |e| *e.weight());
+/// assert_eq!(matching.len(), 1);
+/// assert!(matching.contains_edge(b, c));
+///
+/// // ...unless the matching has to be of maximum cardinality.
+/// let matching = maximum_weight_matching(&graph, true, |e| *e.weight());
+/// assert!(matching.is_perfect());
+/// assert!(matching.contains_edge(a, b));
+/// assert!(matching.contains_edge(c, d));
+/// ```
+pub fn maximum_weight_matching<G, F, K>(
+    graph: G,
+    max_cardinality: bool,
+    mut edge_weight: F,
+) -> Matching<G>
+where
+    G: NodeIndexable + IntoEdgeReferences,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy + Div<Output = K>,
+{
+    let n_vertices = graph.node_bound();
+    let edges: Vec<(usize, usize, K)> = graph
+        .edge_references()
+        .filter(|edge| edge.source() != edge.target())
+        .map(|edge| {
+            let source = graph.to_index(edge.source());
+            let target = graph.to_index(edge.target());
+            (source, target, edge_weight(edge))
+        })
+        .collect();
+
+    let mut blossom = WeightedBlossom::new(n_vertices, edges);
+    blossom.solve(max_cardinality);
+
+    let mut mate = vec![None; n_vertices];
+    let mut n_edges = 0;
+    for (v, p) in blossom.mate.iter().enumerate() {
+        if let Some(p) = *p {
+            mate[v] = Some(graph.from_index(blossom.endpoint(p)));
+            n_edges += 1;
+        }
+    }
+    Matching::new(graph, mate, n_edges / 2)
+}
+
+/// Vertex and blossom labels of the weighted blossom algorithm.
+const FREE: u8 = 0;
+const OUTER: u8 = 1;
+const INNER: u8 = 2;
+/// Breadcrumb set on outer blossoms while scanning for a common ancestor.
+const MARKED: u8 = 5;
+
+/// State of the weighted blossom algorithm.
+///
+/// Vertices are numbered `0..n`, and non-trivial blossoms `n..2n`. Edge `k`
+/// has the two endpoints `2k` (its source) and `2k + 1` (its target), so the
+/// remote endpoint of `p` is `p ^ 1`.
+///
+/// This follows the implementation of Joris van Rantwijk, based on
+/// "An O(EV log V) algorithm for finding a maximal weighted matching in
+/// general graphs" by Z. Galil, S. Micali and H. Gabow.
+struct WeightedBlossom<K> {
+    n: usize,
+    edges: Vec<(usize, usize, K)>,
+    /// The remote endpoints of the edges incident to each vertex.
+    neighbors: Vec<Vec<usize>>,
+    /// The remote endpoint of the matched edge of each vertex.
+    mate: Vec<Option<usize>>,
+    label: Vec<u8>,
+    /// The endpoint through which each labeled vertex or blossom got its label.
+    label_end: Vec<Option<usize>>,
+    /// The top-level blossom containing each vertex.
+    in_blossom: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    /// The endpoints of the edges connecting consecutive children of each blossom.
+    child_endpoints: Vec<Vec<usize>>,
+    base: Vec<Option<usize>>,
+    unused: Vec<usize>,
+    /// The least-slack edge to a different outer blossom, for each free
+    /// vertex and outer blossom.
+    best_edge: Vec<Option<usize>>,
+    /// The least-slack edges to each other outer blossom, for outer blossoms.
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    dual: Vec<K>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl<K> WeightedBlossom<K>
+where
+    K: BoundedMeasure + Copy + Div<Output = K>,
+{
+    fn new(n: usize, edges: Vec<(usize, usize, K)>) -> Self {
+        let zero = K::default();
+        let max_weight = edges
+            .iter()
+            .fold(zero, |max, &(_, _, w)| if w > max { w } else { max });
+        let mut neighbors = vec![Vec::new(); n];
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            neighbors[i].push(2 * k + 1);
+            neighbors[j].push(2 * k);
+        }
+        let mut dual = vec![max_weight; n];
+        dual.resize(2 * n, zero);
+        let n_edges = edges.len();
+        WeightedBlossom {
+            n,
+            edges,
+            neighbors,
+            mate: vec![None; n],
+            label: vec![FREE; 2 * n],
+            label_end: vec![None; 2 * n],
+            in_blossom: (0..n).collect(),
+            parent: vec![None; 2 * n],
+            children: vec![Vec::new(); 2 * n],
+            child_endpoints: vec![Vec::new(); 2 * n],
+            base: (0..n).map(Some).chain((0..n).map(|_| None)).collect(),
+            unused: (n..2 * n).collect(),
+            best_edge: vec![None; 2 * n],
+            blossom_best_edges: vec![None; 2 * n],
+            dual,
+            allowed: vec![false; n_edges],
+            queue: Vec::new(),
+        }
+    }
+
+    fn endpoint(&self, p: usize) -> usize {
+        let (i, j, _) = self.edges[p / 2];
+        if p % 2 == 0 {
+            i
+        } else {
+            j
+        }
+    }
+
+    /// Twice the slack of edge `k`, expressed as `dual(i) + dual(j) - 2 w(k)`.
+    fn slack(&self, k: usize) -> K {
+        let (i, j, w) = self.edges[k];
+        self.dual[i] + self.dual[j] - (w + w)
+    }
+
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![b];
+        while let Some(b) = stack.pop() {
+            if b < self.n {
+                leaves.push(b);
+            } else {
+                stack.extend(self.children[b].iter().rev());
+            }
+        }
+        leaves
+    }
+
+    /// Label the top-level blossom of `w` with `t`, reached through endpoint `p`.
+    fn assign_label(&mut self, w: usize, t: u8, p: Option<usize>) {
+        let b = self.in_blossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        self.best_edge[w] = None;
+        self.best_edge[b] = None;
+        if t == OUTER {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            // The mate of the base of an inner blossom becomes outer.
+            let base = self.base[b].unwrap();
+            let mate = self.mate[base].unwrap();
+            self.assign_label(self.endpoint(mate), OUTER, Some(mate ^ 1));
+        }
+    }
+
+    /// Trace back from `v` and `w` to find a new blossom or an augmenting
+    /// path. Return the base of the new blossom, or `None` for a path.
+    fn scan_blossom(&mut self, v: usize, w: usize) -> Option<usize> {
+        let mut path = Vec::new();
+        let mut base = None;
+        let mut v = Some(v);
+        let mut w = Some(w);
+        while let Some(current) = v {
+            let b = self.in_blossom[current];
+            if self.label[b] & 4 != 0 {
+                base = self.base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = MARKED;
+            v = match self.label_end[b] {
+                // Reached a single vertex: stop this side.
+                None => None,
+                Some(p) => {
+                    let t = self.in_blossom[self.endpoint(p)];
+                    Some(self.endpoint(self.label_end[t].unwrap()))
+                }
+            };
+            if w.is_some() {
+                core::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = OUTER;
+        }
+        base
+    }
+
+    /// Construct a new blossom with the given base, through the edge `k`
+    /// connecting two outer vertices.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let b = self.unused.pop().unwrap();
+        self.base[b] = Some(base);
+        self.parent[b] = None;
+        self.parent[bb] = Some(b);
+
+        let mut path = Vec::new();
+        let mut endpoints = Vec::new();
+        while bv != bb {
+            self.parent[bv] = Some(b);
+            path.push(bv);
+            let p = self.label_end[bv].unwrap();
+            endpoints.push(p);
+            v = self.endpoint(p);
+            bv = self.in_blossom[v];
+        }
+        path.push(bb);
+        path.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+        while bw != bb {
+            self.parent[bw] = Some(b);
+            path.push(bw);
+            let p = self.label_end[bw].unwrap();
+            endpoints.push(p ^ 1);
+            w = self.endpoint(p);
+            bw = self.in_blossom[w];
+        }
+
+        self.label[b] = OUTER;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = K::default();
+        for leaf in self.leaves_of(&path) {
+            if self.label[self.in_blossom[leaf]] == INNER {
+                // Inner vertices become outer and need scanning.
+                self.queue.push(leaf);
+            }
+            self.in_blossom[leaf] = b;
+        }
+
+        // Compute the least-slack edges to the neighboring outer blossoms.
+        let mut best_edge_to: Vec<Option<usize>> = vec![None; 2 * self.n];
+        for &child in &path {
+            let candidates = match self.blossom_best_edges[child].take() {
+                Some(edges) => edges,
+                None => self
+                    .leaves(child)
+                    .into_iter()
+                    .flat_map(|leaf| self.neighbors[leaf].iter().map(|p| p / 2))
+                    .collect(),
+            };
+            for k in candidates {
+                let (mut i, mut j, _) = self.edges[k];
+                if self.in_blossom[j] == b {
+                    core::mem::swap(&mut i, &mut j);
+                }
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.label[bj] == OUTER
+                    && best_edge_to[bj].map_or(true, |best| self.slack(k) < self.slack(best))
+                {
+                    best_edge_to[bj] = Some(k);
+                }
+            }
+            self.best_edge[child] = None;
+        }
+        let best_edges: Vec<usize> = best_edge_to.into_iter().flatten().collect();
+        self.best_edge[b] = None;
+        for &k in &best_edges {
+            if self.best_edge[b].map_or(true, |best| self.slack(k) < self.slack(best)) {
+                self.best_edge[b] = Some(k);
+            }
+        }
+        self.blossom_best_edges[b] = Some(best_edges);
+        self.children[b] = path;
+        self.child_endpoints[b] = endpoints;
+    }
+
+    fn leaves_of(&self, blossoms: &[usize]) -> Vec<usize> {
+        blossoms.iter().flat_map(|&b| self.leaves(b)).collect()
+    }
+
+    /// Position `j` in the cyclic list of children of `b`, allowing negative values.
+    fn child_at(&self, b: usize, j: isize) -> usize {
+        let len = self.children[b].len() as isize;
+        self.children[b][j.rem_euclid(len) as usize]
+    }
+
+    fn child_endpoint_at(&self, b: usize, j: isize) -> usize {
+        let len = self.child_endpoints[b].len() as isize;
+        self.child_endpoints[b][j.rem_euclid(len) as usize]
+    }
+
+    /// The position of `child` in `b`, and the direction and endpoint offset
+    /// to walk the even-length side of `b` back to its base.
+    fn even_side(&self, b: usize, child: usize) -> (isize, isize, usize) {
+        let j = self.children[b].iter().position(|&c| c == child).unwrap() as isize;
+        if j % 2 == 1 {
+            (j - self.children[b].len() as isize, 1, 0)
+        } else {
+            (j, -1, 1)
+        }
+    }
+
+    /// Expand the blossom `b`, relabeling its children if it is inner and the
+    /// search is still in progress.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        for child in self.children[b].clone() {
+            self.parent[child] = None;
+            if child < self.n {
+                self.in_blossom[child] = child;
+            } else if end_stage && self.dual[child] == K::default() {
+                self.expand_blossom(child, end_stage);
+            } else {
+                for leaf in self.leaves(child) {
+                    self.in_blossom[leaf] = child;
+                }
+            }
+        }
+
+        if !end_stage && self.label[b] == INNER {
+            // Relabel the children on the even-length path from the entry
+            // child to the base as alternately inner and outer.
+            let entry_end = self.label_end[b].unwrap();
+            let entry_child = self.in_blossom[self.endpoint(entry_end ^ 1)];
+            let (mut j, step, trick) = self.even_side(b, entry_child);
+            let mut p = entry_end;
+            while j != 0 {
+                let v = self.endpoint(p ^ 1);
+                let q = self.child_endpoint_at(b, j - trick as isize);
+                let mate = self.endpoint(q ^ trick ^ 1);
+                self.label[v] = FREE;
+                self.label[mate] = FREE;
+                self.assign_label(v, INNER, Some(p));
+                self.allowed[q / 2] = true;
+                j += step;
+                p = self.child_endpoint_at(b, j - trick as isize) ^ trick;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+            // The base child becomes inner, without relabeling its mate.
+            let bv = self.child_at(b, j);
+            let v = self.endpoint(p ^ 1);
+            self.label[v] = INNER;
+            self.label[bv] = INNER;
+            self.label_end[v] = Some(p);
+            self.label_end[bv] = Some(p);
+            self.best_edge[bv] = None;
+            j += step;
+            // The children on the odd-length path lose their labels, unless
+            // one of their vertices was reached through an edge from outside.
+            while self.child_at(b, j) != entry_child {
+                let bv = self.child_at(b, j);
+                j += step;
+                if self.label[bv] == OUTER {
+                    continue;
+                }
+                let labeled = self
+                    .leaves(bv)
+                    .into_iter()
+                    .find(|&leaf| self.label[leaf] != FREE);
+                if let Some(v) = labeled {
+                    self.label[v] = FREE;
+                    let mate = self.endpoint(self.mate[self.base[bv].unwrap()].unwrap());
+                    self.label[mate] = FREE;
+                    self.assign_label(v, INNER, self.label_end[v]);
+                }
+            }
+        }
+
+        self.label[b] = FREE;
+        self.label_end[b] = None;
+        self.children[b].clear();
+        self.child_endpoints[b].clear();
+        self.base[b] = None;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = None;
+        self.unused.push(b);
+    }
+
+    /// Swap matched and unmatched edges in `b` along the even-length path
+    /// from `v` to the base, making `v` the new base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.parent[t] != Some(b) {
+            t = self.parent[t].unwrap();
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let i = self.children[b].iter().position(|&c| c == t).unwrap();
+        let (mut j, step, trick) = self.even_side(b, t);
+        while j != 0 {
+            j += step;
+            let t = self.child_at(b, j);
+            let p = self.child_endpoint_at(b, j - trick as isize) ^ trick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint(p));
+            }
+            j += step;
+            let t = self.child_at(b, j);
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint(p ^ 1));
+            }
+            let (v, w) = (self.endpoint(p), self.endpoint(p ^ 1));
+            self.mate[v] = Some(p ^ 1);
+            self.mate[w] = Some(p);
+        }
+        self.children[b].rotate_left(i);
+        self.child_endpoints[b].rotate_left(i);
+        self.base[b] = self.base[self.children[b][0]];
+    }
+
+    /// Augment the matching along the path through the edge `k` between two
+    /// outer vertices.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = Some(p);
+                let label_end = match self.label_end[bs] {
+                    // Reached a single vertex.
+                    None => break,
+                    Some(label_end) => label_end,
+                };
+                let t = self.endpoint(label_end);
+                let bt = self.in_blossom[t];
+                let bt_end = self.label_end[bt].unwrap();
+                s = self.endpoint(bt_end);
+                let j = self.endpoint(bt_end ^ 1);
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = Some(bt_end);
+                p = bt_end ^ 1;
+            }
+        }
+    }
+
+    fn solve(&mut self, max_cardinality: bool) {
+        let zero = K::default();
+        let two = K::from_f32(2.);
+        // Each stage augments the matching by one edge, or stops.
+        for _ in 0..self.n {
+            self.label.iter_mut().for_each(|label| *label = FREE);
+            self.best_edge.iter_mut().for_each(|best| *best = None);
+            for best_edges in &mut self.blossom_best_edges[self.n..] {
+                *best_edges = None;
+            }
+            self.allowed.iter_mut().for_each(|allowed| *allowed = false);
+            self.queue.clear();
+            for v in 0..self.n {
+                if self.mate[v].is_none() && self.label[self.in_blossom[v]] == FREE {
+                    self.assign_label(v, OUTER, None);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                // Grow the alternating trees along tight edges.
+                while let Some(v) = self.queue.pop() {
+                    for i in 0..self.neighbors[v].len() {
+                        let p = self.neighbors[v][i];
+                        let k = p / 2;
+                        let w = self.endpoint(p);
+                        if self.in_blossom[v] == self.in_blossom[w] {
+                            continue;
+                        }
+                        let mut k_slack = zero;
+                        if !self.allowed[k] {
+                            k_slack = self.slack(k);
+                            if k_slack <= zero {
+                                self.allowed[k] = true;
+                            }
+                        }
+                        let bw = self.in_blossom[w];
+                        if self.allowed[k] {
+                            if self.label[bw] == FREE {
+                                self.assign_label(w, INNER, Some(p ^ 1));
+                            } else if self.label[bw] == OUTER {
+                                match self.scan_blossom(v, w) {
+                                    Some(base) => self.add_blossom(base, k),
+                                    None => {
+                                        self.augment_matching(k);
+                                        augmented = true;
+                                        break;
+                                    }
+                                }
+                            } else if self.label[w] == FREE {
+                                // w is in an inner blossom, but not reached yet.
+                                self.label[w] = INNER;
+                                self.label_end[w] = Some(p ^ 1);
+                            }
+                        } else if self.label[bw] == OUTER {
+                            let b = self.in_blossom[v];
+                            if self.best_edge[b].map_or(true, |best| k_slack < self.slack(best)) {
+                                self.best_edge[b] = Some(k);
+                            }
+                        } else if self.label[w] == FREE
+                            && self.best_edge[w].map_or(true, |best| k_slack < self.slack(best))
+                        {
+                            self.best_edge[w] = Some(k);
+                        }
+                    }
+                    if augmented {
+                        break;
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                // No progress is possible along tight edges: update the dual
+                // variables by the largest amount keeping them feasible.
+                let mut delta: Option<(K, Delta)> = None;
+                let mut consider = |value: K, kind: Delta| {
+                    if delta.map_or(true, |(best, _)| value < best) {
+                        delta = Some((value, kind));
+                    }
+                };
+                if !max_cardinality {
+                    let min_dual = self.dual[..self.n].iter().fold(self.dual[0], |min, &d| {
+                        if d < min {
+                            d
+                        } else {
+                            min
+                        }
+                    });
+                    consider(min_dual, Delta::Stop);
+                }
+                for v in 0..self.n {
+                    if self.label[self.in_blossom[v]] == FREE {
+                        if let Some(k) = self.best_edge[v] {
+                            consider(self.slack(k), Delta::Edge(k));
+                        }
+                    }
+                }
+                for b in 0..2 * self.n {
+                    if self.parent[b].is_none() && self.label[b] == OUTER {
+                        if let Some(k) = self.best_edge[b] {
+                            consider(self.slack(k) / two, Delta::Edge(k));
+                        }
+                    }
+                }
+                for b in self.n..2 * self.n {
+                    if self.base[b].is_some() && self.parent[b].is_none() && self.label[b] == INNER
+                    {
+                        consider(self.dual[b], Delta::Expand(b));
+                    }
+                }
+                let (delta, kind) = delta.unwrap_or_else(|| {
+                    // No further improvement is possible with max cardinality.
+                    let min_dual = self.dual[..self.n].iter().fold(self.dual[0], |min, &d| {
+                        if d < min {
+                            d
+                        } else {
+                            min
+                        }
+                    });
+                    let min_dual = if min_dual > zero { min_dual } else { zero };
+                    (min_dual, Delta::Stop)
+                });
+
+                for v in 0..self.n {
+                    match self.label[self.in_blossom[v]] {
+                        OUTER => self.dual[v] = self.dual[v] - delta,
+                        INNER => self.dual[v] = self.dual[v] + delta,
+                        _ => {}
+                    }
+                }
+                for b in self.n..2 * self.n {
+                    if self.base[b].is_some() && self.parent[b].is_none() {
+                        match self.label[b] {
+                            OUTER => self.dual[b] = self.dual[b] + delta,
+                            INNER => self.dual[b] = self.dual[b] - delta,
+                            _ => {}
+                        }
+                    }
+                }
+
+                match kind {
+                    Delta::Stop => break,
+                    Delta::Edge(k) => {
+                        // The edge became tight: scan it from its outer end.
+                        self.allowed[k] = true;
+                        let (i, j, _) = self.edges[k];
+                        let outer = if self.label[self.in_blossom[i]] == FREE {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(outer);
+                    }
+                    Delta::Expand(b) => self.expand_blossom(b, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+            // Expand the outer blossoms whose dual variable dropped to zero.
+            for b in self.n..2 * self.n {
+                if self.parent[b].is_none()
+                    && self.base[b].is_some()
+                    && self.label[b] == OUTER
+                    && self.dual[b] == zero
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+}
+
+/// The kind of dual update of the weighted blossom algorithm.
+#[derive(Clone, Copy)]
+enum Delta {
+    /// No augmenting path is left.
+    Stop,
+    /// An edge becomes tight.
+    Edge(usize),
+    /// An inner blossom reaches a zero dual variable and is expanded.
+    Expand(usize),
+}
+
+/// Compute a [*maximum weight matching*][1] of a bipartite graph using the
+/// [Hungarian algorithm][2].
+///
+/// The function `edge_weight` should return the weight of a particular edge.
+/// The matching maximizes the total weight of its edges, regardless of its
+/// cardinality, so edges with a non-positive weight are never matched. When
+/// several edges connect the same two nodes, only the heaviest one is taken
+/// into account.
+///
+/// The input graph is treated as if undirected.
+///
+/// **Panics** if an edge does not lead from `partition_a` to `partition_b`.
+///
+/// # Arguments
+/// * `graph`: a bipartite graph.
+/// * `partition_a`: the nodes of one side of the graph.
+/// * `partition_b`: the nodes of the other side of the graph.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+///
+/// # Returns
+/// * `(Matching<G>, K)`: the computed matching and its total weight.
+///
+/// # Complexity
+/// * Time complexity: **O(|A|²(|A| + |B|) + |E|)**.
+/// * Auxiliary space: **O(|V| + |A||B|)**.
+///
+/// where **|A|** and **|B|** are the sizes of the partitions, **|V|** is the
+/// number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Maximum_weight_matching
+/// [2]: https://en.wikipedia.org/wiki/Hungarian_algorithm
+///
+/// # Example
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::maximum_weight_bipartite_matching;
+///
+/// let mut graph: UnGraph<(), u32> = UnGraph::new_undirected();
+/// let workers = [graph.add_node(()), graph.add_node(())];
+/// let jobs = [graph.add_node(()), graph.add_node(())];
+/// graph.extend_with_edges(&[
+///     (workers[0], jobs[0], 5),
+///     (workers[0], jobs[1], 4),
+///     (workers[1], jobs[0], 4),
+/// ]);
+///
+/// let (matching, weight) =
+///     maximum_weight_bipartite_matching(&graph, &workers, &jobs, |e| *e.weight());
+/// assert_eq!(weight, 8);
+/// assert_eq!(matching.mate(workers[0]), Some(jobs[1]));
+/// assert_eq!(matching.mate(workers[1]), Some(jobs[0]));
+/// ```
+pub fn maximum_weight_bipartite_matching<G, F, K>(
+    graph: G,
+    partition_a: &[G::NodeId],
+    partition_b: &[G::NodeId],
+    edge_weight: F,
+) -> (Matching<G>, K)
+where
+    G: NodeIndexable + IntoEdges,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    let weights = bipartite_weights(&graph, partition_a, partition_b, edge_weight, |new, old| {
+        new > old
+    });
+    let zero = K::default();
+    let max_weight = weights
+        .iter()
+        .flatten()
+        .flatten()
+        .fold(zero, |max, &weight| if weight > max { weight } else { max });
+
+    // Every node of `partition_a` gets a private dummy column, standing for
+    // leaving it unmatched, so the assignment always exists. Minimizing
+    // `max_weight - weight` over all rows then maximizes the matched weight.
+    let n_columns = partition_b.len() + partition_a.len();
+    let assignment = min_cost_assignment(partition_a.len(), n_columns, |row, column| match column
+        .checked_sub(partition_b.len())
+    {
+        Some(dummy) => (dummy == row).then_some(max_weight),
+        None => match weights[row][column] {
+            Some(weight) if weight > zero => Some(max_weight - weight),
+            _ => None,
+        },
+    })
+    .expect("every row can be left unmatched");
+
+    let pairs = assignment
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, column)| column < partition_b.len())
+        .map(|(row, column)| (row, column, weights[row][column].unwrap()));
+    bipartite_matching_from_pairs(graph, partition_a, partition_b, pairs)
+}
+
+/// Compute a minimum cost [*perfect matching*][1] of a bipartite graph using
+/// the [Hungarian algorithm][2], also known as the assignment problem.
+///
+/// The function `edge_cost` should return the cost of a particular edge.
+/// Every node of `partition_a` is matched with exactly one node of
+/// `partition_b`, so that the total cost of the matched edges is minimal.
+/// When several edges connect the same two nodes, only the cheapest one is
+/// taken into account.
+///
+/// The input graph is treated as if undirected.
+///
+/// **Panics** if an edge does not lead from `partition_a` to `partition_b`.
+///
+/// # Arguments
+/// * `graph`: a bipartite graph.
+/// * `partition_a`: the nodes of one side of the graph.
+/// * `partition_b`: the nodes of the other side of the graph.
+/// * `edge_cost`: closure that returns the cost of a particular edge.
+///
+/// # Returns
+/// * `Some((Matching<G>, K))`: the computed matching and its total cost.
+/// * `None`: if the partitions have different sizes, or no perfect matching exists.
+///
+/// # Complexity
+/// * Time complexity: **O(|A|³ + |E|)**.
+/// * Auxiliary space: **O(|V| + |A|²)**.
+///
+/// where **|A|** is the size of the partitions, **|V|** is the number of
+/// nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Matching_(graph_theory)#Definitions
+/// [2]: https://en.wikipedia.org/wiki/Hungarian_algorithm
+///
+/// # Example
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::minimum_cost_perfect_bipartite_matching;
+///
+/// let mut graph: UnGraph<(), i32> = UnGraph::new_undirected();
+/// let workers = [graph.add_node(()), graph.add_node(()), graph.add_node(())];
+/// let jobs = [graph.add_node(()), graph.add_node(()), graph.add_node(())];
+/// let costs = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+/// for (i, row) in costs.iter().enumerate() {
+///     for (j, &cost) in row.iter().enumerate() {
+///         graph.add_edge(workers[i], jobs[j], cost);
+///     }
+/// }
+///
+/// let (matching, cost) =
+///     minimum_cost_perfect_bipartite_matching(&graph, &workers, &jobs, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 5);
+/// assert!(matching.contains_edge(workers[0], jobs[1]));
+/// assert!(matching.contains_edge(workers[1], jobs[0]));
+/// assert!(matching.contains_edge(workers[2], jobs[2]));
+/// ```
+pub fn minimum_cost_perfect_bipartite_matching<G, F, K>(
+    graph: G,
+    partition_a: &[G::NodeId],
+    partition_b: &[G::NodeId],
+    edge_cost: F,
+) -> Option<(Matching<G>, K)>
+where
+    G: NodeIndexable + IntoEdges,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    if partition_a.len() != partition_b.len() {
+        return None;
+    }
+    let costs = bipartite_weights(&graph, partition_a, partition_b, edge_cost, |new, old| {
+        new < old
+    });
+
+    // Every node is matched exactly once, so shifting all costs by the same
+    // amount keeps the optimum and makes them non-negative.
+    let min_cost = costs
+        .iter()
+        .flatten()
+        .flatten()
+        .fold(None, |min: Option<K>, &cost| match min {
+            Some(min) if min <= cost => Some(min),
+            _ => Some(cost),
+        })
+        .unwrap_or_default();
+    let assignment = min_cost_assignment(partition_a.len(), partition_b.len(), |row, column| {
+        costs[row][column].map(|cost| cost - min_cost)
+    })?;
+
+    let pairs = assignment
+        .into_iter()
+        .enumerate()
+        .map(|(row, column)| (row, column, costs[row][column].unwrap()));
+    Some(bipartite_matching_from_pairs(
+        graph,
+        partition_a,
+        partition_b,
+        pairs,
+    ))
+}
+
+/// Collect the weight of the edges between `partition_a` (the rows) and
+/// `partition_b` (the columns), keeping the one for which `prefer` holds among
+/// parallel edges.
+fn bipartite_weights<G, F, K, P>(
+    graph: &G,
+    partition_a: &[G::NodeId],
+    partition_b: &[G::NodeId],
+    mut edge_weight: F,
+    mut prefer: P,
+) -> Vec<Vec<Option<K>>>
+where
+    G: NodeIndexable + IntoEdges,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Copy,
+    P: FnMut(K, K) -> bool,
+{
+    let mut rows = vec![usize::MAX; graph.node_bound()];
+    let mut columns = vec![usize::MAX; graph.node_bound()];
+    for (row, &node) in partition_a.iter().enumerate() {
+        rows[graph.to_index(node)] = row;
+    }
+    for (column, &node) in partition_b.iter().enumerate() {
+        columns[graph.to_index(node)] = column;
+    }
+
+    let mut weights = vec![vec![None; partition_b.len()]; partition_a.len()];
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        let (row, column) = if rows[source] != usize::MAX && columns[target] != usize::MAX {
+            (rows[source], columns[target])
+        } else if rows[target] != usize::MAX && columns[source] != usize::MAX {
+            (rows[target], columns[source])
+        } else {
+            panic!("Partitions are inconsistent.");
+        };
+        let weight = edge_weight(edge);
+        let entry = &mut weights[row][column];
+        if entry.map_or(true, |old| prefer(weight, old)) {
+            *entry = Some(weight);
+        }
+    }
+    weights
+}
+
+fn bipartite_matching_from_pairs<G, K, I>(
+    graph: G,
+    partition_a: &[G::NodeId],
+    partition_b: &[G::NodeId],
+    pairs: I,
+) -> (Matching<G>, K)
+where
+    G: NodeIndexable,
+    K: Measure + Copy,
+    I: Iterator<Item = (usize, usize, K)>,
+{
+    let mut mate = vec![None; graph.node_bound()];
+    let mut n_edges = 0;
+    let mut total = K::default();
+    for (row, column, weight) in pairs {
+        let (a, b) = (partition_a[row], partition_b[column]);
+        mate[graph.to_index(a)] = Some(b);
+        mate[graph.to_index(b)] = Some(a);
+        n_edges += 1;
+        total = total + weight;
+    }
+    (Matching::new(graph, mate, n_edges), total)
+}
+
+/// Solve the assignment problem: assign a distinct column to every row, so
+/// that the total cost is minimal. `cost` returns `None` for forbidden pairs,
+/// and must otherwise be non-negative.
+///
+/// This is the Hungarian algorithm with potentials, where every row is added
+/// along a shortest augmenting path found with Dijkstra's algorithm. Both
+/// potentials are kept non-negative, so unsigned costs are supported.
+///
+/// Returns the column of every row, or `None` if there is no assignment.
+fn min_cost_assignment<K, F>(n_rows: usize, n_columns: usize, mut cost: F) -> Option<Vec<usize>>
+where
+    K: Measure + Copy + Sub<Output = K>,
+    F: FnMut(usize, usize) -> Option<K>,
+{
+    // Rows and columns are numbered from 1, column 0 being the root of the
+    // current search tree, and row 0 standing for "no row".
+    let zero = K::default();
+    let mut row_potential = vec![zero; n_rows + 1];
+    let mut column_potential = vec![zero; n_columns + 1];
+    let mut row_of = vec![0; n_columns + 1];
+    let mut previous = vec![0; n_columns + 1];
+    for row in 1..=n_rows {
+        row_of[0] = row;
+        let mut column = 0;
+        let mut distance: Vec<Option<K>> = vec![None; n_columns + 1];
+        let mut done = vec![false; n_columns + 1];
+        loop {
+            done[column] = true;
+            let current_row = row_of[column];
+            let mut closest: Option<(K, usize)> = None;
+            for next in 1..=n_columns {
+                if done[next] {
+                    continue;
+                }
+                if let Some(cost) = cost(current_row - 1, next - 1) {
+                    // Non-negative reduced cost, by dual feasibility.
+                    let reduced = cost + column_potential[next] - row_potential[current_row];
+                    if distance[next].map_or(true, |d| reduced < d) {
+                        distance[next] = Some(reduced);
+                        previous[next] = column;
+                    }
+                }
+                if let Some(d) = distance[next] {
+                    if closest.map_or(true, |(c, _)| d < c) {
+                        closest = Some((d, next));
+                    }
+                }
+            }
+
+            let (delta, next) = closest?;
+            for c in 0..=n_columns {
+                if done[c] {
+                    row_potential[row_of[c]] = row_potential[row_of[c]] + delta;
+                    column_potential[c] = column_potential[c] + delta;
+                } else if let Some(d) = distance[c] {
+                    distance[c] = Some(d - delta);
+                }
+            }
+            column = next;
+            if row_of[column] == 0 {
+                break;
+            }
+        }
+
+        // Augment along the path to the free column.
+        while column != 0 {
+            let prev = previous[column];
+            row_of[column] = row_of[prev];
+            column = prev;
+        }
+    }
+
+    let mut assignment = vec![0; n_rows];
+    for (column, &row) in row_of.iter().enumerate().skip(1) {
+        if row != 0 {
+            assignment[row - 1] = column - 1;
+        }
+    }
+    Some(assignment)
+}
+
 /// Create a network from given graph.
 /// Created Nodes and Edges indices are compatible
 /// with the ones from original graph.
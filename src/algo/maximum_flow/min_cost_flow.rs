@@ -0,0 +1,327 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::ops::{Mul, Neg};
+
+use crate::{
+    algo::{BoundedMeasure, NegativeCycle},
+    scored::MinScored,
+    visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences, NodeIndexable},
+};
+
+/// Minimum-cost flow by successive shortest paths.
+///
+/// Computes the cheapest way to send `flow_value` units of flow from `source` to `sink` in a
+/// directed network, where each edge has a capacity given by `capacity` and a cost per unit of
+/// flow given by `cost`. Costs may be negative, but the network must not contain a cycle of
+/// negative total cost made of edges with positive capacity (in that case it will return an
+/// error). Capacities and costs share a signed numeric type.
+///
+/// If the network cannot carry `flow_value` units of flow, the cheapest maximum flow is
+/// computed instead; compare the returned flow value with `flow_value` to tell the cases apart.
+/// To compute the cheapest maximum flow directly, use [`min_cost_max_flow`].
+///
+/// # Arguments
+/// * `network`: a directed graph.
+/// * `source`: a stream *source* node.
+/// * `sink`: a stream *sink* node.
+/// * `flow_value`: the amount of flow to send from `source` to `sink`.
+/// * `capacity`: closure that returns the capacity of a particular edge, which must be
+///   non-negative.
+/// * `cost`: closure that returns the cost per unit of flow of a particular edge.
+///
+/// # Returns
+/// * `Ok`: a tuple of three values:
+///   * `W`: the value of the computed flow;
+///   * `W`: the total cost of the computed flow;
+///   * `Vec<W>`: the flow of each edge. The vector is indexed by the graph's edge indices.
+/// * `Err`: if the network contains a negative cycle.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E| + F(|E| + |V|)log(|V|))**, where **F** is the number of
+///   augmenting paths, which is at most the value of the flow for integer capacities.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::min_cost_flow;
+///
+/// // Edge weights are (capacity, cost) pairs.
+/// let mut graph = Graph::<(), (i32, i32)>::new();
+/// let source = graph.add_node(());
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let sink = graph.add_node(());
+/// let sa = graph.add_edge(source, a, (2, 1));
+/// let sb = graph.add_edge(source, b, (2, 5));
+/// let ab = graph.add_edge(a, b, (1, 1));
+/// let at = graph.add_edge(a, sink, (1, 3));
+/// let bt = graph.add_edge(b, sink, (3, 1));
+///
+/// let (flow, cost, flows) =
+///     min_cost_flow(&graph, source, sink, 3, |e| e.weight().0, |e| e.weight().1).unwrap();
+/// assert_eq!(flow, 3);
+/// // One unit along source -> a -> sink, one along source -> a -> b -> sink,
+/// // and one along source -> b -> sink.
+/// assert_eq!(cost, 4 + 3 + 6);
+/// assert_eq!(flows[sa.index()], 2);
+/// assert_eq!(flows[sb.index()], 1);
+/// assert_eq!(flows[ab.index()], 1);
+/// assert_eq!(flows[at.index()], 1);
+/// assert_eq!(flows[bt.index()], 2);
+/// ```
+pub fn min_cost_flow<N, W, CF, KF>(
+    network: N,
+    source: N::NodeId,
+    sink: N::NodeId,
+    flow_value: W,
+    capacity: CF,
+    cost: KF,
+) -> Result<(W, W, Vec<W>), NegativeCycle>
+where
+    N: IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+    W: BoundedMeasure + Copy + Mul<Output = W> + Neg<Output = W>,
+    CF: FnMut(N::EdgeRef) -> W,
+    KF: FnMut(N::EdgeRef) -> W,
+{
+    successive_shortest_paths(network, source, sink, Some(flow_value), capacity, cost)
+}
+
+/// Minimum-cost maximum flow by successive shortest paths.
+///
+/// Computes a [maximum flow] from `source` to `sink` in a directed network that has the least
+/// total cost among all maximum flows. Each edge has a capacity given by `capacity` and a cost
+/// per unit of flow given by `cost`. Costs may be negative, but the network must not contain a
+/// cycle of negative total cost made of edges with positive capacity (in that case it will
+/// return an error).
+///
+/// # Arguments
+/// * `network`: a directed graph.
+/// * `source`: a stream *source* node.
+/// * `sink`: a stream *sink* node.
+/// * `capacity`: closure that returns the capacity of a particular edge, which must be
+///   non-negative.
+/// * `cost`: closure that returns the cost per unit of flow of a particular edge.
+///
+/// # Returns
+/// * `Ok`: a tuple of three values:
+///   * `W`: the value of the maximum flow;
+///   * `W`: the total cost of the maximum flow;
+///   * `Vec<W>`: the flow of each edge. The vector is indexed by the graph's edge indices.
+/// * `Err`: if the network contains a negative cycle.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E| + F(|E| + |V|)log(|V|))**, where **F** is the number of
+///   augmenting paths, which is at most the value of the flow for integer capacities.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [maximum flow]: https://en.wikipedia.org/wiki/Maximum_flow_problem
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::min_cost_max_flow;
+///
+/// // Edge weights are (capacity, cost) pairs.
+/// let mut graph = Graph::<(), (f64, f64)>::new();
+/// let source = graph.add_node(());
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let sink = graph.add_node(());
+/// graph.extend_with_edges(&[
+///     (source, a, (4., 2.)),
+///     (source, b, (2., 2.)),
+///     (a, b, (2., -1.)),
+///     (a, sink, (3., 3.)),
+///     (b, sink, (3., 1.)),
+/// ]);
+///
+/// let (flow, cost, _) =
+///     min_cost_max_flow(&graph, source, sink, |e| e.weight().0, |e| e.weight().1).unwrap();
+/// assert_eq!(flow, 6.);
+/// assert_eq!(cost, 23.);
+/// ```
+pub fn min_cost_max_flow<N, W, CF, KF>(
+    network: N,
+    source: N::NodeId,
+    sink: N::NodeId,
+    capacity: CF,
+    cost: KF,
+) -> Result<(W, W, Vec<W>), NegativeCycle>
+where
+    N: IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+    W: BoundedMeasure + Copy + Mul<Output = W> + Neg<Output = W>,
+    CF: FnMut(N::EdgeRef) -> W,
+    KF: FnMut(N::EdgeRef) -> W,
+{
+    successive_shortest_paths(network, source, sink, None, capacity, cost)
+}
+
+/// An edge of the network, with dense node indices.
+#[derive(Clone, Copy, Debug)]
+struct FlowEdge<W> {
+    edge: usize,
+    source: usize,
+    target: usize,
+    capacity: W,
+    cost: W,
+}
+
+impl<W> FlowEdge<W>
+where
+    W: BoundedMeasure + Copy + Neg<Output = W>,
+{
+    /// Returns the tail, head, residual capacity and cost of the residual arc
+    /// in the given direction.
+    fn residual(&self, forward: bool, flow: W) -> (usize, usize, W, W) {
+        if forward {
+            (self.source, self.target, self.capacity - flow, self.cost)
+        } else {
+            (self.target, self.source, flow, -self.cost)
+        }
+    }
+}
+
+fn successive_shortest_paths<N, W, CF, KF>(
+    network: N,
+    source: N::NodeId,
+    sink: N::NodeId,
+    flow_value: Option<W>,
+    mut capacity: CF,
+    mut cost: KF,
+) -> Result<(W, W, Vec<W>), NegativeCycle>
+where
+    N: IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+    W: BoundedMeasure + Copy + Mul<Output = W> + Neg<Output = W>,
+    CF: FnMut(N::EdgeRef) -> W,
+    KF: FnMut(N::EdgeRef) -> W,
+{
+    let zero = W::default();
+    let node_bound = network.node_bound();
+    let mut flows = vec![zero; network.edge_bound()];
+
+    // Residual adjacency lists hold (arc index, is forward) pairs.
+    let mut arcs = Vec::new();
+    let mut adjacency = vec![Vec::new(); node_bound];
+    for edge in network.edge_references() {
+        let arc = FlowEdge {
+            edge: EdgeIndexable::to_index(&network, edge.id()),
+            source: NodeIndexable::to_index(&network, edge.source()),
+            target: NodeIndexable::to_index(&network, edge.target()),
+            capacity: capacity(edge),
+            cost: cost(edge),
+        };
+        adjacency[arc.source].push((arcs.len(), true));
+        adjacency[arc.target].push((arcs.len(), false));
+        arcs.push(arc);
+    }
+
+    // Bellman-Ford from a virtual node linked to every node computes initial potentials
+    // making all reduced costs non-negative, unless there is a negative cycle.
+    let mut potentials = vec![zero; node_bound];
+    let mut rounds = 0;
+    loop {
+        let mut relaxed = false;
+        for arc in &arcs {
+            if arc.capacity > zero && potentials[arc.source] + arc.cost < potentials[arc.target] {
+                potentials[arc.target] = potentials[arc.source] + arc.cost;
+                relaxed = true;
+            }
+        }
+        if !relaxed {
+            break;
+        }
+        // Without negative cycles, |V| - 1 relaxing rounds are enough.
+        rounds += 1;
+        if rounds >= node_bound {
+            return Err(NegativeCycle(()));
+        }
+    }
+
+    let source = NodeIndexable::to_index(&network, source);
+    let sink = NodeIndexable::to_index(&network, sink);
+    let mut total_flow = zero;
+    let mut total_cost = zero;
+    if source == sink {
+        return Ok((total_flow, total_cost, flows));
+    }
+
+    let mut distances: Vec<Option<W>> = vec![None; node_bound];
+    let mut predecessors: Vec<Option<(usize, bool)>> = vec![None; node_bound];
+    let mut done = vec![false; node_bound];
+    let mut visit_next = BinaryHeap::new();
+    loop {
+        let remaining = match flow_value {
+            Some(value) if value <= total_flow => break,
+            Some(value) => Some(value - total_flow),
+            None => None,
+        };
+
+        // Dijkstra's algorithm on the residual network, with reduced costs.
+        distances.iter_mut().for_each(|d| *d = None);
+        predecessors.iter_mut().for_each(|p| *p = None);
+        done.iter_mut().for_each(|d| *d = false);
+        distances[source] = Some(zero);
+        visit_next.push(MinScored(zero, source));
+        while let Some(MinScored(score, node)) = visit_next.pop() {
+            if done[node] {
+                continue;
+            }
+            done[node] = true;
+            for &(arc_index, forward) in &adjacency[node] {
+                let arc = &arcs[arc_index];
+                let (_, next, residual, arc_cost) = arc.residual(forward, flows[arc.edge]);
+                if residual <= zero || done[next] {
+                    continue;
+                }
+                let next_score = score + arc_cost + potentials[node] - potentials[next];
+                if distances[next].map_or(true, |d| next_score < d) {
+                    distances[next] = Some(next_score);
+                    predecessors[next] = Some((arc_index, forward));
+                    visit_next.push(MinScored(next_score, next));
+                }
+            }
+        }
+
+        if distances[sink].is_none() {
+            break;
+        }
+        for (potential, distance) in potentials.iter_mut().zip(&distances) {
+            if let Some(distance) = *distance {
+                *potential = *potential + distance;
+            }
+        }
+
+        // Find the bottleneck capacity of the path.
+        let mut path_flow = remaining.unwrap_or_else(W::max);
+        let mut node = sink;
+        while let Some((arc_index, forward)) = predecessors[node] {
+            let arc = &arcs[arc_index];
+            let (tail, _, residual, _) = arc.residual(forward, flows[arc.edge]);
+            if residual < path_flow {
+                path_flow = residual;
+            }
+            node = tail;
+        }
+
+        // Update the flow of each edge along the path.
+        let mut node = sink;
+        while let Some((arc_index, forward)) = predecessors[node] {
+            let arc = &arcs[arc_index];
+            let (tail, _, _, arc_cost) = arc.residual(forward, flows[arc.edge]);
+            flows[arc.edge] = if forward {
+                flows[arc.edge] + path_flow
+            } else {
+                flows[arc.edge] - path_flow
+            };
+            total_cost = total_cost + arc_cost * path_flow;
+            node = tail;
+        }
+        total_flow = total_flow + path_flow;
+    }
+
+    Ok((total_flow, total_cost, flows))
+}
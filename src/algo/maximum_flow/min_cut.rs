@@ -0,0 +1,262 @@
+use alloc::{collections::BinaryHeap, collections::VecDeque, vec, vec::Vec};
+use core::ops::Sub;
+
+use hashbrown::HashMap;
+
+use crate::{
+    algo::{Measure, PositiveMeasure},
+    prelude::Direction,
+    scored::MaxScored,
+    visit::{
+        EdgeIndexable, EdgeRef, IntoEdgeReferences, IntoEdgesDirected, IntoNodeIdentifiers,
+        NodeIndexable, VisitMap, Visitable,
+    },
+};
+
+/// Minimum s-t cut from a maximum flow.
+///
+/// Given the edge flows of a [maximum flow] from `source`, as computed by
+/// [`dinics`](crate::algo::dinics) or [`ford_fulkerson`](crate::algo::ford_fulkerson),
+/// compute the corresponding [minimum cut]: the nodes reachable from `source` in the residual
+/// network, and the edges leading from these nodes to the other ones. Each of these edges is
+/// saturated by the flow, and their total capacity is equal to the value of the maximum flow.
+///
+/// The capacity of each edge is its weight, as for the maximum flow algorithms.
+///
+/// # Arguments
+/// * `network`: a weighted directed graph.
+/// * `source`: the stream *source* node the flow was computed from.
+/// * `flows`: the flow of each edge, indexed by the graph's edge indices.
+///
+/// # Returns
+/// Returns a tuple of two values:
+/// * `Vec<N::NodeId>`: the nodes on the source side of the cut, starting with `source`;
+/// * `Vec<N::EdgeId>`: the edges of the cut, leading from the source side to the sink side.
+///
+/// If `flows` is not a maximum flow, the returned edges do not form a minimum cut.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [maximum flow]: https://en.wikipedia.org/wiki/Maximum_flow_problem
+/// [minimum cut]: https://en.wikipedia.org/wiki/Max-flow_min-cut_theorem
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::{dinics, min_st_cut};
+///
+/// let mut graph = Graph::<(), u32>::new();
+/// let source = graph.add_node(());
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let sink = graph.add_node(());
+/// graph.add_edge(source, a, 10);
+/// graph.add_edge(source, b, 10);
+/// let a_sink = graph.add_edge(a, sink, 4);
+/// graph.add_edge(a, b, 2);
+/// let b_sink = graph.add_edge(b, sink, 5);
+///
+/// let (max_flow, flows) = dinics(&graph, source, sink);
+/// let (source_side, cut_edges) = min_st_cut(&graph, source, &flows);
+/// assert_eq!(max_flow, 9);
+/// assert_eq!(source_side.len(), 3);
+/// assert!(!source_side.contains(&sink));
+/// assert_eq!(cut_edges, vec![a_sink, b_sink]);
+/// ```
+pub fn min_st_cut<N>(
+    network: N,
+    source: N::NodeId,
+    flows: &[N::EdgeWeight],
+) -> (Vec<N::NodeId>, Vec<N::EdgeId>)
+where
+    N: IntoEdgesDirected + EdgeIndexable + NodeIndexable + Visitable,
+    N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
+{
+    let flow = |edge: &N::EdgeRef| flows[EdgeIndexable::to_index(&network, edge.id())];
+
+    let mut source_side = network.visit_map();
+    let mut nodes = vec![source];
+    let mut queue = VecDeque::new();
+    source_side.visit(source);
+    queue.push_back(source);
+    while let Some(vertex) = queue.pop_front() {
+        // Forward edges with residual capacity.
+        for edge in network.edges_directed(vertex, Direction::Outgoing) {
+            let next = edge.target();
+            if *edge.weight() - flow(&edge) > N::EdgeWeight::zero() && source_side.visit(next) {
+                nodes.push(next);
+                queue.push_back(next);
+            }
+        }
+        // Backward edges carrying some flow.
+        for edge in network.edges_directed(vertex, Direction::Incoming) {
+            let next = edge.source();
+            if flow(&edge) > N::EdgeWeight::zero() && source_side.visit(next) {
+                nodes.push(next);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let cut_edges = network
+        .edge_references()
+        .filter(|edge| {
+            source_side.is_visited(&edge.source()) && !source_side.is_visited(&edge.target())
+        })
+        .map(|edge| edge.id())
+        .collect();
+    (nodes, cut_edges)
+}
+
+/// Stoer–Wagner global minimum cut.
+///
+/// Computes a [minimum cut][sw] of an undirected weighted graph: a partition of its nodes into
+/// two non-empty sets such that the total weight of the edges between them is minimal. Unlike
+/// [`min_st_cut`], no source and sink need to be given.
+///
+/// Edges are treated as undirected, so a directed graph is handled as its underlying undirected
+/// graph. Parallel edges add up and self-loops are ignored. Edge weights must be non-negative.
+///
+/// # Arguments
+/// * `graph`: an undirected graph.
+/// * `edge_weight`: closure that returns the weight of a particular edge.
+///
+/// # Returns
+/// * `Some((K, Vec<G::NodeId>))`: the weight of the minimum cut and the nodes on one side
+///   of it.
+/// * `None`: if the graph has fewer than two nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E|log(|E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [sw]: https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::stoer_wagner_min_cut;
+///
+/// // Two triangles joined by a single light edge.
+/// let mut graph = Graph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// graph.extend_with_edges(&[
+///     (a, b, 3),
+///     (b, c, 3),
+///     (c, a, 3),
+///     (d, e, 3),
+///     (e, f, 3),
+///     (f, d, 3),
+///     (c, d, 1),
+/// ]);
+///
+/// let (weight, mut side) = stoer_wagner_min_cut(&graph, |e| *e.weight()).unwrap();
+/// side.sort();
+/// assert_eq!(weight, 1);
+/// assert!(side == vec![a, b, c] || side == vec![d, e, f]);
+/// ```
+pub fn stoer_wagner_min_cut<G, F, K>(graph: G, mut edge_weight: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let node_count = nodes.len();
+    if node_count < 2 {
+        return None;
+    }
+
+    // Nodes are renumbered densely; merged nodes keep the members of their group.
+    let mut dense = vec![usize::MAX; graph.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        dense[graph.to_index(node)] = i;
+    }
+    let mut adjacency: Vec<HashMap<usize, K>> = vec![HashMap::new(); node_count];
+    for edge in graph.edge_references() {
+        let a = dense[graph.to_index(edge.source())];
+        let b = dense[graph.to_index(edge.target())];
+        if a == b {
+            continue;
+        }
+        let weight = edge_weight(edge);
+        for (x, y) in [(a, b), (b, a)] {
+            let entry = adjacency[x].entry(y).or_default();
+            *entry = *entry + weight;
+        }
+    }
+    let mut groups: Vec<Vec<usize>> = (0..node_count).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..node_count).collect();
+
+    let mut best: Option<K> = None;
+    let mut best_group = Vec::new();
+    let mut added = vec![false; node_count];
+    let mut connectivity = vec![K::default(); node_count];
+    while active.len() > 1 {
+        // Minimum cut phase: add the most tightly connected node until all are added.
+        let mut heap = BinaryHeap::new();
+        for &v in &active {
+            added[v] = false;
+            connectivity[v] = K::default();
+            heap.push(MaxScored(K::default(), v));
+        }
+        let mut previous = active[0];
+        let mut last = active[0];
+        let mut cut_of_phase = K::default();
+        let mut remaining = active.len();
+        while let Some(MaxScored(key, v)) = heap.pop() {
+            if added[v] {
+                continue;
+            }
+            added[v] = true;
+            previous = last;
+            last = v;
+            cut_of_phase = key;
+            remaining -= 1;
+            if remaining == 0 {
+                break;
+            }
+            for (&u, &weight) in &adjacency[v] {
+                if !added[u] {
+                    connectivity[u] = connectivity[u] + weight;
+                    heap.push(MaxScored(connectivity[u], u));
+                }
+            }
+        }
+
+        if best.map_or(true, |weight| cut_of_phase < weight) {
+            best = Some(cut_of_phase);
+            best_group.clone_from(&groups[last]);
+        }
+
+        // Merge the last node into the one added before it.
+        let (s, t) = (previous, last);
+        let t_edges: Vec<(usize, K)> = adjacency[t].drain().collect();
+        for (u, weight) in t_edges {
+            adjacency[u].remove(&t);
+            if u == s {
+                continue;
+            }
+            for (x, y) in [(s, u), (u, s)] {
+                let entry = adjacency[x].entry(y).or_default();
+                *entry = *entry + weight;
+            }
+        }
+        let t_group = core::mem::take(&mut groups[t]);
+        groups[s].extend(t_group);
+        active.retain(|&v| v != t);
+    }
+
+    best.map(|weight| (weight, best_group.iter().map(|&i| nodes[i]).collect()))
+}
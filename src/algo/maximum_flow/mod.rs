@@ -1,5 +1,11 @@
 mod dinics;
 mod ford_fulkerson;
+mod min_cost_flow;
+mod min_cut;
+mod push_relabel;
 
 pub use dinics::dinics;
 pub use ford_fulkerson::ford_fulkerson;
+pub use min_cost_flow::{min_cost_flow, min_cost_max_flow};
+pub use min_cut::{min_st_cut, stoer_wagner_min_cut};
+pub use push_relabel::push_relabel;
@@ -0,0 +1,362 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::ops::Sub;
+
+use crate::{
+    algo::PositiveMeasure,
+    visit::{
+        EdgeCount, EdgeIndexable, EdgeRef, IntoEdgeReferences, IntoEdgesDirected, NodeCount,
+        NodeIndexable, Visitable,
+    },
+};
+
+/// Highest-label push-relabel algorithm.
+///
+/// Computes the [maximum flow][ff] of a weighted directed graph, using the
+/// [push-relabel][pr] method with the highest-label selection rule, together with the global
+/// relabeling and gap heuristics. It has the same signature and return values as
+/// [`dinics`](crate::algo::dinics), and is usually faster on dense networks.
+///
+/// # Arguments
+/// * `network`: a weighted directed graph.
+/// * `source`: a stream *source* node.
+/// * `sink`: a stream *sink* node.
+///
+/// # Returns
+/// Returns a tuple of two values:
+/// * `N::EdgeWeight`: computed maximum flow;
+/// * `Vec<N::EdgeWeight>`: the flow of each edge. The vector is indexed by the graph's edge indices.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|²√|E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [ff]: https://en.wikipedia.org/wiki/Maximum_flow_problem
+/// [pr]: https://en.wikipedia.org/wiki/Push%E2%80%93relabel_maximum_flow_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::push_relabel;
+/// // Example from CLRS book
+/// let mut graph = Graph::<u8, u8>::new();
+/// let source = graph.add_node(0);
+/// let _ = graph.add_node(1);
+/// let _ = graph.add_node(2);
+/// let _ = graph.add_node(3);
+/// let _ = graph.add_node(4);
+/// let sink = graph.add_node(5);
+/// graph.extend_with_edges(&[
+///    (0, 1, 16),
+///    (0, 2, 13),
+///    (1, 2, 10),
+///    (1, 3, 12),
+///    (2, 1, 4),
+///    (2, 4, 14),
+///    (3, 2, 9),
+///    (3, 5, 20),
+///    (4, 3, 7),
+///    (4, 5, 4),
+/// ]);
+/// let (max_flow, _) = push_relabel(&graph, source, sink);
+/// assert_eq!(23, max_flow);
+/// ```
+pub fn push_relabel<N>(
+    network: N,
+    source: N::NodeId,
+    sink: N::NodeId,
+) -> (N::EdgeWeight, Vec<N::EdgeWeight>)
+where
+    N: NodeCount + EdgeCount + IntoEdgesDirected + EdgeIndexable + NodeIndexable + Visitable,
+    N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
+{
+    let mut residual = ResidualNetwork::new(&network);
+    let source = NodeIndexable::to_index(&network, source);
+    let sink = NodeIndexable::to_index(&network, sink);
+    let max_flow = if source == sink {
+        N::EdgeWeight::zero()
+    } else {
+        residual.max_flow(source, sink)
+    };
+
+    let mut flows = vec![N::EdgeWeight::zero(); network.edge_bound()];
+    for (i, &edge) in residual.edges.iter().enumerate() {
+        // The residual capacity of the reverse arc is the flow of the edge.
+        flows[edge] = residual.capacities[2 * i + 1];
+    }
+    (max_flow, flows)
+}
+
+/// The residual network, with dense node indices.
+///
+/// Each edge `i` is represented by the arc `2 * i` and its reverse arc `2 * i + 1`.
+struct ResidualNetwork<W> {
+    /// The edge index of each edge.
+    edges: Vec<usize>,
+    /// The head of each arc.
+    heads: Vec<usize>,
+    /// The residual capacity of each arc.
+    capacities: Vec<W>,
+    /// The arcs leaving node `v` are `adjacency[offsets[v]..offsets[v + 1]]`.
+    offsets: Vec<usize>,
+    adjacency: Vec<usize>,
+}
+
+impl<W> ResidualNetwork<W>
+where
+    W: Sub<Output = W> + PositiveMeasure,
+{
+    fn new<N>(network: N) -> Self
+    where
+        N: IntoEdgeReferences<EdgeWeight = W> + EdgeIndexable + NodeIndexable,
+    {
+        let node_bound = network.node_bound();
+        let mut edges = Vec::new();
+        let mut tails = Vec::new();
+        let mut heads = Vec::new();
+        let mut capacities = Vec::new();
+        for edge in network.edge_references() {
+            let source = NodeIndexable::to_index(&network, edge.source());
+            let target = NodeIndexable::to_index(&network, edge.target());
+            edges.push(EdgeIndexable::to_index(&network, edge.id()));
+            tails.extend([source, target]);
+            heads.extend([target, source]);
+            capacities.extend([*edge.weight(), W::zero()]);
+        }
+
+        // Counting sort of the arcs by their tail.
+        let mut offsets = vec![0; node_bound + 1];
+        for &tail in &tails {
+            offsets[tail + 1] += 1;
+        }
+        for v in 0..node_bound {
+            offsets[v + 1] += offsets[v];
+        }
+        let mut next = offsets.clone();
+        let mut adjacency = vec![0; tails.len()];
+        for (arc, &tail) in tails.iter().enumerate() {
+            adjacency[next[tail]] = arc;
+            next[tail] += 1;
+        }
+
+        ResidualNetwork {
+            edges,
+            heads,
+            capacities,
+            offsets,
+            adjacency,
+        }
+    }
+
+    fn node_bound(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn arcs(&self, v: usize) -> &[usize] {
+        &self.adjacency[self.offsets[v]..self.offsets[v + 1]]
+    }
+
+    /// Compute a maximum flow from `source` to `sink`, leaving the residual capacities
+    /// of the flow in `self`.
+    fn max_flow(&mut self, source: usize, sink: usize) -> W {
+        let n = self.node_bound();
+        let mut state = PushRelabel {
+            heights: vec![0; n],
+            excess: vec![W::zero(); n],
+            current: self.offsets[..n].to_vec(),
+            buckets: vec![Vec::new(); 2 * n + 1],
+            counts: vec![0; 2 * n + 1],
+            highest: 0,
+        };
+
+        // Saturate the arcs leaving the source. Its excess is left at zero, as it is
+        // never discharged.
+        for i in self.offsets[source]..self.offsets[source + 1] {
+            let arc = self.adjacency[i];
+            let w = self.heads[arc];
+            if w == source {
+                continue;
+            }
+            let delta = self.capacities[arc];
+            self.capacities[arc] = W::zero();
+            self.capacities[arc ^ 1] = self.capacities[arc ^ 1] + delta;
+            state.excess[w] = state.excess[w] + delta;
+        }
+
+        self.global_relabel(&mut state, source, sink);
+        let mut relabels = 0;
+        while let Some(v) = state.pop_active() {
+            if v == source || v == sink {
+                continue;
+            }
+            relabels += self.discharge(&mut state, v);
+            if relabels >= n {
+                relabels = 0;
+                self.global_relabel(&mut state, source, sink);
+            }
+        }
+        state.excess[sink]
+    }
+
+    /// Push `delta` units of flow from `v` along `arc`.
+    fn push(&mut self, state: &mut PushRelabel<W>, v: usize, arc: usize, delta: W) {
+        let w = self.heads[arc];
+        self.capacities[arc] = self.capacities[arc] - delta;
+        self.capacities[arc ^ 1] = self.capacities[arc ^ 1] + delta;
+        state.excess[v] = state.excess[v] - delta;
+        let was_active = state.excess[w] > W::zero();
+        state.excess[w] = state.excess[w] + delta;
+        if !was_active {
+            state.push_active(w);
+        }
+    }
+
+    /// Push the excess of `v` to its neighbors, relabeling it as needed.
+    ///
+    /// Returns the number of relabel operations.
+    fn discharge(&mut self, state: &mut PushRelabel<W>, v: usize) -> usize {
+        let n = self.node_bound();
+        let end = self.offsets[v + 1];
+        let mut relabels = 0;
+        while state.excess[v] > W::zero() {
+            if state.current[v] == end {
+                // Relabel: lift v just above its lowest residual neighbor.
+                let old_height = state.heights[v];
+                let new_height = self
+                    .arcs(v)
+                    .iter()
+                    .filter(|&&arc| self.capacities[arc] > W::zero())
+                    .map(|&arc| state.heights[self.heads[arc]] + 1)
+                    .min()
+                    .unwrap_or(2 * n)
+                    .min(2 * n);
+                state.set_height(v, new_height);
+                state.current[v] = self.offsets[v];
+                relabels += 1;
+                if old_height < n && state.counts[old_height] == 0 {
+                    state.gap(old_height, &self.offsets[..n]);
+                }
+                if state.heights[v] >= 2 * n {
+                    break;
+                }
+                continue;
+            }
+
+            let arc = self.adjacency[state.current[v]];
+            let w = self.heads[arc];
+            let capacity = self.capacities[arc];
+            if capacity > W::zero() && state.heights[v] == state.heights[w] + 1 {
+                let delta = if state.excess[v] < capacity {
+                    state.excess[v]
+                } else {
+                    capacity
+                };
+                self.push(state, v, arc, delta);
+            } else {
+                state.current[v] += 1;
+            }
+        }
+        relabels
+    }
+
+    /// Set every height to the exact residual distance to the sink, or, for the nodes
+    /// that cannot reach the sink anymore, to `n` plus the distance to the source.
+    fn global_relabel(&self, state: &mut PushRelabel<W>, source: usize, sink: usize) {
+        let n = self.node_bound();
+        let unlabeled = usize::MAX;
+        let mut heights = vec![unlabeled; n];
+        let mut queue = VecDeque::new();
+        for (root, base) in [(sink, 0), (source, n)] {
+            heights[root] = base;
+            queue.push_back(root);
+            while let Some(w) = queue.pop_front() {
+                // Label the tails of the residual arcs entering w.
+                for &arc in self.arcs(w) {
+                    let v = self.heads[arc];
+                    if heights[v] == unlabeled && self.capacities[arc ^ 1] > W::zero() {
+                        heights[v] = heights[w] + 1;
+                        queue.push_back(v);
+                    }
+                }
+            }
+        }
+
+        for bucket in &mut state.buckets {
+            bucket.clear();
+        }
+        for count in &mut state.counts {
+            *count = 0;
+        }
+        state.highest = 0;
+        for (v, &height) in heights.iter().enumerate() {
+            let height = if height == unlabeled { 2 * n } else { height };
+            state.heights[v] = height;
+            state.counts[height] += 1;
+            state.current[v] = self.offsets[v];
+            if v != source && v != sink && state.excess[v] > W::zero() {
+                state.push_active(v);
+            }
+        }
+    }
+}
+
+/// Labels and active nodes of the push-relabel algorithm.
+struct PushRelabel<W> {
+    heights: Vec<usize>,
+    excess: Vec<W>,
+    /// The position of the current arc of each node in the adjacency array.
+    current: Vec<usize>,
+    /// The active nodes, by height. Entries of nodes that were lifted since are stale.
+    buckets: Vec<Vec<usize>>,
+    /// The number of nodes at each height.
+    counts: Vec<usize>,
+    /// An upper bound of the height of the highest active node.
+    highest: usize,
+}
+
+impl<W> PushRelabel<W> {
+    fn push_active(&mut self, v: usize) {
+        let height = self.heights[v];
+        self.buckets[height].push(v);
+        if height > self.highest {
+            self.highest = height;
+        }
+    }
+
+    fn pop_active(&mut self) -> Option<usize> {
+        loop {
+            while let Some(v) = self.buckets[self.highest].pop() {
+                if self.heights[v] == self.highest {
+                    return Some(v);
+                }
+            }
+            if self.highest == 0 {
+                return None;
+            }
+            self.highest -= 1;
+        }
+    }
+
+    fn set_height(&mut self, v: usize, height: usize) {
+        self.counts[self.heights[v]] -= 1;
+        self.heights[v] = height;
+        self.counts[height] += 1;
+    }
+
+    /// No node has height `gap` anymore: the nodes above it, up to `n`, cannot reach the sink.
+    fn gap(&mut self, gap: usize, first_arcs: &[usize]) {
+        let n = self.heights.len();
+        for (v, &first_arc) in first_arcs.iter().enumerate() {
+            let height = self.heights[v];
+            if gap < height && height < n {
+                self.set_height(v, n + 1);
+                self.current[v] = first_arc;
+                self.buckets[n + 1].push(v);
+                if n + 1 > self.highest {
+                    self.highest = n + 1;
+                }
+            }
+        }
+    }
+}
@@ -7,8 +7,11 @@
 pub mod articulation_points;
 pub mod astar;
 pub mod bellman_ford;
+pub mod bidirectional;
 pub mod bridges;
+pub mod centrality;
 pub mod coloring;
+pub mod community;
 pub mod connectivity;
 pub mod dijkstra;
 pub mod dominators;
@@ -18,6 +21,7 @@ pub mod ford_fulkerson;
 pub mod isomorphism;
 pub mod johnson;
 pub mod k_shortest_path;
+pub mod longest_path;
 pub mod matching;
 pub mod maximal_cliques;
 pub mod maximum_flow;
@@ -26,7 +30,6 @@ pub mod page_rank;
 pub mod scc;
 pub mod simple_paths;
 pub mod spfa;
-#[cfg(feature = "stable_graph")]
 pub mod steiner_tree;
 pub mod tred;
 
@@ -45,9 +48,18 @@ use crate::visit::Walker;
 
 pub use astar::astar;
 pub use bellman_ford::{bellman_ford, find_negative_cycle};
+pub use bidirectional::{bidirectional_astar, bidirectional_dijkstra};
 pub use bridges::bridges;
-pub use coloring::dsatur_coloring;
-pub use dijkstra::dijkstra;
+pub use centrality::{
+    betweenness_centrality, closeness_centrality, edge_betweenness_centrality,
+    eigenvector_centrality, harmonic_centrality, hits, katz_centrality,
+    weighted_betweenness_centrality, weighted_edge_betweenness_centrality,
+};
+pub use coloring::{
+    dsatur_coloring, exact_coloring, greedy_coloring, misra_gries_edge_coloring, ColoringStrategy,
+};
+pub use community::{label_propagation, leiden, louvain, modularity};
+pub use dijkstra::{dijkstra, dijkstra_with_paths, DijkstraPaths};
 pub use feedback_arc_set::greedy_feedback_arc_set;
 pub use floyd_warshall::floyd_warshall;
 pub use isomorphism::{
@@ -55,10 +67,18 @@ pub use isomorphism::{
     subgraph_isomorphisms_iter,
 };
 pub use johnson::johnson;
-pub use k_shortest_path::k_shortest_path;
-pub use matching::{greedy_matching, maximum_bipartite_matching, maximum_matching, Matching};
+pub use k_shortest_path::{k_shortest_path, shortest_simple_paths};
+pub use longest_path::{critical_path, longest_path, CriticalPath};
+pub use matching::{
+    greedy_matching, maximum_bipartite_matching, maximum_matching,
+    maximum_weight_bipartite_matching, maximum_weight_matching,
+    minimum_cost_perfect_bipartite_matching, Matching,
+};
 pub use maximal_cliques::maximal_cliques;
-pub use maximum_flow::{dinics, ford_fulkerson};
+pub use maximum_flow::{
+    dinics, ford_fulkerson, min_cost_flow, min_cost_max_flow, min_st_cut, push_relabel,
+    stoer_wagner_min_cut,
+};
 pub use min_spanning_tree::{min_spanning_tree, min_spanning_tree_prim};
 pub use page_rank::page_rank;
 #[allow(deprecated)]
@@ -71,7 +91,14 @@ pub use simple_paths::all_simple_paths;
 pub use spfa::spfa;
 #[cfg(feature = "stable_graph")]
 pub use steiner_tree::steiner_tree;
+pub use steiner_tree::{dreyfus_wagner_steiner_tree, mehlhorn_steiner_tree};
 
+#[cfg(feature = "rayon")]
+pub use centrality::{
+    parallel_betweenness_centrality, parallel_closeness_centrality,
+    parallel_edge_betweenness_centrality, parallel_harmonic_centrality,
+    parallel_weighted_betweenness_centrality, parallel_weighted_edge_betweenness_centrality,
+};
 #[cfg(feature = "rayon")]
 pub use johnson::parallel_johnson;
 
@@ -1,24 +1,38 @@
-use alloc::vec::Vec;
-use core::{fmt::Debug, hash::Hash};
-
-use hashbrown::{HashMap, HashSet};
-
-use crate::algo::floyd_warshall::floyd_warshall_path;
-use crate::algo::{dijkstra, min_spanning_tree, BoundedMeasure, Measure};
-use crate::data::FromElements;
-use crate::graph::{IndexType, NodeIndex, UnGraph};
-use crate::visit::{
-    Data, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges, IntoNeighbors,
-    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeIndexable, Visitable,
-};
-use crate::Undirected;
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::{cmp::Ordering, hash::Hash};
+
+use hashbrown::HashSet;
+
+use crate::algo::Measure;
+use crate::scored::MinScored;
+use crate::unionfind::UnionFind;
+use crate::visit::{EdgeRef, IntoEdges, NodeIndexable};
+
+#[cfg(feature = "stable_graph")]
+use core::fmt::Debug;
+
+#[cfg(feature = "stable_graph")]
+use hashbrown::HashMap;
 
 #[cfg(feature = "stable_graph")]
-use crate::stable_graph::StableGraph;
+use crate::{
+    algo::{dijkstra, floyd_warshall::floyd_warshall_path, min_spanning_tree, BoundedMeasure},
+    data::FromElements,
+    graph::{IndexType, NodeIndex, UnGraph},
+    stable_graph::StableGraph,
+    visit::{
+        Data, GraphBase, GraphProp, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers,
+        IntoNodeReferences, NodeCompactIndexable, Visitable,
+    },
+    Undirected,
+};
 
+#[cfg(feature = "stable_graph")]
 type Edge<G> = (<G as GraphBase>::NodeId, <G as GraphBase>::NodeId);
+#[cfg(feature = "stable_graph")]
 type Subgraph<G> = HashSet<<G as GraphBase>::NodeId>;
 
+#[cfg(feature = "stable_graph")]
 fn compute_shortest_path_length<G>(graph: G, source: G::NodeId, target: G::NodeId) -> G::EdgeWeight
 where
     G: Visitable + IntoEdges,
@@ -29,6 +43,7 @@ where
     output[&target]
 }
 
+#[cfg(feature = "stable_graph")]
 fn compute_metric_closure<G>(
     graph: G,
     terminals: &[G::NodeId],
@@ -50,6 +65,7 @@ where
     closure
 }
 
+#[cfg(feature = "stable_graph")]
 fn subgraph_edges_from_metric_closure<G>(
     graph: G,
     minimum_spanning_closure: G,
@@ -86,6 +102,7 @@ where
     (retained_edges, retained_nodes)
 }
 
+#[cfg(feature = "stable_graph")]
 fn non_terminal_leaves<G>(graph: G, terminals: &[G::NodeId]) -> HashSet<G::NodeId>
 where
     G: GraphBase + IntoNodeReferences + IntoNodeIdentifiers + IntoNeighbors,
@@ -210,7 +227,290 @@ where
     graph
 }
 
-#[cfg(test)]
+/// [Steiner Tree][1] approximation by [Mehlhorn's algorithm][2].
+///
+/// Computes a tree of an undirected graph connecting the given terminal nodes, whose cost
+/// is at most twice the cost of a minimum Steiner tree. Each node is assigned to its
+/// nearest terminal, and the tree is made of the shortest paths between neighboring
+/// terminals chosen by a minimum spanning tree, which is much faster than [`steiner_tree`]
+/// on large graphs.
+///
+/// ## Arguments
+/// * `graph`: The undirected graph in which to find the Steiner tree.
+/// * `terminals`: A slice of the nodes to connect.
+/// * `edge_cost`: Closure that returns the non-negative cost of an edge.
+///
+/// ## Returns
+/// * `Some(edges)`: The set of the edges of the tree.
+/// * `None`: If the terminals are not connected.
+///
+/// ## Complexity
+/// Time complexity: **O((|V| + |E|)log(|V|))**.
+/// where **|V|** the number of vertices (i.e nodes) and **|E|** the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Steiner_tree_problem
+/// [2]: https://doi.org/10.1016/0020-0190(88)90066-X
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::steiner_tree::mehlhorn_steiner_tree;
+/// use petgraph::graph::UnGraph;
+/// let mut graph = UnGraph::<(), i32>::default();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// graph.extend_with_edges([
+///     (a, b, 7),
+///     (a, f, 6),
+///     (b, c, 1),
+///     (b, f, 5),
+///     (c, d, 1),
+///     (c, e, 3),
+///     (d, e, 1),
+///     (d, f, 4),
+///     (e, f, 10),
+/// ]);
+/// let terminals = vec![a, c, e, f];
+/// let tree = mehlhorn_steiner_tree(&graph, &terminals, |edge| *edge.weight()).unwrap();
+/// assert_eq!(tree.iter().map(|&edge| graph[edge]).sum::<i32>(), 12);
+/// ```
+pub fn mehlhorn_steiner_tree<G, F, K>(
+    graph: G,
+    terminals: &[G::NodeId],
+    mut edge_cost: F,
+) -> Option<HashSet<G::EdgeId>>
+where
+    G: IntoEdges + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let terminals = terminal_indices(graph, terminals);
+    let n = graph.node_bound();
+
+    // Shortest paths from the nearest terminal of each node.
+    let mut distances = vec![None; n];
+    let mut predecessors = vec![None; n];
+    let mut regions = vec![usize::MAX; n];
+    for (region, &terminal) in terminals.iter().enumerate() {
+        distances[terminal] = Some(K::default());
+        regions[terminal] = region;
+    }
+    let order = dijkstra_from(graph, &mut distances, &mut predecessors, &mut edge_cost);
+    for v in order {
+        if let Some((_, u)) = predecessors[v] {
+            regions[v] = regions[u];
+        }
+    }
+
+    // Each edge between two regions stands for a path between their terminals.
+    let mut bridges = Vec::new();
+    for edge in graph.edge_references() {
+        let (u, v) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if let (Some(du), Some(dv)) = (distances[u], distances[v]) {
+            if regions[u] != regions[v] {
+                bridges.push((du + edge_cost(edge) + dv, edge.id(), u, v));
+            }
+        }
+    }
+    bridges.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let mut tree = HashSet::new();
+    let mut components = UnionFind::new(terminals.len());
+    let mut joined = 1;
+    for (_, edge, u, v) in bridges {
+        if !components.union(regions[u], regions[v]) {
+            continue;
+        }
+        tree.insert(edge);
+        for mut node in [u, v] {
+            while let Some((edge, previous)) = predecessors[node] {
+                if !tree.insert(edge) {
+                    break;
+                }
+                node = previous;
+            }
+        }
+        joined += 1;
+    }
+
+    (joined >= terminals.len()).then_some(tree)
+}
+
+/// [Steiner Tree][1] algorithm by [Dreyfus and Wagner][2].
+///
+/// Computes a minimum Steiner tree of an undirected graph connecting the given terminal
+/// nodes, by dynamic programming over the subsets of terminals. It is exact, but its
+/// running time is exponential in the number of terminals, so it is meant for a few
+/// terminals.
+///
+/// ## Arguments
+/// * `graph`: The undirected graph in which to find the Steiner tree.
+/// * `terminals`: A slice of the nodes to connect.
+/// * `edge_cost`: Closure that returns the non-negative cost of an edge.
+///
+/// ## Returns
+/// * `Some(edges)`: The set of the edges of the tree.
+/// * `None`: If the terminals are not connected.
+///
+/// ## Complexity
+/// Time complexity: **O(3^|S| |V| + 2^|S| (|V| + |E|)log(|V|))**.
+/// Auxiliary space: **O(2^|S| |V|)**.
+/// where **|V|** the number of vertices (i.e nodes), **|E|** the number of edges and **|S|**
+/// the number of provided terminals.
+///
+/// [1]: https://en.wikipedia.org/wiki/Steiner_tree_problem
+/// [2]: https://doi.org/10.1002/net.3230010302
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::steiner_tree::dreyfus_wagner_steiner_tree;
+/// use petgraph::graph::UnGraph;
+/// // A star, whose center is cheaper to use than the edges between the terminals.
+/// let mut graph = UnGraph::<(), u32>::default();
+/// let center = graph.add_node(());
+/// let terminals: Vec<_> = (0..3).map(|_| graph.add_node(())).collect();
+/// for (i, &terminal) in terminals.iter().enumerate() {
+///     graph.add_edge(center, terminal, 2);
+///     graph.add_edge(terminal, terminals[(i + 1) % 3], 3);
+/// }
+/// let tree = dreyfus_wagner_steiner_tree(&graph, &terminals, |edge| *edge.weight()).unwrap();
+/// assert_eq!(tree.iter().map(|&edge| graph[edge]).sum::<u32>(), 6);
+/// ```
+pub fn dreyfus_wagner_steiner_tree<G, F, K>(
+    graph: G,
+    terminals: &[G::NodeId],
+    mut edge_cost: F,
+) -> Option<HashSet<G::EdgeId>>
+where
+    G: IntoEdges + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let terminals = terminal_indices(graph, terminals);
+    let (root, terminals) = match terminals.split_last() {
+        Some((root, terminals)) if !terminals.is_empty() => (root, terminals),
+        _ => return Some(HashSet::new()),
+    };
+    let n = graph.node_bound();
+    let full = (1 << terminals.len()) - 1;
+
+    // `costs[s][v]` is the cost of a minimum tree connecting the terminals in the subset
+    // `s` and the node `v`, which is made of the `splits[s][v]` and `s - splits[s][v]`
+    // trees if not zero, and of the `predecessors[s][v]` edge and tree otherwise.
+    let mut costs = vec![Vec::new(); full + 1];
+    let mut splits = vec![Vec::new(); full + 1];
+    let mut predecessors = vec![Vec::new(); full + 1];
+    for subset in 1..=full {
+        let mut cost = vec![None; n];
+        let mut split = vec![0; n];
+        if subset.count_ones() == 1 {
+            cost[terminals[subset.trailing_zeros() as usize]] = Some(K::default());
+        } else {
+            // Submasks which contain the lowest terminal, so that each split is seen once.
+            let lowest = subset & subset.wrapping_neg();
+            let mut part = (subset - 1) & subset;
+            while part > 0 {
+                if part & lowest != 0 {
+                    let (left, right) = (&costs[part], &costs[subset ^ part]);
+                    for v in 0..n {
+                        if let (Some(a), Some(b)) = (left[v], right[v]) {
+                            let total = a + b;
+                            if cost[v].map_or(true, |c| total < c) {
+                                cost[v] = Some(total);
+                                split[v] = part;
+                            }
+                        }
+                    }
+                }
+                part = (part - 1) & subset;
+            }
+        }
+        let mut predecessor = vec![None; n];
+        dijkstra_from(graph, &mut cost, &mut predecessor, &mut edge_cost);
+        costs[subset] = cost;
+        splits[subset] = split;
+        predecessors[subset] = predecessor;
+    }
+
+    costs[full][*root]?;
+    let mut tree = HashSet::new();
+    let mut stack = vec![(full, *root)];
+    while let Some((subset, v)) = stack.pop() {
+        if subset == 0 {
+            continue;
+        }
+        if let Some((edge, u)) = predecessors[subset][v] {
+            tree.insert(edge);
+            stack.push((subset, u));
+        } else if splits[subset][v] != 0 {
+            let part = splits[subset][v];
+            stack.push((part, v));
+            stack.push((subset ^ part, v));
+        }
+    }
+    Some(tree)
+}
+
+/// Return the indices of the terminals, without duplicates.
+fn terminal_indices<G: NodeIndexable>(graph: G, terminals: &[G::NodeId]) -> Vec<usize> {
+    let mut seen = HashSet::new();
+    terminals
+        .iter()
+        .map(|&terminal| graph.to_index(terminal))
+        .filter(|&terminal| seen.insert(terminal))
+        .collect()
+}
+
+/// Dijkstra's algorithm from the nodes with a known distance, which records the edge and
+/// node preceding each reached node. Return the nodes in the order they were reached.
+fn dijkstra_from<G, F, K>(
+    graph: G,
+    distances: &mut [Option<K>],
+    predecessors: &mut [Option<(G::EdgeId, usize)>],
+    edge_cost: &mut F,
+) -> Vec<usize>
+where
+    G: IntoEdges + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut heap: BinaryHeap<_> = distances
+        .iter()
+        .enumerate()
+        .filter_map(|(v, distance)| distance.map(|distance| MinScored(distance, v)))
+        .collect();
+    let mut visited = vec![false; distances.len()];
+    let mut order = Vec::new();
+    while let Some(MinScored(distance, u)) = heap.pop() {
+        if visited[u] {
+            continue;
+        }
+        visited[u] = true;
+        order.push(u);
+        for edge in graph.edges(graph.from_index(u)) {
+            let v = graph.to_index(edge.target());
+            if visited[v] {
+                continue;
+            }
+            let next = distance + edge_cost(edge);
+            if distances[v].map_or(true, |d| next < d) {
+                distances[v] = Some(next);
+                predecessors[v] = Some((edge.id(), u));
+                heap.push(MinScored(next, v));
+            }
+        }
+    }
+    order
+}
+
+#[cfg(all(test, feature = "stable_graph"))]
 mod test {
     use alloc::vec;
 
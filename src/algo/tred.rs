@@ -1,4 +1,4 @@
-//! Compute the transitive reduction and closure of a directed acyclic graph
+//! Compute the transitive reduction and closure of a directed graph
 //!
 //! ## Transitive reduction and closure
 //! The *transitive closure* of a graph **G = (V, E)** is the graph **Gc = (V, Ec)**
@@ -7,15 +7,24 @@
 //! = (V, Er)** such that **Er** is minimal wrt. inclusion in **E** and the transitive
 //! closure of **Gr** is the same as that of **G**.
 //! The transitive reduction is well-defined for acyclic graphs only.
+//!
+//! The functions [`dag_to_toposorted_adjacency_list`] and
+//! [`dag_transitive_reduction_closure`] work on acyclic graphs, while
+//! [`transitive_closure`], [`transitive_reduction`] and [`ReachabilityIndex`] accept
+//! any directed graph, by working on its strongly connected components.
 
 use alloc::{vec, vec::Vec};
+use core::hash::Hash;
 
 use fixedbitset::FixedBitSet;
+use hashbrown::HashMap;
 
 use crate::adj::{List, UnweightedList};
+use crate::algo::tarjan_scc;
 use crate::graph::IndexType;
 use crate::visit::{
-    GraphBase, IntoNeighbors, IntoNeighborsDirected, NodeCompactIndexable, NodeCount,
+    GraphBase, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCompactIndexable,
+    NodeCount, NodeIndexable,
 };
 use crate::Direction;
 
@@ -150,6 +159,239 @@ pub fn dag_transitive_reduction_closure<E, Ix: IndexType>(
     (tred, tclos)
 }
 
+/// An index answering whether a node of a directed graph can reach another one.
+///
+/// The index condenses the strongly connected components of the graph, and stores the
+/// components reachable from each component in a bitset, so that each query takes
+/// constant time. A node reaches another one when there is a path of at least one edge
+/// between them: a node reaches itself only if it belongs to a cycle.
+///
+/// The index is a snapshot, which does not follow later changes to the graph.
+///
+/// # Example
+///
+/// ```rust
+/// use petgraph::algo::tred::ReachabilityIndex;
+/// use petgraph::graph::DiGraph;
+///
+/// let graph = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 1), (3, 0)]);
+/// let index = ReachabilityIndex::new(&graph);
+///
+/// assert!(index.reaches(3.into(), 2.into()));
+/// assert!(index.reaches(1.into(), 1.into()));
+/// assert!(!index.reaches(0.into(), 0.into()));
+/// assert!(!index.reaches(2.into(), 0.into()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReachabilityIndex<N> {
+    /// The strongly connected component of each node.
+    components: HashMap<N, usize>,
+    /// The other components reachable from each component.
+    reachable: Vec<FixedBitSet>,
+    /// The components which have a cycle.
+    cyclic: FixedBitSet,
+}
+
+impl<N: Copy + Eq + Hash> ReachabilityIndex<N> {
+    /// Build the reachability index of a directed graph.
+    ///
+    /// # Complexity
+    /// * Time complexity: **O(|V| + |E| + |E||C|/w)**.
+    /// * Auxiliary space: **O(|V| + |C|²/w)**.
+    ///
+    /// where **|V|** is the number of nodes, **|E|** is the number of edges, **|C|** is
+    /// the number of strongly connected components and **w** is the word size.
+    pub fn new<G>(graph: G) -> Self
+    where
+        G: IntoNeighbors<NodeId = N> + IntoNodeIdentifiers + NodeIndexable,
+    {
+        // The components come in reverse topological order, so the components reachable
+        // from a component are known before it.
+        let sccs = tarjan_scc(graph);
+        let mut components = HashMap::with_capacity(graph.node_bound());
+        for (c, scc) in sccs.iter().enumerate() {
+            for &node in scc {
+                components.insert(node, c);
+            }
+        }
+        let mut reachable = vec![FixedBitSet::with_capacity(sccs.len()); sccs.len()];
+        let mut cyclic = FixedBitSet::with_capacity(sccs.len());
+        for (c, scc) in sccs.iter().enumerate() {
+            let mut reach = FixedBitSet::with_capacity(sccs.len());
+            for &node in scc {
+                for next in graph.neighbors(node) {
+                    let d = components[&next];
+                    if d == c {
+                        cyclic.insert(c);
+                    } else if !reach.contains(d) {
+                        reach.insert(d);
+                        reach.union_with(&reachable[d]);
+                    }
+                }
+            }
+            reachable[c] = reach;
+        }
+        ReachabilityIndex {
+            components,
+            reachable,
+            cyclic,
+        }
+    }
+
+    fn component_reaches(&self, c: usize, d: usize) -> bool {
+        if c == d {
+            self.cyclic.contains(c)
+        } else {
+            self.reachable[c].contains(d)
+        }
+    }
+
+    /// Return `true` if there is a path of at least one edge from `a` to `b`.
+    ///
+    /// Return `false` if `a` or `b` was not in the graph.
+    pub fn reaches(&self, a: N, b: N) -> bool {
+        match (self.components.get(&a), self.components.get(&b)) {
+            (Some(&a), Some(&b)) => self.component_reaches(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Compute the transitive closure of a directed graph.
+///
+/// The closure has an edge from `a` to `b` whenever there is a path of at least one edge
+/// from `a` to `b` in the graph, so it has a loop on each node that belongs to a cycle.
+///
+/// # Arguments
+/// * `graph`: a directed graph, which may have cycles.
+///
+/// # Returns
+/// The edges of the transitive closure, as pairs of nodes, sorted by their source then
+/// their target in the order of [`node_identifiers`](IntoNodeIdentifiers::node_identifiers).
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E||C|/w)**.
+/// * Auxiliary space: **O(|V| + |C|²/w)**, besides the output.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges, **|C|** is the
+/// number of strongly connected components and **w** is the word size.
+///
+/// # Example
+///
+/// ```rust
+/// use petgraph::algo::tred::transitive_closure;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// let graph = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 1)]);
+/// let closure: Vec<(usize, usize)> = transitive_closure(&graph)
+///     .into_iter()
+///     .map(|(a, b)| (a.index(), b.index()))
+///     .collect();
+/// assert_eq!(closure, [(0, 1), (0, 2), (1, 1), (1, 2), (2, 1), (2, 2)]);
+/// ```
+pub fn transitive_closure<G>(graph: G) -> Vec<(G::NodeId, G::NodeId)>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let index = ReachabilityIndex::new(graph);
+    let mut closure = Vec::new();
+    for a in graph.node_identifiers() {
+        let c = index.components[&a];
+        for b in graph.node_identifiers() {
+            if index.component_reaches(c, index.components[&b]) {
+                closure.push((a, b));
+            }
+        }
+    }
+    closure
+}
+
+/// Compute a transitive reduction of a directed graph.
+///
+/// The reduction has the same transitive closure as the graph, with as few edges as
+/// possible. For an acyclic graph, it is unique and made of edges of the graph. For a
+/// graph with cycles, the nodes of each strongly connected component are joined by a
+/// single cycle following the order of
+/// [`node_identifiers`](IntoNodeIdentifiers::node_identifiers), whose edges might not
+/// be in the graph, and a node on a loop keeps its loop. The components are joined by
+/// edges of the graph.
+///
+/// # Arguments
+/// * `graph`: a directed graph, which may have cycles.
+///
+/// # Returns
+/// The edges of the transitive reduction, as pairs of nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E||C|/w)**.
+/// * Auxiliary space: **O(|V| + |C|²/w)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges, **|C|** is the
+/// number of strongly connected components and **w** is the word size.
+///
+/// # Example
+///
+/// ```rust
+/// use petgraph::algo::tred::transitive_reduction;
+/// use petgraph::graph::DiGraph;
+///
+/// // A cycle of three nodes, each joined to a sink, which is only reached once.
+/// let graph = DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (0, 2), (0, 3), (1, 3), (2, 3),
+/// ]);
+/// let reduction = transitive_reduction(&graph);
+/// assert_eq!(reduction.len(), 4);
+/// ```
+pub fn transitive_reduction<G>(graph: G) -> Vec<(G::NodeId, G::NodeId)>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let index = ReachabilityIndex::new(graph);
+    let component_count = index.reachable.len();
+    let mut members = vec![Vec::new(); component_count];
+    for node in graph.node_identifiers() {
+        members[index.components[&node]].push(node);
+    }
+
+    let mut reduction = Vec::new();
+    // The first edge of the graph found between the component and each successor.
+    let mut successors = vec![None; component_count];
+    let mut touched = Vec::new();
+    for (c, nodes) in members.iter().enumerate() {
+        if nodes.len() > 1 {
+            let next = nodes.iter().skip(1).chain(nodes.first());
+            reduction.extend(nodes.iter().copied().zip(next.copied()));
+        } else if index.cyclic.contains(c) {
+            reduction.push((nodes[0], nodes[0]));
+        }
+
+        for &a in nodes {
+            for b in graph.neighbors(a) {
+                let d = index.components[&b];
+                if d != c && successors[d].is_none() {
+                    successors[d] = Some((a, b));
+                    touched.push(d);
+                }
+            }
+        }
+        // An edge to a successor is redundant if another successor reaches it.
+        let mut redundant = FixedBitSet::with_capacity(component_count);
+        for &d in &touched {
+            redundant.union_with(&index.reachable[d]);
+        }
+        for d in touched.drain(..) {
+            if let Some(edge) = successors[d].take() {
+                if !redundant.contains(d) {
+                    reduction.push(edge);
+                }
+            }
+        }
+    }
+    reduction
+}
+
 #[cfg(test)]
 #[test]
 fn test_easy_tred() {
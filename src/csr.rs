@@ -6,22 +6,27 @@ use core::{
     fmt,
     iter::{Enumerate, Zip},
     marker::PhantomData,
-    ops::{Index, IndexMut, Range},
+    ops::{Deref, Index, IndexMut, Range},
     slice::Windows,
 };
 
 use crate::visit::{
-    Data, EdgeCount, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences,
-    IntoEdges, IntoNeighbors, IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable,
-    NodeCount, NodeIndexable, Visitable,
+    Data, EdgeCount, EdgeIndexable, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp,
+    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeIndexable,
+    NodeRef, Visitable,
 };
 
+use crate::data::{Build, Create};
 use crate::util::zip;
 
 #[doc(no_inline)]
 pub use crate::graph::{DefaultIx, IndexType};
 
-use crate::{Directed, EdgeType, IntoWeightedEdge};
+use crate::{Directed, Direction, EdgeType, IntoWeightedEdge, Undirected};
+
+#[cfg(feature = "serde-1")]
+mod serialization;
 
 /// Csr node index type, a plain integer.
 pub type NodeIndex<Ix = DefaultIx> = Ix;
@@ -69,6 +74,14 @@ impl fmt::Display for CsrError {
 ///
 /// Fast iteration of the outgoing edges of a node.
 ///
+/// An undirected edge is stored in both directions, so that it is an outgoing
+/// edge of both of its endpoints for [`edges`](Csr::edges),
+/// [`neighbors_slice`](Csr::neighbors_slice) and [`IntoEdgeReferences`], while
+/// [`edge_count`](Csr::edge_count) counts it once. Both directions have the same
+/// edge id, the position of the one stored from the lower endpoint, so that
+/// [`EdgeIndexable`] gives the edge a single index and the index of the other
+/// direction is unused.
+///
 /// [`CSR`]: https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)
 #[derive(Debug)]
 pub struct Csr<N = (), E = (), Ty = Directed, Ix = DefaultIx> {
@@ -153,6 +166,130 @@ where
             ty: PhantomData,
         }
     }
+
+    /// Create a new `Csr` from a sequence of edges in any order.
+    ///
+    /// The graph has as many nodes as needed for the largest node index, and `N`
+    /// must implement [`Default`] for the weight of each node. Of repeated edges,
+    /// only the first one is kept, as with [`add_edge`](Csr::add_edge).
+    ///
+    /// The edges are sorted with a counting sort, so this computes in
+    /// **O(|V| + |E|)** time where V is the set of nodes and E is the set of edges.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    /// use petgraph::prelude::*;
+    ///
+    /// let graph = Csr::<(), u32>::from_edges([(2, 0, 5), (0, 2, 1), (0, 1, 3), (0, 2, 7)]);
+    /// assert_eq!(graph.node_count(), 3);
+    /// assert_eq!(graph.edge_count(), 3);
+    /// assert_eq!(graph.neighbors_slice(0), &[1, 2]);
+    /// assert_eq!(graph.edges_slice(0), &[3, 1]);
+    /// ```
+    pub fn from_edges<I>(iterable: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoWeightedEdge<E, NodeId = NodeIndex<Ix>>,
+        N: Default,
+        E: Clone,
+    {
+        let mut edges = Vec::new();
+        for edge in iterable {
+            let (a, b, weight) = edge.into_weighted_edge();
+            if !Ty::is_directed() && a != b {
+                edges.push((b.index(), a, weight.clone()));
+            }
+            edges.push((a.index(), b, weight));
+        }
+        let node_count = edges
+            .iter()
+            .map(|&(a, b, _)| max(a, b.index()) + 1)
+            .max()
+            .unwrap_or(0);
+        let mut self_ = Self::with_nodes(node_count);
+        self_.rebuild_with(edges.into_iter().map(|(a, b, w)| (a, b, Some(w))));
+        self_
+    }
+
+    /// Create a new `Csr` with a copy of the nodes and edges of `graph`.
+    ///
+    /// This is the bulk version of [`convert`](crate::data::convert) for `Csr`
+    /// targets: the edges are sorted at once with a counting sort instead of
+    /// being inserted one by one. The nodes get compact indices in the order of
+    /// [`IntoNodeReferences::node_references`], and of parallel edges only the
+    /// first one is kept.
+    ///
+    /// # Arguments
+    /// * `graph`: the graph to copy.
+    ///
+    /// # Returns
+    /// * The new graph and the new index of each node, indexed by the node
+    ///   indices of `graph` as given by [`NodeIndexable::to_index`]. The entries
+    ///   of indices without a node are `None`.
+    ///
+    /// # Complexity
+    /// * Time complexity: **O(|V| + |E|)**.
+    /// * Auxiliary space: **O(|V| + |E|)**.
+    ///
+    /// where **|V|** is the number of nodes and **|E|** is the number of edges.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    /// use petgraph::prelude::*;
+    ///
+    /// let mut graph = StableGraph::<&str, u32>::new();
+    /// let a = graph.add_node("a");
+    /// let b = graph.add_node("b");
+    /// let c = graph.add_node("c");
+    /// graph.extend_with_edges([(c, a, 3), (a, c, 1), (a, b, 2)]);
+    /// graph.remove_node(b);
+    ///
+    /// let (csr, node_map) = Csr::<_, _>::from_graph(&graph);
+    /// assert_eq!(node_map, [Some(0), None, Some(1)]);
+    /// assert_eq!(csr.neighbors_slice(0), &[1]);
+    /// assert_eq!(csr.edges_slice(1), &[3]);
+    /// ```
+    pub fn from_graph<G>(graph: G) -> (Self, Vec<Option<NodeIndex<Ix>>>)
+    where
+        G: IntoNodeReferences
+            + IntoEdgeReferences
+            + NodeIndexable
+            + GraphProp<EdgeType = Ty>
+            + Data<NodeWeight = N, EdgeWeight = E>,
+        N: Clone,
+        E: Clone,
+    {
+        let mut node_map = vec![None; graph.node_bound()];
+        let mut node_weights = Vec::new();
+        for node in graph.node_references() {
+            node_map[graph.to_index(node.id())] = Some(Ix::new(node_weights.len()));
+            node_weights.push(node.weight().clone());
+        }
+        let mut edges = Vec::new();
+        // Both directions of an undirected edge are added: an edge listed more
+        // than once, like the two stored directions of an undirected `Csr` edge,
+        // is kept once by `rebuild_with`.
+        for edge in graph.edge_references() {
+            let a = node_map[graph.to_index(edge.source())].unwrap();
+            let b = node_map[graph.to_index(edge.target())].unwrap();
+            if !Ty::is_directed() && a != b {
+                edges.push((b.index(), a, Some(edge.weight().clone())));
+            }
+            edges.push((a.index(), b, Some(edge.weight().clone())));
+        }
+        let mut self_ = Csr {
+            column: Vec::new(),
+            edges: Vec::new(),
+            row: vec![0; node_weights.len() + 1],
+            node_weights,
+            edge_count: 0,
+            ty: PhantomData,
+        };
+        self_.rebuild_with(edges);
+        (self_, node_map)
+    }
 }
 
 /// Csr creation error: edges were not in sorted order.
@@ -162,6 +299,22 @@ pub struct EdgesNotSorted {
     first_error: (usize, usize),
 }
 
+/// A change to the edges of a `Csr`, for [`Csr::apply_changes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EdgeChange<E, Ix = DefaultIx> {
+    /// Add an edge from `source` to `target`, unless it already exists.
+    Add {
+        source: NodeIndex<Ix>,
+        target: NodeIndex<Ix>,
+        weight: E,
+    },
+    /// Remove the edge from `source` to `target`, if it exists.
+    Remove {
+        source: NodeIndex<Ix>,
+        target: NodeIndex<Ix>,
+    },
+}
+
 impl<N, E, Ix> Csr<N, E, Directed, Ix>
 where
     Ix: IndexType,
@@ -259,6 +412,61 @@ where
     }
 }
 
+impl<N, E, Ix> Csr<N, E, Undirected, Ix>
+where
+    Ix: IndexType,
+{
+    /// Create a new undirected `Csr` from a sorted sequence of edges, storing
+    /// each edge in both directions.
+    ///
+    /// Edges **must** be sorted and unique, where the sort order is the default
+    /// order for the pair *(u, v)* in Rust (*u* has priority), and an edge and its
+    /// reverse are the same edge.
+    ///
+    /// Computes in **O(|V| + |E|)** time where V is the set of nodes and E is the set of edges.
+    /// # Example
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    /// use petgraph::prelude::*;
+    ///
+    /// let graph = Csr::<(), (), Undirected>::from_sorted_undirected_edges(&[(0, 1), (0, 2), (2, 2)]).unwrap();
+    /// assert_eq!(graph.edge_count(), 3);
+    /// assert_eq!(graph.neighbors_slice(2), &[0, 2]);
+    ///
+    /// assert!(Csr::<(), (), Undirected>::from_sorted_undirected_edges(&[(0, 1), (1, 0)]).is_err());
+    /// ```
+    pub fn from_sorted_undirected_edges<Edge>(edges: &[Edge]) -> Result<Self, EdgesNotSorted>
+    where
+        Edge: Clone + IntoWeightedEdge<E, NodeId = NodeIndex<Ix>>,
+        N: Default,
+        E: Clone,
+    {
+        let pairs = edges.iter().map(|edge| {
+            let (a, b, _) = edge.clone().into_weighted_edge();
+            (a.index(), b.index())
+        });
+        let mut last = None;
+        for pair in pairs.clone() {
+            if last.map_or(false, |last| last >= pair) {
+                return Err(EdgesNotSorted { first_error: pair });
+            }
+            last = Some(pair);
+        }
+        let self_ = Self::from_edges(edges.iter().cloned());
+        if self_.edge_count() != edges.len() {
+            // An edge was given along with its reverse.
+            let sorted: Vec<_> = pairs.clone().collect();
+            let first_error = pairs
+                .filter(|&(a, b)| a > b)
+                .find(|&(a, b)| sorted.binary_search(&(b, a)).is_ok());
+            return Err(EdgesNotSorted {
+                first_error: first_error.unwrap(),
+            });
+        }
+        Ok(self_)
+    }
+}
+
 impl<N, E, Ty, Ix> Csr<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -373,6 +581,210 @@ where
         Ok(true)
     }
 
+    /// Remove the edge from `a` to `b` and return its weight, or `None` if it
+    /// does not exist.
+    ///
+    /// Computes in **O(|V| + |E|)** time where V is the set of nodes and E is the set of edges.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn remove_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<E> {
+        let weight = self.remove_edge_(a, b)?;
+        if !self.is_directed() {
+            self.edge_count -= 1;
+            if a != b {
+                self.remove_edge_(b, a);
+            }
+        }
+        Some(weight)
+    }
+
+    fn remove_edge_(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<E> {
+        let pos = self.find_edge_pos(a, b).ok()?;
+        self.column.remove(pos);
+        for r in &mut self.row[a.index() + 1..] {
+            *r -= 1;
+        }
+        Some(self.edges.remove(pos))
+    }
+
+    /// Remove the node `a` with all its edges and return its weight, or `None`
+    /// if it does not exist.
+    ///
+    /// The nodes after `a` are shifted down by one, so that the node with index
+    /// `i > a` has index `i - 1` afterwards.
+    ///
+    /// Computes in **O(|V| + |E|)** time where V is the set of nodes and E is the set of edges.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> Option<N> {
+        if a.index() >= self.node_count() {
+            return None;
+        }
+        if !self.is_directed() {
+            self.edge_count -= self.out_degree(a);
+        }
+        let column = core::mem::take(&mut self.column);
+        let edges = core::mem::take(&mut self.edges);
+        let mut old_edges = column.into_iter().zip(edges);
+        let mut row = Vec::with_capacity(self.row.len() - 1);
+        row.push(0);
+        for (node, degree) in self.row.windows(2).map(|w| w[1] - w[0]).enumerate() {
+            for (target, weight) in old_edges.by_ref().take(degree) {
+                if node == a.index() || target == a {
+                    continue;
+                }
+                let target = if target.index() > a.index() {
+                    Ix::new(target.index() - 1)
+                } else {
+                    target
+                };
+                self.column.push(target);
+                self.edges.push(weight);
+            }
+            if node != a.index() {
+                row.push(self.column.len());
+            }
+        }
+        self.row = row;
+        Some(self.node_weights.remove(a.index()))
+    }
+
+    /// Apply a batch of edge insertions and removals, in order, rebuilding the
+    /// `Csr` once.
+    ///
+    /// Each change behaves as the corresponding call of [`add_edge`](Csr::add_edge)
+    /// or [`remove_edge`](Csr::remove_edge): adding an existing edge keeps its
+    /// weight, so remove it first to replace it.
+    ///
+    /// The changes are sorted with a counting sort, so this computes in
+    /// **O(|V| + |E| + |C|)** time where V is the set of nodes, E is the set of
+    /// edges and C is the sequence of changes.
+    ///
+    /// **Panics** if a node of a change does not exist.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::csr::{Csr, EdgeChange};
+    ///
+    /// let mut graph = Csr::<(), u32>::from_edges([(0, 1, 1), (1, 2, 2)]);
+    /// graph.apply_changes([
+    ///     EdgeChange::Add { source: 2, target: 0, weight: 3 },
+    ///     EdgeChange::Remove { source: 0, target: 1 },
+    ///     EdgeChange::Remove { source: 1, target: 2 },
+    ///     EdgeChange::Add { source: 1, target: 2, weight: 4 },
+    /// ]);
+    /// assert_eq!(graph.edge_count(), 2);
+    /// assert_eq!(graph.edges_slice(1), &[4]);
+    /// assert_eq!(graph.neighbors_slice(2), &[0]);
+    /// ```
+    #[track_caller]
+    pub fn apply_changes<I>(&mut self, changes: I)
+    where
+        I: IntoIterator<Item = EdgeChange<E, Ix>>,
+        E: Clone,
+    {
+        self.try_apply_changes(changes).unwrap()
+    }
+
+    /// Try to apply a batch of edge insertions and removals, in order,
+    /// rebuilding the `Csr` once.
+    ///
+    /// See [`apply_changes`](Csr::apply_changes). The `Csr` is left unchanged
+    /// if an error occurs.
+    ///
+    /// Possible errors:
+    /// - [`CsrError::IndicesOutBounds`] - when a node of a change is out of bounds.
+    pub fn try_apply_changes<I>(&mut self, changes: I) -> Result<(), CsrError>
+    where
+        I: IntoIterator<Item = EdgeChange<E, Ix>>,
+        E: Clone,
+    {
+        let node_count = self.node_count();
+        let mut edges = Vec::new();
+        for change in changes {
+            let (a, b, weight) = match change {
+                EdgeChange::Add {
+                    source,
+                    target,
+                    weight,
+                } => (source, target, Some(weight)),
+                EdgeChange::Remove { source, target } => (source, target, None),
+            };
+            if !(a.index() < node_count && b.index() < node_count) {
+                return Err(CsrError::IndicesOutBounds(a.index(), b.index()));
+            }
+            if !self.is_directed() && a != b {
+                edges.push((b.index(), a, weight.clone()));
+            }
+            edges.push((a.index(), b, weight));
+        }
+        self.rebuild_with(edges);
+        Ok(())
+    }
+
+    /// Rebuild the edges of the `Csr`, applying the `changes` to its current
+    /// edges in order. A change is an edge given by its source index and target,
+    /// with the weight to add or `None` to remove it.
+    fn rebuild_with<I>(&mut self, changes: I)
+    where
+        I: IntoIterator<Item = (usize, NodeIndex<Ix>, Option<E>)>,
+    {
+        let node_count = self.node_count();
+        // Sorting by target, then stably by source, sorts the changes by edge
+        // and keeps the changes of each edge in order.
+        let changes = counting_sort(changes.into_iter().collect(), node_count, |c| c.1.index());
+        let changes = counting_sort(changes, node_count, |c| c.0);
+        let mut changes = changes.into_iter().peekable();
+
+        let column = core::mem::take(&mut self.column);
+        let edges = core::mem::take(&mut self.edges);
+        let mut old_edges = column.into_iter().zip(edges);
+        let old_row = core::mem::replace(&mut self.row, Vec::with_capacity(node_count + 1));
+        self.row.push(0);
+        let mut edge_count = 0;
+        for node in 0..node_count {
+            let degree = old_row[node + 1] - old_row[node];
+            let mut old_edges = old_edges.by_ref().take(degree).peekable();
+            loop {
+                let next_old = old_edges.peek().map(|&(target, _)| target);
+                let next_change = changes
+                    .peek()
+                    .filter(|&&(source, _, _)| source == node)
+                    .map(|&(_, target, _)| target);
+                let target = match (next_old, next_change) {
+                    (Some(a), Some(b)) => a.min(b),
+                    (Some(a), None) | (None, Some(a)) => a,
+                    (None, None) => break,
+                };
+                let mut weight = old_edges
+                    .next_if(|&(t, _)| t == target)
+                    .map(|(_, weight)| weight);
+                while let Some((_, _, change)) =
+                    changes.next_if(|&(source, t, _)| source == node && t == target)
+                {
+                    match change {
+                        Some(new_weight) => {
+                            if weight.is_none() {
+                                weight = Some(new_weight);
+                            }
+                        }
+                        None => weight = None,
+                    }
+                }
+                if let Some(weight) = weight {
+                    if node <= target.index() {
+                        edge_count += 1;
+                    }
+                    self.column.push(target);
+                    self.edges.push(weight);
+                }
+            }
+            self.row.push(self.column.len());
+        }
+        if !self.is_directed() {
+            self.edge_count = edge_count;
+        }
+    }
+
     fn find_edge_pos(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Result<usize, usize> {
         let (index, neighbors) = self.neighbors_of(a);
         if neighbors.len() < BINARY_SEARCH_CUTOFF {
@@ -453,16 +865,45 @@ where
         Edges {
             index: r.start,
             source: a,
+            row: &self.row,
+            column: &self.column,
             iter: zip(&self.column[r.clone()], &self.edges[r]),
             ty: self.ty,
         }
     }
 }
 
+/// Return the id of the edge stored at `index`, from `source` to `target`.
+///
+/// An undirected edge is stored in both directions: both get the index of the
+/// one stored from the lower of its endpoints, so that the edge has a single id.
+fn edge_id<Ty, Ix>(
+    row: &[usize],
+    column: &[NodeIndex<Ix>],
+    index: usize,
+    source: NodeIndex<Ix>,
+    target: NodeIndex<Ix>,
+) -> EdgeIndex
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    if Ty::is_directed() || source <= target {
+        return index;
+    }
+    let start = row[target.index()];
+    match column[start..row[target.index() + 1]].binary_search(&source) {
+        Ok(i) => start + i,
+        Err(_) => index,
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Edges<'a, E: 'a, Ty = Directed, Ix: 'a = DefaultIx> {
     index: usize,
     source: NodeIndex<Ix>,
+    row: &'a [usize],
+    column: &'a [NodeIndex<Ix>],
     iter: Zip<SliceIter<'a, NodeIndex<Ix>>, SliceIter<'a, E>>,
     ty: PhantomData<Ty>,
 }
@@ -528,7 +969,7 @@ where
     type Item = EdgeReference<'a, E, Ty, Ix>;
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(move |(&j, w)| {
-            let index = self.index;
+            let index = edge_id::<Ty, Ix>(self.row, self.column, self.index, self.source, j);
             self.index += 1;
             EdgeReference {
                 index,
@@ -553,6 +994,70 @@ where
     type EdgeWeight = E;
 }
 
+impl<N, E, Ty, Ix> Build for Csr<N, E, Ty, Ix>
+where
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        self.add_node(weight)
+    }
+
+    /// Add an edge from `a` to `b` to the `Csr`, with its associated data
+    /// `weight`, and return its index, or `None` if the edge already exists.
+    ///
+    /// **Note** that the indices of the edges after it are shifted by the
+    /// insertion.
+    ///
+    /// **Panics** if `a` or `b` are out of bounds.
+    fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> Option<EdgeIndex> {
+        if self.add_edge(a, b, weight) {
+            self.find_edge_pos(a, b).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Add or update an edge from `a` to `b` to the `Csr`, with its associated
+    /// data `weight`, and return its index.
+    ///
+    /// **Panics** if `a` or `b` are out of bounds.
+    fn update_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex {
+        match self.find_edge_pos(a, b) {
+            Ok(pos) => {
+                if !self.is_directed() && a != b {
+                    let mirror = self.find_edge_pos(b, a).unwrap();
+                    self.edges[mirror] = weight.clone();
+                }
+                self.edges[pos] = weight;
+                pos
+            }
+            Err(_) => Build::add_edge(self, a, b, weight).unwrap(),
+        }
+    }
+}
+
+impl<N, E, Ty, Ix> Create for Csr<N, E, Ty, Ix>
+where
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn with_capacity(nodes: usize, edges: usize) -> Self {
+        let mut row = Vec::with_capacity(nodes + 1);
+        row.push(0);
+        Csr {
+            column: Vec::with_capacity(edges),
+            edges: Vec::with_capacity(edges),
+            row,
+            node_weights: Vec::with_capacity(nodes),
+            edge_count: 0,
+            ty: PhantomData,
+        }
+    }
+}
+
 impl<'a, N, E, Ty, Ix> IntoEdgeReferences for &'a Csr<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -565,6 +1070,7 @@ where
             index: 0,
             source_index: Ix::new(0),
             edge_ranges: self.row.windows(2).enumerate(),
+            row: &self.row,
             column: &self.column,
             edges: &self.edges,
             iter: zip(&[], &[]),
@@ -573,11 +1079,16 @@ where
     }
 }
 
+/// An iterator over all the edges of a `Csr`, in order of their source.
+///
+/// An undirected edge is listed in both of its stored directions, with the
+/// same id.
 #[derive(Debug, Clone)]
 pub struct EdgeReferences<'a, E: 'a, Ty, Ix: 'a> {
     source_index: NodeIndex<Ix>,
     index: usize,
     edge_ranges: Enumerate<Windows<'a, usize>>,
+    row: &'a [usize],
     column: &'a [NodeIndex<Ix>],
     edges: &'a [E],
     iter: Zip<SliceIter<'a, NodeIndex<Ix>>, SliceIter<'a, E>>,
@@ -593,7 +1104,8 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             if let Some((&j, w)) = self.iter.next() {
-                let index = self.index;
+                let index =
+                    edge_id::<Ty, Ix>(self.row, self.column, self.index, self.source_index, j);
                 self.index += 1;
                 return Some(EdgeReference {
                     index,
@@ -797,6 +1309,22 @@ where
     }
 }
 
+impl<N, E, Ty, Ix> EdgeIndexable for Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn edge_bound(&self) -> usize {
+        self.column.len()
+    }
+    fn to_index(&self, a: Self::EdgeId) -> usize {
+        a
+    }
+    fn from_index(&self, ix: usize) -> Self::EdgeId {
+        ix
+    }
+}
+
 impl<N, E, Ty, Ix> GraphProp for Csr<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -886,6 +1414,420 @@ where
     }
 }
 
+/// A [`Csr`] together with a Compressed Sparse Column ([`CSC`]) index of its
+/// incoming edges.
+///
+/// The index gives fast iteration of the incoming edges of a node, so that
+/// `BiCsr` implements [`IntoNeighborsDirected`] and [`IntoEdgesDirected`], as
+/// needed by algorithms walking edges backwards. It is built in
+/// **O(|V| + |E|)** time and uses **O(|V| + |E|)** additional space.
+///
+/// `BiCsr` dereferences to its `Csr`. To change the graph, take the `Csr` back
+/// with [`into_csr`](BiCsr::into_csr) and build a new index afterwards.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::kosaraju_scc;
+/// use petgraph::csr::{BiCsr, Csr};
+/// use petgraph::Incoming;
+///
+/// let csr = Csr::<(), ()>::from_sorted_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]).unwrap();
+/// let graph = BiCsr::new(csr);
+/// assert_eq!(graph.in_neighbors_slice(0), &[2]);
+/// assert_eq!(graph.neighbors_directed(3, Incoming).collect::<Vec<_>>(), vec![2]);
+/// assert_eq!(kosaraju_scc(&graph).len(), 2);
+/// ```
+///
+/// [`CSC`]: https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_column_(CSC_or_CCS)
+#[derive(Debug, Clone)]
+pub struct BiCsr<N = (), E = (), Ty = Directed, Ix = DefaultIx> {
+    csr: Csr<N, E, Ty, Ix>,
+    /// Index of start of the incoming edges of each node. Always node_count + 1 long.
+    in_row: Vec<usize>,
+    /// Source of each incoming edge, in increasing order for each node
+    in_column: Vec<NodeIndex<Ix>>,
+    /// Index in the `Csr` of each incoming edge; lock step with in_column
+    in_edges: Vec<EdgeIndex>,
+}
+
+impl<N, E, Ty, Ix> BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Build the incoming edge index of `csr`.
+    ///
+    /// Computes in **O(|V| + |E|)** time where V is the set of nodes and E is the set of edges.
+    pub fn new(csr: Csr<N, E, Ty, Ix>) -> Self {
+        let mut in_row = vec![0; csr.node_count() + 1];
+        for target in &csr.column {
+            in_row[target.index() + 1] += 1;
+        }
+        let mut sum = 0;
+        for r in &mut in_row {
+            sum += *r;
+            *r = sum;
+        }
+        // Going through the edges by source keeps the sources of each node sorted.
+        let mut next = in_row.clone();
+        let mut in_column = vec![Ix::new(0); csr.column.len()];
+        let mut in_edges = vec![0; csr.column.len()];
+        for (source, w) in csr.row.windows(2).enumerate() {
+            for index in w[0]..w[1] {
+                let pos = &mut next[csr.column[index].index()];
+                in_column[*pos] = Ix::new(source);
+                in_edges[*pos] = index;
+                *pos += 1;
+            }
+        }
+        BiCsr {
+            csr,
+            in_row,
+            in_column,
+            in_edges,
+        }
+    }
+
+    /// Return the `Csr`, dropping the incoming edge index.
+    pub fn into_csr(self) -> Csr<N, E, Ty, Ix> {
+        self.csr
+    }
+
+    fn in_range(&self, a: NodeIndex<Ix>) -> Range<usize> {
+        self.in_row[a.index()]..self.in_row[a.index() + 1]
+    }
+
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        let r = self.in_range(a);
+        r.end - r.start
+    }
+
+    /// Return the sources of the incoming edges of `a`, in increasing order.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn in_neighbors_slice(&self, a: NodeIndex<Ix>) -> &[NodeIndex<Ix>] {
+        &self.in_column[self.in_range(a)]
+    }
+
+    /// Return an iterator of all neighbors that have an edge between them and
+    /// `a`, in the specified direction.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`: All edges connected to `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    #[track_caller]
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Neighbors<'_, Ix> {
+        let slice = match dir {
+            Direction::Outgoing => self.csr.neighbors_slice(a),
+            Direction::Incoming => self.in_neighbors_slice(a),
+        };
+        Neighbors { iter: slice.iter() }
+    }
+
+    /// Return an iterator of all edges of `a`, in the specified direction.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`, `Outgoing`: All edges connected to `a`, with `a` being the source of each
+    ///   edge.
+    /// - `Undirected`, `Incoming`: All edges connected to `a`, with `a` being the target of each
+    ///   edge.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `EdgeReference<E, Ty, Ix>`.
+    #[track_caller]
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> EdgesDirected<'_, E, Ty, Ix> {
+        let inner = match dir {
+            Direction::Outgoing => EdgesDirectedInner::Outgoing(self.csr.edges(a)),
+            Direction::Incoming => {
+                let r = self.in_range(a);
+                EdgesDirectedInner::Incoming {
+                    target: a,
+                    iter: zip(&self.in_column[r.clone()], &self.in_edges[r]),
+                    row: &self.csr.row,
+                    column: &self.csr.column,
+                    weights: &self.csr.edges,
+                }
+            }
+        };
+        EdgesDirected { inner }
+    }
+}
+
+impl<N, E, Ty, Ix> From<Csr<N, E, Ty, Ix>> for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(csr: Csr<N, E, Ty, Ix>) -> Self {
+        Self::new(csr)
+    }
+}
+
+impl<N, E, Ty, Ix> Deref for BiCsr<N, E, Ty, Ix> {
+    type Target = Csr<N, E, Ty, Ix>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.csr
+    }
+}
+
+/// Iterator over the edges of a node in a direction, see
+/// [`BiCsr::edges_directed`].
+#[derive(Clone, Debug)]
+pub struct EdgesDirected<'a, E: 'a, Ty = Directed, Ix: 'a = DefaultIx> {
+    inner: EdgesDirectedInner<'a, E, Ty, Ix>,
+}
+
+#[derive(Clone, Debug)]
+enum EdgesDirectedInner<'a, E: 'a, Ty, Ix: 'a> {
+    Outgoing(Edges<'a, E, Ty, Ix>),
+    Incoming {
+        target: NodeIndex<Ix>,
+        iter: Zip<SliceIter<'a, NodeIndex<Ix>>, SliceIter<'a, EdgeIndex>>,
+        row: &'a [usize],
+        column: &'a [NodeIndex<Ix>],
+        weights: &'a [E],
+    },
+}
+
+impl<'a, E, Ty, Ix> Iterator for EdgesDirected<'a, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = EdgeReference<'a, E, Ty, Ix>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            EdgesDirectedInner::Outgoing(edges) => edges.next(),
+            EdgesDirectedInner::Incoming {
+                target,
+                iter,
+                row,
+                column,
+                weights,
+            } => iter.next().map(|(&source, &index)| EdgeReference {
+                index: edge_id::<Ty, Ix>(row, column, index, source, *target),
+                source,
+                target: *target,
+                weight: &weights[index],
+                ty: PhantomData,
+            }),
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            EdgesDirectedInner::Outgoing(edges) => edges.size_hint(),
+            EdgesDirectedInner::Incoming { iter, .. } => iter.size_hint(),
+        }
+    }
+}
+
+impl<N, E, Ty, Ix> GraphBase for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = EdgeIndex;
+}
+
+impl<N, E, Ty, Ix> Data for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, Ty, Ix> GraphProp for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeType = Ty;
+}
+
+impl<N, E, Ty, Ix> Visitable for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Map = FixedBitSet;
+    fn visit_map(&self) -> FixedBitSet {
+        self.csr.visit_map()
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        self.csr.reset_map(map)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeCount for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        self.csr.node_count()
+    }
+}
+
+impl<N, E, Ty, Ix> EdgeCount for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.csr.edge_count()
+    }
+}
+
+impl<N, E, Ty, Ix> NodeIndexable for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_bound(&self) -> usize {
+        self.csr.node_count()
+    }
+    fn to_index(&self, a: Self::NodeId) -> usize {
+        a.index()
+    }
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        Ix::new(ix)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeCompactIndexable for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+
+impl<N, E, Ty, Ix> EdgeIndexable for BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn edge_bound(&self) -> usize {
+        self.csr.edge_bound()
+    }
+    fn to_index(&self, a: Self::EdgeId) -> usize {
+        a
+    }
+    fn from_index(&self, ix: usize) -> Self::EdgeId {
+        ix
+    }
+}
+
+impl<N, E, Ty, Ix> IntoNodeIdentifiers for &BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeIdentifiers = NodeIdentifiers<Ix>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.csr.node_identifiers()
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNodeReferences for &'a BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = NodeReferences<'a, N, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        self.csr.node_references()
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdgeReferences for &'a BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeRef = EdgeReference<'a, E, Ty, Ix>;
+    type EdgeReferences = EdgeReferences<'a, E, Ty, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        self.csr.edge_references()
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNeighbors for &'a BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Neighbors = Neighbors<'a, Ix>;
+    fn neighbors(self, a: Self::NodeId) -> Self::Neighbors {
+        self.csr.neighbors(a)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNeighborsDirected for &'a BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NeighborsDirected = Neighbors<'a, Ix>;
+    fn neighbors_directed(self, a: Self::NodeId, dir: Direction) -> Self::NeighborsDirected {
+        self.neighbors_directed(a, dir)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdges for &'a BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Edges = Edges<'a, E, Ty, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        self.csr.edges(a)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdgesDirected for &'a BiCsr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgesDirected = EdgesDirected<'a, E, Ty, Ix>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        self.edges_directed(a, dir)
+    }
+}
+
+impl<N, E, Ty, Ix> GetAdjacencyMatrix for &BiCsr<N, E, Ty, Ix>
+where
+    Ix: IndexType,
+    Ty: EdgeType,
+{
+    type AdjMatrix = FixedBitSet;
+
+    fn adjacency_matrix(&self) -> FixedBitSet {
+        (&self.csr).adjacency_matrix()
+    }
+
+    fn is_adjacent(&self, matrix: &FixedBitSet, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        (&self.csr).is_adjacent(matrix, a, b)
+    }
+}
+
 /*
  *
 Example
@@ -900,6 +1842,29 @@ Row   : [0, 2, 5]   <- value index of row start
 
  * */
 
+/// Stably sort `items` by a key in `0..bound` in **O(bound + |items|)** time.
+fn counting_sort<T, F>(items: Vec<T>, bound: usize, key: F) -> Vec<T>
+where
+    F: Fn(&T) -> usize,
+{
+    let mut offsets = vec![0; bound + 1];
+    for item in &items {
+        offsets[key(item) + 1] += 1;
+    }
+    let mut sum = 0;
+    for offset in &mut offsets {
+        sum += *offset;
+        *offset = sum;
+    }
+    let mut slots: Vec<Option<T>> = (0..items.len()).map(|_| None).collect();
+    for item in items {
+        let k = key(&item);
+        slots[offsets[k]] = Some(item);
+        offsets[k] += 1;
+    }
+    slots.into_iter().map(|slot| slot.unwrap()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec::Vec;
@@ -1160,6 +2125,38 @@ mod tests {
         assert_eq!(&m.edges, &m2.edges);
     }
 
+    #[test]
+    fn test_edge_references_undirected() {
+        use crate::visit::EdgeRef;
+        use crate::visit::IntoEdgeReferences;
+        let m = Csr::<(), _, Undirected>::from_sorted_undirected_edges(&[
+            (0, 1, 'a'),
+            (0, 2, 'b'),
+            (1, 1, 'c'),
+            (2, 3, 'd'),
+        ])
+        .unwrap();
+        // Each stored direction is listed, with the id of the edge; a self-loop
+        // is stored once.
+        let edges: Vec<_> = m
+            .edge_references()
+            .map(|e| (e.source(), e.target(), *e.weight(), e.id()))
+            .collect();
+        assert_eq!(
+            edges,
+            [
+                (0, 1, 'a', 0),
+                (0, 2, 'b', 1),
+                (1, 0, 'a', 0),
+                (1, 1, 'c', 3),
+                (2, 0, 'b', 1),
+                (2, 3, 'd', 5),
+                (3, 2, 'd', 5)
+            ]
+        );
+        assert_eq!(m.edge_count(), 4);
+    }
+
     #[test]
     fn test_add_node() {
         let mut g: Csr = Csr::new();
@@ -1217,4 +2214,245 @@ mod tests {
         assert_eq!(refs.next(), Some((2, &44)));
         assert_eq!(refs.next(), None);
     }
+
+    #[test]
+    fn csr_remove_edge() {
+        let mut m: Csr<(), u32> = Csr::from_edges([(0, 1, 1), (0, 2, 2), (1, 2, 3), (2, 2, 4)]);
+        assert_eq!(m.remove_edge(0, 2), Some(2));
+        assert_eq!(m.remove_edge(0, 2), None);
+        assert_eq!(m.remove_edge(2, 0), None);
+        assert_eq!(&m.column, &[1, 2, 2]);
+        assert_eq!(&m.row, &[0, 1, 2, 3]);
+        assert_eq!(m.edge_count(), 3);
+
+        let mut m: Csr<(), u32, Undirected> = Csr::from_edges([(0, 1, 1), (1, 1, 2), (1, 2, 3)]);
+        assert_eq!(m.edge_count(), 3);
+        assert_eq!(m.remove_edge(1, 0), Some(1));
+        assert_eq!(m.remove_edge(1, 1), Some(2));
+        assert_eq!(m.neighbors_slice(0), &[]);
+        assert_eq!(m.neighbors_slice(1), &[2]);
+        assert_eq!(m.neighbors_slice(2), &[1]);
+        assert_eq!(m.edge_count(), 1);
+    }
+
+    #[test]
+    fn csr_remove_node() {
+        let mut m: Csr<char, u32> = Csr::from_edges([(0, 1, 1), (1, 2, 2), (2, 0, 3), (2, 3, 4)]);
+        for (i, weight) in ['a', 'b', 'c', 'd'].into_iter().enumerate() {
+            m[i as u32] = weight;
+        }
+        assert_eq!(m.remove_node(1), Some('b'));
+        assert_eq!(m.remove_node(3), None);
+        assert_eq!(m.node_count(), 3);
+        assert_eq!(m.edge_count(), 2);
+        assert_eq!(m[1], 'c');
+        assert_eq!(m.neighbors_slice(0), &[]);
+        assert_eq!(m.neighbors_slice(1), &[0, 2]);
+        assert_eq!(m.edges_slice(1), &[3, 4]);
+
+        let mut m: Csr<(), (), Undirected> = Csr::from_edges([(0, 1), (1, 1), (1, 2), (2, 3)]);
+        assert_eq!(m.remove_node(1), Some(()));
+        assert_eq!(m.edge_count(), 1);
+        assert_eq!(m.neighbors_slice(0), &[]);
+        assert_eq!(m.neighbors_slice(1), &[2]);
+        assert_eq!(m.neighbors_slice(2), &[1]);
+    }
+
+    #[test]
+    fn csr_apply_changes() {
+        use super::{CsrError, EdgeChange};
+
+        let mut m: Csr<(), u32> = Csr::with_nodes(4);
+        m.add_edge(0, 1, 1);
+        m.add_edge(3, 2, 2);
+        m.apply_changes([
+            EdgeChange::Add {
+                source: 2,
+                target: 0,
+                weight: 3,
+            },
+            EdgeChange::Add {
+                source: 0,
+                target: 1,
+                weight: 4,
+            },
+            EdgeChange::Remove {
+                source: 3,
+                target: 2,
+            },
+            EdgeChange::Add {
+                source: 3,
+                target: 2,
+                weight: 5,
+            },
+            EdgeChange::Add {
+                source: 1,
+                target: 1,
+                weight: 6,
+            },
+            EdgeChange::Remove {
+                source: 1,
+                target: 1,
+            },
+        ]);
+        assert_eq!(&m.column, &[1, 0, 2]);
+        assert_eq!(&m.edges, &[1, 3, 5]);
+        assert_eq!(&m.row, &[0, 1, 1, 2, 3]);
+
+        let error = m.try_apply_changes([
+            EdgeChange::Remove {
+                source: 0,
+                target: 1,
+            },
+            EdgeChange::Remove {
+                source: 4,
+                target: 0,
+            },
+        ]);
+        assert_eq!(error, Err(CsrError::IndicesOutBounds(4, 0)));
+        assert_eq!(m.edge_count(), 3);
+
+        let mut m: Csr<(), (), Undirected> = Csr::with_nodes(3);
+        m.apply_changes([
+            EdgeChange::Add {
+                source: 2,
+                target: 0,
+                weight: (),
+            },
+            EdgeChange::Add {
+                source: 1,
+                target: 1,
+                weight: (),
+            },
+            EdgeChange::Add {
+                source: 0,
+                target: 2,
+                weight: (),
+            },
+        ]);
+        assert_eq!(m.edge_count(), 2);
+        assert_eq!(&m.column, &[2, 1, 0]);
+        m.apply_changes([EdgeChange::Remove {
+            source: 0,
+            target: 2,
+        }]);
+        assert_eq!(m.edge_count(), 1);
+        assert_eq!(&m.column, &[1]);
+    }
+
+    #[test]
+    fn csr_from_edges() {
+        let edges = [
+            (3, 1, 'a'),
+            (0, 2, 'b'),
+            (3, 0, 'c'),
+            (0, 2, 'd'),
+            (1, 1, 'e'),
+        ];
+        let m: Csr<(), char> = Csr::from_edges(edges);
+        let mut expected: Csr<(), char> = Csr::with_nodes(4);
+        for (a, b, weight) in edges {
+            expected.add_edge(a, b, weight);
+        }
+        assert_eq!(&m.column, &expected.column);
+        assert_eq!(&m.edges, &expected.edges);
+        assert_eq!(&m.row, &expected.row);
+        assert_eq!(m.edge_count(), 4);
+
+        let m: Csr<(), char, Undirected> = Csr::from_edges(edges);
+        let mut expected: Csr<(), char, Undirected> = Csr::with_nodes(4);
+        for (a, b, weight) in edges {
+            expected.add_edge(a, b, weight);
+        }
+        assert_eq!(&m.column, &expected.column);
+        assert_eq!(&m.edges, &expected.edges);
+        assert_eq!(&m.row, &expected.row);
+        assert_eq!(m.edge_count(), expected.edge_count());
+
+        let m: Csr = Csr::from_edges(Vec::<(u32, u32)>::new());
+        assert_eq!(m.node_count(), 0);
+    }
+
+    #[test]
+    fn csr_from_sorted_undirected_edges() {
+        let m: Csr<(), u8, Undirected> =
+            Csr::from_sorted_undirected_edges(&[(0, 1, 1), (0, 3, 2), (2, 1, 3), (3, 3, 4)])
+                .unwrap();
+        assert_eq!(m.node_count(), 4);
+        assert_eq!(m.edge_count(), 4);
+        assert_eq!(m.neighbors_slice(1), &[0, 2]);
+        assert_eq!(m.edges_slice(1), &[1, 3]);
+        assert_eq!(m.neighbors_slice(3), &[0, 3]);
+
+        let unsorted = Csr::<(), (), Undirected>::from_sorted_undirected_edges(&[(1, 0), (0, 2)]);
+        assert_eq!(unsorted.unwrap_err().first_error, (0, 2));
+        let reversed =
+            Csr::<(), (), Undirected>::from_sorted_undirected_edges(&[(0, 1), (1, 2), (2, 1)]);
+        assert_eq!(reversed.unwrap_err().first_error, (2, 1));
+    }
+
+    #[test]
+    fn bicsr_incoming() {
+        use super::BiCsr;
+        use crate::visit::EdgeRef;
+        use crate::Direction::{Incoming, Outgoing};
+
+        let m: Csr<(), u8> =
+            Csr::from_edges([(0, 1, 1), (2, 1, 2), (1, 1, 3), (1, 2, 4), (3, 0, 5)]);
+        let g = BiCsr::new(m);
+        assert_eq!(g.in_neighbors_slice(1), &[0, 1, 2]);
+        assert_eq!(g.in_neighbors_slice(3), &[]);
+        assert_eq!(g.in_degree(0), 1);
+        assert_eq!(g.neighbors_directed(2, Outgoing).collect::<Vec<_>>(), [1]);
+        assert_eq!(g.neighbors_directed(2, Incoming).collect::<Vec<_>>(), [1]);
+
+        let incoming: Vec<_> = g
+            .edges_directed(1, Incoming)
+            .map(|e| (e.source(), e.target(), *e.weight()))
+            .collect();
+        assert_eq!(incoming, [(0, 1, 1), (1, 1, 3), (2, 1, 2)]);
+        for e in g.edges_directed(1, Incoming) {
+            assert!(g.edges(e.source()).any(|out| out.id() == e.id()));
+        }
+        assert_eq!(g.into_csr().edge_count(), 5);
+
+        let m: Csr<(), u8, Undirected> = Csr::from_edges([(0, 1, 1), (1, 2, 2)]);
+        let g = BiCsr::from(m);
+        for e in g.edges_directed(1, Incoming) {
+            assert_eq!(e.target(), 1);
+        }
+        assert_eq!(g.in_neighbors_slice(1), g.neighbors_slice(1));
+    }
+
+    #[test]
+    fn bicsr_algorithms() {
+        use super::BiCsr;
+        use crate::algo::{dinics, dominators, kosaraju_scc};
+        use alloc::vec;
+
+        let m: Csr<(), u32> = Csr::from_sorted_edges(&[
+            (0, 1, 3),
+            (0, 2, 2),
+            (1, 2, 1),
+            (1, 3, 2),
+            (2, 3, 3),
+            (3, 1, 1),
+        ])
+        .unwrap();
+        let g = BiCsr::new(m);
+
+        let mut sccs = kosaraju_scc(&g);
+        for scc in &mut sccs {
+            scc.sort();
+        }
+        sccs.sort();
+        assert_eq!(sccs, [vec![0], vec![1, 2, 3]]);
+
+        let doms = dominators::simple_fast(&g, 0);
+        assert_eq!(doms.immediate_dominator(3), Some(0));
+
+        let (max_flow, flows) = dinics(&g, 0, 3);
+        assert_eq!(max_flow, 5);
+        assert_eq!(flows.len(), 6);
+    }
 }
@@ -0,0 +1,193 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Csr, IndexType};
+use crate::graph_impl::serialization::{invalid_length_err, invalid_node_err, EdgeProperty};
+use crate::serde_utils::{FromDeserialized, IntoSerializable};
+use crate::EdgeType;
+
+/// Serialization representation for Csr
+/// Keep in sync with deserialization
+///
+/// The serialization format is as follows, in Pseudorust:
+///
+/// Csr {
+///     nodes: [N],
+///     edge_property: EdgeProperty,
+///     row: [usize],
+///     column: [Ix],
+///     edges: [E],
+/// }
+///
+/// This is the compressed sparse row layout of the graph itself: the
+/// neighbors of node `i` are `column[row[i]..row[i + 1]]`, with the edge
+/// weights in lock step in `edges`.
+///
+/// On deserialization, `row` must have one more element than `nodes`, start at
+/// zero, be non-decreasing and end at the length of `column`, which must be
+/// the length of `edges`. The neighbors of each node must be valid node
+/// indices in strictly increasing order. For undirected graphs, each edge is
+/// stored in both directions and the adjacency must be symmetric.
+#[derive(Serialize)]
+#[serde(rename = "Csr")]
+#[serde(bound(serialize = "N: Serialize, E: Serialize, Ix: Serialize"))]
+pub struct SerCsr<'a, N: 'a, E: 'a, Ix: 'a> {
+    nodes: &'a [N],
+    edge_property: EdgeProperty,
+    row: &'a [usize],
+    column: &'a [Ix],
+    edges: &'a [E],
+}
+
+// Deserialization representation for Csr
+// Keep in sync with serialization
+#[derive(Deserialize)]
+#[serde(rename = "Csr")]
+#[serde(bound(deserialize = "N: Deserialize<'de>, E: Deserialize<'de>, Ix: Deserialize<'de>"))]
+pub struct DeserCsr<N, E, Ix> {
+    nodes: Vec<N>,
+    edge_property: EdgeProperty,
+    row: Vec<usize>,
+    column: Vec<Ix>,
+    edges: Vec<E>,
+}
+
+impl<'a, N, E, Ty, Ix> IntoSerializable for &'a Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = SerCsr<'a, N, E, Ix>;
+    fn into_serializable(self) -> Self::Output {
+        SerCsr {
+            nodes: &self.node_weights,
+            edge_property: EdgeProperty::from(PhantomData::<Ty>),
+            row: &self.row,
+            column: &self.column,
+            edges: &self.edges,
+        }
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+impl<N, E, Ty, Ix> Serialize for Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType + Serialize,
+    N: Serialize,
+    E: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.into_serializable().serialize(serializer)
+    }
+}
+
+fn invalid_row_err<E>(node_count: usize) -> E
+where
+    E: Error,
+{
+    E::custom(format_args!(
+        "invalid value: `row` is not a valid row index for a graph with {node_count} nodes",
+    ))
+}
+
+impl<N, E, Ty, Ix> FromDeserialized for Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Input = DeserCsr<N, E, Ix>;
+    fn from_deserialized<E2>(input: Self::Input) -> Result<Self, E2>
+    where
+        E2: Error,
+    {
+        let ty = PhantomData::<Ty>::from_deserialized(input.edge_property)?;
+        let DeserCsr {
+            nodes,
+            row,
+            column,
+            edges,
+            ..
+        } = input;
+        let node_count = nodes.len();
+        if node_count >= <Ix as IndexType>::max().index() {
+            Err(invalid_length_err::<Ix, _>("node", node_count))?
+        }
+
+        if row.len() != node_count + 1
+            || row[0] != 0
+            || row[node_count] != column.len()
+            || row.windows(2).any(|w| w[0] > w[1])
+        {
+            Err(invalid_row_err(node_count))?
+        }
+        if edges.len() != column.len() {
+            Err(E2::custom(format_args!(
+                "invalid length: graph has {} edge weights for {} edges",
+                edges.len(),
+                column.len()
+            )))?
+        }
+
+        let mut edge_count = 0;
+        for (source, w) in row.windows(2).enumerate() {
+            let neighbors = &column[w[0]..w[1]];
+            for (i, target) in neighbors.iter().enumerate() {
+                if target.index() >= node_count {
+                    Err(invalid_node_err(target.index(), node_count))?
+                }
+                if i > 0 && neighbors[i - 1] >= *target {
+                    Err(E2::custom(format_args!(
+                        "invalid value: neighbors of node `{source}` are not in strictly \
+                         increasing order",
+                    )))?
+                }
+                if !Ty::is_directed() {
+                    let target = target.index();
+                    let symmetric = column[row[target]..row[target + 1]]
+                        .binary_search(&Ix::new(source))
+                        .is_ok();
+                    if !symmetric {
+                        Err(E2::custom(format_args!(
+                            "invalid value: undirected edge `{source}` - `{target}` \
+                             is not stored in both directions",
+                        )))?
+                    }
+                    if source <= target {
+                        edge_count += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(Csr {
+            column,
+            edges,
+            row,
+            node_weights: nodes,
+            edge_count,
+            ty,
+        })
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+impl<'de, N, E, Ty, Ix> Deserialize<'de> for Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType + Deserialize<'de>,
+    N: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::from_deserialized(DeserCsr::deserialize(deserializer)?)
+    }
+}
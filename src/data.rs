@@ -1,9 +1,15 @@
 //! Graph traits for associated data and graph construction.
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::HashSet;
 
 use crate::graph::IndexType;
-use crate::visit::{Data, NodeCount, NodeIndexable, Reversed};
+use crate::visit::{
+    Data, EdgeCount, EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeCount,
+    NodeIndexable, NodeRef, Reversed,
+};
 use crate::EdgeType;
 use crate::Graph;
 
@@ -11,11 +17,14 @@ use crate::Graph;
 use crate::stable_graph::StableGraph;
 
 #[cfg(feature = "graphmap")]
-use {
-    crate::graphmap::{GraphMap, NodeTrait},
-    core::hash::BuildHasher,
+use crate::{
+    graphmap::{GraphMap, NodeTrait},
+    multigraphmap::MultiGraphMap,
 };
 
+#[cfg(feature = "graphmap")]
+use core::hash::BuildHasher;
+
 trait_template! {
     /// Access node and edge weights (associated data).
 #[allow(clippy::needless_arbitrary_self_type)]
@@ -233,6 +242,42 @@ where
     }
 }
 
+#[cfg(feature = "graphmap")]
+impl<N, E, Ty, S> Build for MultiGraphMap<N, E, Ty, S>
+where
+    Ty: EdgeType,
+    N: NodeTrait,
+    S: BuildHasher,
+{
+    fn add_node(&mut self, weight: Self::NodeWeight) -> Self::NodeId {
+        self.add_node(weight)
+    }
+    fn add_edge(
+        &mut self,
+        a: Self::NodeId,
+        b: Self::NodeId,
+        weight: Self::EdgeWeight,
+    ) -> Option<Self::EdgeId> {
+        Some(self.add_edge(a, b, weight))
+    }
+    fn update_edge(
+        &mut self,
+        a: Self::NodeId,
+        b: Self::NodeId,
+        weight: Self::EdgeWeight,
+    ) -> Self::EdgeId {
+        // Update the first of the parallel edges, if any.
+        let key = self.edges_connecting(a, b).next().map(|edge| edge.id());
+        match key {
+            Some(key) => {
+                self[key] = weight;
+                key
+            }
+            None => self.add_edge(a, b, weight),
+        }
+    }
+}
+
 impl<N, E, Ty, Ix> Create for Graph<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -266,6 +311,103 @@ where
     }
 }
 
+#[cfg(feature = "graphmap")]
+impl<N, E, Ty, S> Create for MultiGraphMap<N, E, Ty, S>
+where
+    Ty: EdgeType,
+    N: NodeTrait,
+    S: BuildHasher + Default,
+{
+    fn with_capacity(nodes: usize, edges: usize) -> Self {
+        Self::with_capacity(nodes, edges)
+    }
+}
+
+/// Copy a graph into a new graph of another type, with the same node and edge
+/// weights.
+///
+/// Nodes are added in the order of `graph.node_references()` and edges in the
+/// order of `graph.edge_references()`, through [`Build`]. Node ids generally
+/// differ between the two graphs, so the new id of each node is returned along
+/// with the new graph, indexed by [`NodeIndexable::to_index`] of `graph`. The
+/// entries of indices without a node, e.g. removed nodes of a `StableGraph`,
+/// are `None`.
+///
+/// An undirected edge listed more than once with the same id, like the two
+/// stored directions of an undirected `Csr` edge, is added once.
+///
+/// Converting to a graph that does not allow parallel edges, such as
+/// `GraphMap`, keeps the first of the parallel edges. `GraphMap` also merges
+/// nodes with equal weights, which then have the same new id.
+///
+/// # Arguments
+/// * `graph`: the graph to copy.
+///
+/// # Returns
+/// * The new graph and the new id of each node.
+///
+/// The edges are added one by one with [`Build::add_edge`]. For a `Csr`,
+/// each insertion shifts the edges stored after it, so that the conversion
+/// computes in **O(|E|²)** time: use [`Csr::from_graph`](crate::csr::Csr::from_graph)
+/// instead, which builds the `Csr` at once.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** calls to [`Build::add_node`] and
+///   [`Build::add_edge`] on the new graph, which is **O(|V| + |E|)** in total
+///   for `Graph`, `StableGraph` and `GraphMap`, **O(|V|² + |E|)** for
+///   `MatrixGraph` and **O(|E|²)** for `Csr`.
+/// * Auxiliary space: **O(|V|)** for directed graphs and **O(|V| + |E|)** for
+///   undirected graphs.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::csr::Csr;
+/// use petgraph::data::convert;
+/// use petgraph::prelude::*;
+///
+/// let mut graph = StableGraph::<&str, u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.extend_with_edges([(a, b, 1), (b, c, 2), (c, a, 3)]);
+/// graph.remove_node(b);
+///
+/// let (csr, node_map): (Csr<_, _>, _) = convert(&graph);
+/// assert_eq!(node_map, [Some(0), None, Some(1)]);
+/// assert_eq!(csr[1], "c");
+/// assert_eq!(csr.edges_slice(1), &[3]);
+///
+/// let (map, _): (DiGraphMap<_, _>, _) = convert(&graph);
+/// assert_eq!(map.edge_weight("c", "a"), Some(&3));
+/// ```
+pub fn convert<G, H>(graph: G) -> (H, Vec<Option<H::NodeId>>)
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + EdgeCount + GraphProp,
+    G::EdgeId: Hash + Eq,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+    H: Create<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight>
+        + GraphProp<EdgeType = G::EdgeType>,
+{
+    let mut new_graph = H::with_capacity(graph.node_bound(), graph.edge_count());
+    let mut node_map = vec![None; graph.node_bound()];
+    for node in graph.node_references() {
+        node_map[graph.to_index(node.id())] = Some(new_graph.add_node(node.weight().clone()));
+    }
+    let mut seen = HashSet::new();
+    for edge in graph.edge_references() {
+        if !graph.is_directed() && !seen.insert(edge.id()) {
+            continue;
+        }
+        let a = node_map[graph.to_index(edge.source())].unwrap();
+        let b = node_map[graph.to_index(edge.target())].unwrap();
+        new_graph.add_edge(a, b, edge.weight().clone());
+    }
+    (new_graph, node_map)
+}
+
 /// A graph element.
 ///
 /// A sequence of Elements, for example an iterator, is laid out as follows:
@@ -382,6 +524,22 @@ where
     }
 }
 
+#[cfg(feature = "graphmap")]
+impl<N, E, Ty, S> FromElements for MultiGraphMap<N, E, Ty, S>
+where
+    Ty: EdgeType,
+    N: NodeTrait,
+    S: BuildHasher + Default,
+{
+    fn from_elements<I>(iterable: I) -> Self
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Element<Self::NodeWeight, Self::EdgeWeight>>,
+    {
+        from_elements_indexable(iterable)
+    }
+}
+
 /// Iterator adaptors for iterators of `Element`.
 pub trait ElementIterator<N, E>: Iterator<Item = Element<N, E>> {
     /// Create an iterator adaptor that filters graph elements.
@@ -3,9 +3,24 @@
 //! ***Unstable: API may change at any time.*** Depends on `feature = "generate"`.
 //!
 
+pub mod classic;
+pub mod random;
+
+use alloc::vec::Vec;
+
+use crate::data::Build;
 use crate::graph::NodeIndex;
 use crate::{Directed, EdgeType, Graph};
 
+pub use classic::{
+    complete_bipartite_graph, complete_graph, cycle_graph, grid_graph, hypercube_graph, path_graph,
+    petersen_graph, star_graph,
+};
+pub use random::{
+    barabasi_albert_graph, gnm_random_graph, gnp_random_graph, random_regular_graph,
+    stochastic_block_model, watts_strogatz_graph,
+};
+
 // A DAG has the property that the adjacency matrix is lower triangular,
 // diagonal zero.
 //
@@ -131,3 +146,13 @@ impl<Ty: EdgeType> Iterator for Generator<Ty> {
         self.next_ref().cloned()
     }
 }
+
+/// Add `n` nodes to `graph`, with the weights given by `node_weight`, and return their
+/// identifiers.
+fn add_nodes<G, N>(graph: &mut G, n: usize, mut node_weight: N) -> Vec<G::NodeId>
+where
+    G: Build,
+    N: FnMut(usize) -> G::NodeWeight,
+{
+    (0..n).map(|i| graph.add_node(node_weight(i))).collect()
+}
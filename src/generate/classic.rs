@@ -0,0 +1,353 @@
+//! Generators of classic graph families.
+//!
+//! The nodes are numbered `0..n` in insertion order, and the node and edge weights are
+//! given by closures of these numbers, which allows filling graph types like
+//! [`GraphMap`](crate::graphmap::GraphMap) whose node weights are their identifiers.
+//! Edges go from the lower to the higher node number, unless stated otherwise.
+//!
+//! ***Unstable: API may change at any time.*** Depends on `feature = "generate"`.
+
+use super::add_nodes;
+use crate::data::Create;
+use crate::visit::GraphProp;
+
+/// Complete graph.
+///
+/// Generates a graph with `n` nodes where every pair of distinct nodes is joined by an
+/// edge. In a directed graph, both directions are added.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::complete_graph;
+/// use petgraph::graph::{DiGraph, UnGraph};
+///
+/// let graph: UnGraph<(), ()> = complete_graph(5, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 10);
+/// let graph: DiGraph<(), ()> = complete_graph(5, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 20);
+/// ```
+pub fn complete_graph<G, N, E>(n: usize, node_weight: N, mut edge_weight: E) -> G
+where
+    G: Create + GraphProp,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let mut graph = G::with_capacity(n, n * n.saturating_sub(1));
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    let directed = graph.is_directed();
+    for i in 0..n {
+        for j in i + 1..n {
+            graph.add_edge(nodes[i], nodes[j], edge_weight(i, j));
+            if directed {
+                graph.add_edge(nodes[j], nodes[i], edge_weight(j, i));
+            }
+        }
+    }
+    graph
+}
+
+/// Path graph.
+///
+/// Generates a graph with `n` nodes and an edge from each node number `i` to the node
+/// number `i + 1`.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::path_graph;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph: UnGraph<(), ()> = path_graph(4, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 3);
+/// assert!(graph.contains_edge(NodeIndex::new(2), NodeIndex::new(3)));
+/// ```
+pub fn path_graph<G, N, E>(n: usize, node_weight: N, edge_weight: E) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let edges = (1..n).map(|j| (j - 1, j));
+    from_edges(n, n.saturating_sub(1), edges, node_weight, edge_weight)
+}
+
+/// Cycle graph.
+///
+/// Generates the [`path_graph`] of `n` nodes, closed by an edge from the last node to
+/// the first one. The closing edge is only added if it is neither a self-loop nor, in an
+/// undirected graph, a parallel edge, so that the graph stays simple.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::cycle_graph;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// let graph: DiGraph<(), ()> = cycle_graph(4, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 4);
+/// assert!(graph.contains_edge(NodeIndex::new(3), NodeIndex::new(0)));
+/// ```
+pub fn cycle_graph<G, N, E>(n: usize, node_weight: N, mut edge_weight: E) -> G
+where
+    G: Create + GraphProp,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let mut graph = G::with_capacity(n, n);
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    for j in 1..n {
+        graph.add_edge(nodes[j - 1], nodes[j], edge_weight(j - 1, j));
+    }
+    let closing = if graph.is_directed() { 2 } else { 3 };
+    if n >= closing {
+        graph.add_edge(nodes[n - 1], nodes[0], edge_weight(n - 1, 0));
+    }
+    graph
+}
+
+/// Star graph.
+///
+/// Generates a graph with a center, the node number `0`, joined by an edge to each of
+/// `n` leaves, the nodes number `1..=n`.
+///
+/// # Arguments
+/// * `n`: the number of leaves.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::star_graph;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph: UnGraph<(), ()> = star_graph(5, |_| (), |_, _| ());
+/// assert_eq!(graph.node_count(), 6);
+/// assert_eq!(graph.neighbors(NodeIndex::new(0)).count(), 5);
+/// ```
+pub fn star_graph<G, N, E>(n: usize, node_weight: N, edge_weight: E) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let edges = (1..=n).map(|leaf| (0, leaf));
+    from_edges(n + 1, n, edges, node_weight, edge_weight)
+}
+
+/// Two-dimensional grid graph.
+///
+/// Generates a graph with `rows * columns` nodes, where the node number
+/// `row * columns + column` is joined by an edge to its right and lower neighbors.
+///
+/// # Arguments
+/// * `rows`: the number of rows.
+/// * `columns`: the number of columns.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::grid_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = grid_graph(3, 4, |_| (), |_, _| ());
+/// assert_eq!(graph.node_count(), 12);
+/// assert_eq!(graph.edge_count(), 3 * 3 + 2 * 4);
+/// ```
+pub fn grid_graph<G, N, E>(rows: usize, columns: usize, node_weight: N, edge_weight: E) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let n = rows * columns;
+    let edge_count = rows * columns.saturating_sub(1) + rows.saturating_sub(1) * columns;
+    let edges = (0..n).flat_map(|i| {
+        let right = (i % columns + 1 < columns).then_some((i, i + 1));
+        let down = (i + columns < n).then_some((i, i + columns));
+        right.into_iter().chain(down)
+    });
+    from_edges(n, edge_count, edges, node_weight, edge_weight)
+}
+
+/// Hypercube graph.
+///
+/// Generates a graph with `2^dimension` nodes, where two nodes are joined by an edge
+/// when the binary representations of their numbers differ by exactly one bit.
+///
+/// # Arguments
+/// * `dimension`: the dimension of the hypercube.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Panics
+/// Panics if `2^dimension` does not fit in a `usize`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|log|V|)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::hypercube_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = hypercube_graph(3, |_| (), |_, _| ());
+/// assert_eq!(graph.node_count(), 8);
+/// assert_eq!(graph.edge_count(), 12);
+/// ```
+#[track_caller]
+pub fn hypercube_graph<G, N, E>(dimension: usize, node_weight: N, edge_weight: E) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    assert!(
+        dimension < usize::BITS as usize,
+        "hypercube_graph: the dimension is too large"
+    );
+    let n = 1 << dimension;
+    let edges = (0..n).flat_map(move |i| {
+        (0..dimension)
+            .map(move |bit| (i, i | 1 << bit))
+            .filter(|&(i, j)| i != j)
+    });
+    from_edges(n, n / 2 * dimension, edges, node_weight, edge_weight)
+}
+
+/// Petersen graph.
+///
+/// Generates the Petersen graph: an outer cycle of the nodes `0..5`, an inner pentagram
+/// of the nodes `5..10`, and a spoke from each node number `i` of the outer cycle to the
+/// node number `i + 5`.
+///
+/// # Arguments
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::petersen_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = petersen_graph(|_| (), |_, _| ());
+/// assert_eq!(graph.node_count(), 10);
+/// assert_eq!(graph.edge_count(), 15);
+/// ```
+pub fn petersen_graph<G, N, E>(node_weight: N, edge_weight: E) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let edges = (0..5)
+        .flat_map(|i| [(i, (i + 1) % 5), (i, i + 5), (i + 5, (i + 2) % 5 + 5)])
+        .map(|(i, j)| (i.min(j), i.max(j)));
+    from_edges(10, 15, edges, node_weight, edge_weight)
+}
+
+/// Complete bipartite graph.
+///
+/// Generates a graph with a first part of `a` nodes, the nodes `0..a`, and a second part
+/// of `b` nodes, the nodes `a..a + b`, where each node of the first part is joined by an
+/// edge to each node of the second part.
+///
+/// # Arguments
+/// * `a`: the number of nodes of the first part.
+/// * `b`: the number of nodes of the second part.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::complete_bipartite_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = complete_bipartite_graph(2, 3, |_| (), |_, _| ());
+/// assert_eq!(graph.node_count(), 5);
+/// assert_eq!(graph.edge_count(), 6);
+/// ```
+pub fn complete_bipartite_graph<G, N, E>(a: usize, b: usize, node_weight: N, edge_weight: E) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let edges = (0..a).flat_map(|i| (a..a + b).map(move |j| (i, j)));
+    from_edges(a + b, a * b, edges, node_weight, edge_weight)
+}
+
+/// Create a graph with `n` nodes and the given edges between node numbers.
+fn from_edges<G, I, N, E>(
+    n: usize,
+    edge_count: usize,
+    edges: I,
+    node_weight: N,
+    mut edge_weight: E,
+) -> G
+where
+    G: Create,
+    I: IntoIterator<Item = (usize, usize)>,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let mut graph = G::with_capacity(n, edge_count);
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    for (i, j) in edges {
+        graph.add_edge(nodes[i], nodes[j], edge_weight(i, j));
+    }
+    graph
+}
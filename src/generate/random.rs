@@ -0,0 +1,518 @@
+//! Random graph generators.
+//!
+//! Every generator is driven by a `seed`, so that the same arguments always produce the
+//! same graph. The nodes are numbered `0..n` in insertion order, and the node and edge
+//! weights are given by closures of these numbers, which allows filling graph types
+//! like [`GraphMap`](crate::graphmap::GraphMap) whose node weights are their identifiers.
+//!
+//! ***Unstable: API may change at any time.*** Depends on `feature = "generate"`.
+
+use alloc::{vec, vec::Vec};
+
+use hashbrown::HashSet;
+
+use super::add_nodes;
+use crate::data::Create;
+use crate::util::SplitMix64;
+use crate::visit::GraphProp;
+
+/// Erdős–Rényi **G(n, p)** random graph.
+///
+/// Generates a graph with `n` nodes where each possible edge is present independently
+/// with probability `p`. In a directed graph, both directions of a pair of nodes are
+/// drawn independently. There are no self-loops.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `p`: the probability of each edge.
+/// * `seed`: the seed of the random choices.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge between the nodes
+///   number `i` and `j`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::gnp_random_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = gnp_random_graph(10, 1., 0, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 45);
+/// ```
+pub fn gnp_random_graph<G, N, E>(
+    n: usize,
+    p: f64,
+    seed: u64,
+    node_weight: N,
+    mut edge_weight: E,
+) -> G
+where
+    G: Create + GraphProp,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let mut rng = SplitMix64::new(seed);
+    let mut graph = G::with_capacity(n, 0);
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    let directed = graph.is_directed();
+    for i in 0..n {
+        let start = if directed { 0 } else { i + 1 };
+        for j in start..n {
+            if i != j && rng.gen_f64() < p {
+                graph.add_edge(nodes[i], nodes[j], edge_weight(i, j));
+            }
+        }
+    }
+    graph
+}
+
+/// Erdős–Rényi **G(n, m)** random graph.
+///
+/// Generates a graph with `n` nodes and `m` edges, chosen uniformly among all such
+/// graphs without self-loops and parallel edges. In a directed graph, the two
+/// directions of a pair of nodes are distinct edges.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `m`: the number of edges.
+/// * `seed`: the seed of the random choices.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Panics
+/// Panics if `m` is larger than the number of possible edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected, or **O(|V|²)** for dense graphs.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::gnm_random_graph;
+/// use petgraph::graph::DiGraph;
+///
+/// let graph: DiGraph<(), ()> = gnm_random_graph(10, 20, 0, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 20);
+/// ```
+#[track_caller]
+pub fn gnm_random_graph<G, N, E>(
+    n: usize,
+    m: usize,
+    seed: u64,
+    node_weight: N,
+    mut edge_weight: E,
+) -> G
+where
+    G: Create + GraphProp,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let mut rng = SplitMix64::new(seed);
+    let mut graph = G::with_capacity(n, m);
+    let directed = graph.is_directed();
+    let max_edges = if directed {
+        n * n.saturating_sub(1)
+    } else {
+        n * n.saturating_sub(1) / 2
+    };
+    assert!(
+        m <= max_edges,
+        "A graph with {} nodes has at most {} edges.",
+        n,
+        max_edges
+    );
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    let key = |i: usize, j: usize| if directed || i < j { (i, j) } else { (j, i) };
+
+    // Draw the edges, or for dense graphs the missing edges.
+    let complement = 2 * m > max_edges;
+    let draws = if complement { max_edges - m } else { m };
+    let mut drawn = HashSet::with_capacity(draws);
+    let mut edges = Vec::with_capacity(draws);
+    while edges.len() < draws {
+        let i = rng.gen_index(n);
+        let j = rng.gen_index(n);
+        if i != j && drawn.insert(key(i, j)) {
+            edges.push(key(i, j));
+        }
+    }
+    if complement {
+        edges.clear();
+        for i in 0..n {
+            let start = if directed { 0 } else { i + 1 };
+            for j in start..n {
+                if i != j && !drawn.contains(&(i, j)) {
+                    edges.push((i, j));
+                }
+            }
+        }
+    }
+    for (i, j) in edges {
+        graph.add_edge(nodes[i], nodes[j], edge_weight(i, j));
+    }
+    graph
+}
+
+/// Barabási–Albert preferential attachment random graph.
+///
+/// Generates a graph with `n` nodes, starting from a star of `m + 1` nodes, then adding
+/// the other nodes one at a time, each with edges to `m` distinct existing nodes chosen
+/// with probabilities proportional to their degrees. In a directed graph, the edges go
+/// from the new nodes to the existing ones.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `m`: the number of edges of each new node.
+/// * `seed`: the seed of the random choices.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Panics
+/// Panics if `m` is zero or not smaller than `n`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|m²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::barabasi_albert_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = barabasi_albert_graph(100, 3, 0, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 3 + 96 * 3);
+/// ```
+#[track_caller]
+pub fn barabasi_albert_graph<G, N, E>(
+    n: usize,
+    m: usize,
+    seed: u64,
+    node_weight: N,
+    mut edge_weight: E,
+) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    assert!(
+        0 < m && m < n,
+        "The number of edges of each new node must be in 1..{}.",
+        n
+    );
+    let mut rng = SplitMix64::new(seed);
+    let mut graph = G::with_capacity(n, (n - m) * m);
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    // Each node appears once per incident edge, for sampling proportionally to degrees.
+    let mut repeated_nodes = Vec::with_capacity(2 * (n - m) * m);
+    for leaf in 1..=m {
+        graph.add_edge(nodes[0], nodes[leaf], edge_weight(0, leaf));
+        repeated_nodes.extend([0, leaf]);
+    }
+    let mut targets = Vec::with_capacity(m);
+    for source in m + 1..n {
+        targets.clear();
+        while targets.len() < m {
+            let target = repeated_nodes[rng.gen_index(repeated_nodes.len())];
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+        for &target in &targets {
+            graph.add_edge(nodes[source], nodes[target], edge_weight(source, target));
+            repeated_nodes.extend([source, target]);
+        }
+    }
+    graph
+}
+
+/// Watts–Strogatz small-world random graph.
+///
+/// Generates a ring of `n` nodes, each connected to its `k / 2` nearest neighbors on
+/// each side, then rewires each edge `(u, v)` with probability `p` to `(u, w)`, where
+/// `w` is chosen uniformly among the nodes that are not yet adjacent to `u`. In a
+/// directed graph, each edge is added once, from `u`.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `k`: the number of neighbors of each node in the ring; odd values are rounded down.
+/// * `p`: the probability of rewiring each edge.
+/// * `seed`: the seed of the random choices.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Panics
+/// Panics if `k` is not smaller than `n`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|k)** expected.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::watts_strogatz_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let ring: UnGraph<(), ()> = watts_strogatz_graph(10, 4, 0., 0, |_| (), |_, _| ());
+/// assert!(ring.node_indices().all(|node| ring.neighbors(node).count() == 4));
+/// let graph: UnGraph<(), ()> = watts_strogatz_graph(10, 4, 0.5, 0, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 20);
+/// ```
+#[track_caller]
+pub fn watts_strogatz_graph<G, N, E>(
+    n: usize,
+    k: usize,
+    p: f64,
+    seed: u64,
+    node_weight: N,
+    mut edge_weight: E,
+) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    assert!(
+        k < n,
+        "The number of neighbors must be smaller than the number of nodes."
+    );
+    let mut rng = SplitMix64::new(seed);
+    let half = k / 2;
+    let key = |i: usize, j: usize| if i < j { (i, j) } else { (j, i) };
+    let mut edges = Vec::with_capacity(n * half);
+    let mut present = HashSet::with_capacity(n * half);
+    for j in 1..=half {
+        for u in 0..n {
+            let v = (u + j) % n;
+            edges.push((u, v));
+            present.insert(key(u, v));
+        }
+    }
+    let mut degrees = vec![2 * half; n];
+    for edge in &mut edges {
+        let (u, v) = *edge;
+        if rng.gen_f64() >= p || degrees[u] >= n - 1 {
+            continue;
+        }
+        let mut w = rng.gen_index(n);
+        while w == u || present.contains(&key(u, w)) {
+            w = rng.gen_index(n);
+        }
+        present.remove(&key(u, v));
+        present.insert(key(u, w));
+        degrees[v] -= 1;
+        degrees[w] += 1;
+        *edge = (u, w);
+    }
+
+    let mut graph = G::with_capacity(n, edges.len());
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    for (u, v) in edges {
+        graph.add_edge(nodes[u], nodes[v], edge_weight(u, v));
+    }
+    graph
+}
+
+/// Random regular graph.
+///
+/// Generates a graph with `n` nodes which all have `d` neighbors, drawn nearly
+/// uniformly among such graphs by randomly pairing the edge endpoints, without
+/// self-loops and parallel edges. In a directed graph, each edge is added once.
+///
+/// # Arguments
+/// * `d`: the degree of every node.
+/// * `n`: the number of nodes.
+/// * `seed`: the seed of the random choices.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Panics
+/// Panics if `d` is not smaller than `n`, or if `n * d` is odd.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|d²)** expected for small degrees.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generate::random_regular_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = random_regular_graph(3, 10, 0, |_| (), |_, _| ());
+/// assert!(graph.node_indices().all(|node| graph.neighbors(node).count() == 3));
+/// ```
+#[track_caller]
+pub fn random_regular_graph<G, N, E>(
+    d: usize,
+    n: usize,
+    seed: u64,
+    node_weight: N,
+    mut edge_weight: E,
+) -> G
+where
+    G: Create,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    assert!(
+        d < n || (d == 0 && n == 0),
+        "The degree must be smaller than the number of nodes."
+    );
+    assert!(
+        (n * d) % 2 == 0,
+        "The number of edge endpoints must be even."
+    );
+    let mut rng = SplitMix64::new(seed);
+    let edges = loop {
+        if let Some(edges) = try_regular_pairing(d, n, &mut rng) {
+            break edges;
+        }
+    };
+
+    let mut graph = G::with_capacity(n, edges.len());
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    for (u, v) in edges {
+        graph.add_edge(nodes[u], nodes[v], edge_weight(u, v));
+    }
+    graph
+}
+
+/// Pair the endpoints of a `d`-regular graph at random, pairing again the endpoints of
+/// the rejected pairs until none are left. Return `None` if the remaining endpoints
+/// cannot be paired anymore.
+fn try_regular_pairing(d: usize, n: usize, rng: &mut SplitMix64) -> Option<Vec<(usize, usize)>> {
+    let mut edges = Vec::with_capacity(n * d / 2);
+    let mut present = HashSet::with_capacity(n * d / 2);
+    let mut stubs: Vec<usize> = (0..n).flat_map(|v| core::iter::repeat(v).take(d)).collect();
+    let mut rejected = vec![0; n];
+    while !stubs.is_empty() {
+        rng.shuffle(&mut stubs);
+        for pair in stubs.chunks(2) {
+            let (u, v) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            if u != v && present.insert((u, v)) {
+                edges.push((u, v));
+            } else {
+                rejected[u] += 1;
+                rejected[v] += 1;
+            }
+        }
+        // The rejected endpoints must contain a pair that can still be joined.
+        let candidates: Vec<usize> = (0..n).filter(|&v| rejected[v] > 0).collect();
+        let suitable = candidates.is_empty()
+            || candidates.iter().enumerate().any(|(i, &u)| {
+                candidates[i + 1..]
+                    .iter()
+                    .any(|&v| !present.contains(&(u, v)))
+            });
+        if !suitable {
+            return None;
+        }
+        stubs.clear();
+        for v in candidates {
+            stubs.extend(core::iter::repeat(v).take(rejected[v]));
+            rejected[v] = 0;
+        }
+    }
+    Some(edges)
+}
+
+/// Stochastic block model random graph.
+///
+/// Generates a graph whose nodes are split into consecutive blocks of the given
+/// `sizes`, where an edge between a node of block `a` and a node of block `b` is
+/// present independently with probability `probabilities[a][b]`. In a directed graph,
+/// both directions of a pair of nodes are drawn independently. There are no self-loops.
+///
+/// # Arguments
+/// * `sizes`: the number of nodes of each block.
+/// * `probabilities`: the matrix of the edge probabilities between blocks, which must
+///   be symmetric for undirected graphs.
+/// * `seed`: the seed of the random choices.
+/// * `node_weight`: closure that returns the weight of the node number `i`.
+/// * `edge_weight`: closure that returns the weight of the edge from the node number `i`
+///   to the node number `j`.
+///
+/// # Panics
+/// Panics if `probabilities` is not a square matrix of the size of `sizes`, or if it is
+/// not symmetric for an undirected graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::connected_components;
+/// use petgraph::generate::stochastic_block_model;
+/// use petgraph::graph::UnGraph;
+///
+/// // Two cliques of 5 nodes.
+/// let probabilities = [[1., 0.], [0., 1.]];
+/// let graph: UnGraph<(), ()> =
+///     stochastic_block_model(&[5, 5], &probabilities, 0, |_| (), |_, _| ());
+/// assert_eq!(graph.edge_count(), 20);
+/// assert_eq!(connected_components(&graph), 2);
+/// ```
+#[track_caller]
+pub fn stochastic_block_model<G, P, N, E>(
+    sizes: &[usize],
+    probabilities: &[P],
+    seed: u64,
+    node_weight: N,
+    mut edge_weight: E,
+) -> G
+where
+    G: Create + GraphProp,
+    P: AsRef<[f64]>,
+    N: FnMut(usize) -> G::NodeWeight,
+    E: FnMut(usize, usize) -> G::EdgeWeight,
+{
+    let blocks = sizes.len();
+    assert!(
+        probabilities.len() == blocks
+            && probabilities.iter().all(|row| row.as_ref().len() == blocks),
+        "The probability matrix must have one row and one column per block."
+    );
+    let mut rng = SplitMix64::new(seed);
+    let n = sizes.iter().sum();
+    let mut graph = G::with_capacity(n, 0);
+    let directed = graph.is_directed();
+    if !directed {
+        for a in 0..blocks {
+            for b in 0..a {
+                assert!(
+                    probabilities[a].as_ref()[b] == probabilities[b].as_ref()[a],
+                    "The probability matrix of an undirected graph must be symmetric."
+                );
+            }
+        }
+    }
+    let nodes = add_nodes(&mut graph, n, node_weight);
+    let block_of: Vec<usize> = sizes
+        .iter()
+        .enumerate()
+        .flat_map(|(block, &size)| core::iter::repeat(block).take(size))
+        .collect();
+    for i in 0..n {
+        let start = if directed { 0 } else { i + 1 };
+        for j in start..n {
+            if i != j && rng.gen_f64() < probabilities[block_of[i]].as_ref()[block_of[j]] {
+                graph.add_edge(nodes[i], nodes[j], edge_weight(i, j));
+            }
+        }
+    }
+    graph
+}
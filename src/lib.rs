@@ -127,6 +127,8 @@ Graph { Ty: "Undirected", node_count: 4, edge_count: 4, edges: (0, 1), (1, 2), (
 * [`GraphMap`](./graphmap/struct.GraphMap.html) -
   An adjacency list graph backed by a hash table. The node identifiers are the keys
   into the table.
+* [`MultiGraphMap`](./multigraphmap/struct.MultiGraphMap.html) -
+  Similar to `GraphMap`, but it allows parallel edges, identified by stable edge keys.
 * [`MatrixGraph`](./matrix_graph/struct.MatrixGraph.html) -
   An adjacency matrix graph.
 * [`CSR`](./csr/struct.Csr.html) -
@@ -439,7 +441,8 @@ println!("Enhanced DOT format:\n{:?}", fancy_dot);
 `petgraph` is built with these features enabled by default:
 
 * **graphmap** -
-  Enables [`GraphMap`](./graphmap/struct.GraphMap.html).
+  Enables [`GraphMap`](./graphmap/struct.GraphMap.html) and
+  [`MultiGraphMap`](./multigraphmap/struct.MultiGraphMap.html).
 * **stable_graph** -
   Enables [`StableGraph`](./stable_graph/struct.StableGraph.html).
 * **matrix_graph** -
@@ -450,7 +453,7 @@ println!("Enhanced DOT format:\n{:?}", fancy_dot);
 Optionally, the following features can be enabled:
 
 * **serde-1** -
-  Enables serialization for ``Graph, StableGraph, GraphMap`` using
+  Enables serialization for ``Graph, StableGraph, GraphMap, MatrixGraph, Csr, adj::List`` using
   [`serde 1.0`](https://crates.io/crates/serde). May require a more recent version
   of Rust than petgraph alone.
 * **rayon** -
@@ -514,6 +517,8 @@ mod iter_format;
 mod iter_utils;
 #[cfg(feature = "matrix_graph")]
 pub mod matrix_graph;
+#[cfg(feature = "graphmap")]
+pub mod multigraphmap;
 #[cfg(feature = "quickcheck")]
 mod quickcheck;
 #[cfg(feature = "serde-1")]
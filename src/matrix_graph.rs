@@ -13,7 +13,7 @@ use fixedbitset::FixedBitSet;
 use indexmap::IndexSet;
 
 use crate::{
-    data::Build,
+    data::{Build, Create},
     graph::NodeIndex as GraphNodeIndex,
     visit::{
         Data, EdgeCount, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
@@ -28,6 +28,9 @@ use std::collections::hash_map::RandomState;
 
 pub use crate::graph::IndexType;
 
+#[cfg(feature = "serde-1")]
+mod serialization;
+
 // The following types are used to control the max size of the adjacency matrix. Since the maximum
 // size of the matrix vector's is the square of the maximum number of nodes, the number of nodes
 // should be reasonably picked.
@@ -1475,6 +1478,14 @@ impl<N, E, S: BuildHasher, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexT
     }
 }
 
+impl<N, E, S: BuildHasher + Default, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType>
+    Create for MatrixGraph<N, E, S, Ty, Null, Ix>
+{
+    fn with_capacity(nodes: usize, _edges: usize) -> Self {
+        Self::with_capacity(nodes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
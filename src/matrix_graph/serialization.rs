@@ -0,0 +1,151 @@
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use core::marker::PhantomData;
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{IndexType, MatrixGraph, NodeIndex, Nullable};
+use crate::graph_impl::serialization::{
+    invalid_hole_err, invalid_length_err, invalid_node_err, EdgeProperty,
+};
+use crate::serde_utils::{FromDeserialized, IntoSerializable};
+use crate::visit::IntoEdgeReferences;
+use crate::EdgeType;
+
+/// Serialization representation for MatrixGraph
+/// Keep in sync with deserialization
+///
+/// The serialization format is as follows, in Pseudorust:
+///
+/// MatrixGraph {
+///     nodes: [Option<N>],
+///     edge_property: EdgeProperty,
+///     edges: [(NodeIndex<Ix>, NodeIndex<Ix>, E)],
+/// }
+///
+/// `nodes` has one element per node index up to the node bound, where `None`
+/// is the hole left by a removed node, so that node indices are kept.
+/// Undirected edges are listed once.
+///
+/// On deserialization, the endpoints of every edge must be existing nodes and
+/// there can be at most one edge between two nodes (in each direction, for
+/// directed graphs).
+#[derive(Serialize)]
+#[serde(rename = "MatrixGraph")]
+#[serde(bound(serialize = "N: Serialize, E: Serialize, Ix: IndexType + Serialize"))]
+pub struct SerMatrixGraph<'a, N: 'a, E: 'a, Ix: 'a + IndexType> {
+    nodes: &'a [Option<N>],
+    edge_property: EdgeProperty,
+    edges: Vec<(NodeIndex<Ix>, NodeIndex<Ix>, &'a E)>,
+}
+
+// Deserialization representation for MatrixGraph
+// Keep in sync with serialization
+#[derive(Deserialize)]
+#[serde(rename = "MatrixGraph")]
+#[serde(bound(
+    deserialize = "N: Deserialize<'de>, E: Deserialize<'de>, Ix: IndexType + Deserialize<'de>"
+))]
+pub struct DeserMatrixGraph<N, E, Ix> {
+    nodes: Vec<Option<N>>,
+    edge_property: EdgeProperty,
+    edges: Vec<(NodeIndex<Ix>, NodeIndex<Ix>, E)>,
+}
+
+impl<'a, N, E, S, Ty, Null, Ix> IntoSerializable for &'a MatrixGraph<N, E, S, Ty, Null, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    type Output = SerMatrixGraph<'a, N, E, Ix>;
+    fn into_serializable(self) -> Self::Output {
+        SerMatrixGraph {
+            nodes: &self.nodes.elements[..self.nodes.upper_bound],
+            edge_property: EdgeProperty::from(PhantomData::<Ty>),
+            edges: self.edge_references().collect(),
+        }
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+impl<N, E, S, Ty, Null, Ix> Serialize for MatrixGraph<N, E, S, Ty, Null, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType + Serialize,
+    N: Serialize,
+    E: Serialize,
+{
+    fn serialize<S2>(&self, serializer: S2) -> Result<S2::Ok, S2::Error>
+    where
+        S2: Serializer,
+    {
+        self.into_serializable().serialize(serializer)
+    }
+}
+
+impl<N, E, S, Ty, Null, Ix> FromDeserialized for MatrixGraph<N, E, S, Ty, Null, Ix>
+where
+    S: BuildHasher + Default,
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    type Input = DeserMatrixGraph<N, E, Ix>;
+    fn from_deserialized<E2>(input: Self::Input) -> Result<Self, E2>
+    where
+        E2: Error,
+    {
+        PhantomData::<Ty>::from_deserialized(input.edge_property)?;
+        let node_bound = input.nodes.len();
+        if node_bound >= <Ix as IndexType>::max().index() {
+            Err(invalid_length_err::<Ix, _>("node", node_bound))?
+        }
+
+        let mut graph = Self::with_capacity(node_bound);
+        for (i, node) in input.nodes.iter().enumerate() {
+            if node.is_none() {
+                graph.nodes.removed_ids.insert(i);
+            }
+        }
+        graph.nodes.elements = input.nodes;
+        graph.nodes.upper_bound = node_bound;
+
+        for (a, b, weight) in input.edges {
+            for node in [a, b] {
+                match graph.nodes.elements.get(node.index()) {
+                    None => Err(invalid_node_err(node.index(), node_bound))?,
+                    Some(None) => Err(invalid_hole_err(node.index()))?,
+                    Some(Some(_)) => {}
+                }
+            }
+            if graph.update_edge(a, b, weight).is_some() {
+                Err(E2::custom(format_args!(
+                    "invalid value: parallel edge `{}` - `{}` is not allowed",
+                    a.index(),
+                    b.index()
+                )))?
+            }
+        }
+        Ok(graph)
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+impl<'de, N, E, S, Ty, Null, Ix> Deserialize<'de> for MatrixGraph<N, E, S, Ty, Null, Ix>
+where
+    S: BuildHasher + Default,
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType + Deserialize<'de>,
+    N: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::from_deserialized(DeserMatrixGraph::deserialize(deserializer)?)
+    }
+}
@@ -0,0 +1,1033 @@
+//! `MultiGraphMap<N, E, Ty>` is a graph datastructure where node values are
+//! mapping keys, and that allows parallel edges.
+
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    hash::{BuildHasher, Hash},
+    iter::{Copied, FromIterator},
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    slice::Iter,
+};
+
+use hashbrown::HashSet;
+use indexmap::{
+    map::{Iter as IndexMapIter, Keys},
+    IndexMap,
+};
+
+use crate::{
+    data,
+    graph::{node_index, Graph},
+    graphmap::NodeTrait,
+    visit, Directed, Direction, EdgeType, Incoming, IntoWeightedEdge, Outgoing, Undirected,
+};
+
+#[cfg(feature = "std")]
+use std::collections::hash_map::RandomState;
+
+/// A `MultiGraphMap` with undirected edges.
+///
+/// For example, an edge between *1* and *2* is equivalent to an edge between
+/// *2* and *1*.
+pub type UnMultiGraphMap<
+    N,
+    E,
+    #[cfg(not(feature = "std"))] S,
+    #[cfg(feature = "std")] S = RandomState,
+> = MultiGraphMap<N, E, Undirected, S>;
+/// A `MultiGraphMap` with directed edges.
+///
+/// For example, an edge from *1* to *2* is distinct from an edge from *2* to
+/// *1*.
+pub type DiMultiGraphMap<
+    N,
+    E,
+    #[cfg(not(feature = "std"))] S,
+    #[cfg(feature = "std")] S = RandomState,
+> = MultiGraphMap<N, E, Directed, S>;
+
+/// The key of an edge in a [`MultiGraphMap`].
+///
+/// Edge keys are never reused by a graph, so that they stay valid and refer to
+/// the same edge until it is removed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdgeKey(usize);
+
+/// `MultiGraphMap<N, E, Ty>` is a graph datastructure using an associative
+/// array of its node weights `N`, where any number of edges may connect two
+/// nodes.
+///
+/// It is the counterpart of [`GraphMap`](crate::graphmap::GraphMap) with
+/// parallel edges: adding an edge never replaces an existing one, and each
+/// edge is identified by an [`EdgeKey`] instead of its endpoints. It uses an
+/// adjacency list representation, using **O(|V| + |E|)** space where V is the
+/// set of nodes and E is the set of edges.
+///
+/// `MultiGraphMap` is parameterized over:
+///
+/// - Associated data `N` for nodes and `E` for edges, called *weights*.
+/// - The node weight `N` must implement `Copy` and will be used as node
+///   identifier, duplicated into several places in the data structure.
+///   It must be suitable as a hash table key (implementing `Eq + Hash`).
+/// - `E` can be of arbitrary type.
+/// - Edge type `Ty` that determines whether the graph edges are directed or
+///   undirected.
+///
+/// You can use the type aliases `UnMultiGraphMap` and `DiMultiGraphMap` for
+/// convenience.
+///
+/// Parallel edges and self loops are allowed.
+///
+/// Depends on crate feature `graphmap` (default).
+///
+/// ```
+/// use petgraph::multigraphmap::UnMultiGraphMap;
+///
+/// // Two rail links and a road between the same hubs.
+/// let mut links = UnMultiGraphMap::<_, _>::new();
+/// let rail = links.add_edge("Lyon", "Paris", 2.0);
+/// links.add_edge("Paris", "Lyon", 2.5);
+/// links.add_edge("Lyon", "Paris", 4.5);
+/// assert_eq!(links.edge_count(), 3);
+/// assert_eq!(links.edges_connecting("Paris", "Lyon").count(), 3);
+///
+/// assert_eq!(links.remove_edge(rail), Some(2.0));
+/// assert_eq!(links.edges_connecting("Lyon", "Paris").count(), 2);
+/// ```
+#[derive(Clone)]
+pub struct MultiGraphMap<
+    N,
+    E,
+    Ty,
+    #[cfg(not(feature = "std"))] S,
+    #[cfg(feature = "std")] S = RandomState,
+> where
+    S: BuildHasher,
+{
+    nodes: IndexMap<N, Vec<(N, Direction, EdgeKey)>, S>,
+    edges: IndexMap<EdgeKey, (N, N, E), S>,
+    next_key: usize,
+    ty: PhantomData<Ty>,
+}
+
+impl<N: Eq + Hash + fmt::Debug, E: fmt::Debug, Ty: EdgeType, S: BuildHasher> fmt::Debug
+    for MultiGraphMap<N, E, Ty, S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MultiGraphMap")
+            .field("nodes", &self.nodes.keys())
+            .field("edges", &self.edges.values())
+            .finish()
+    }
+}
+
+impl<N, E, Ty, S> MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    /// Create a new `MultiGraphMap`
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self::default()
+    }
+
+    /// Create a new `MultiGraphMap` with estimated capacity.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            nodes: IndexMap::with_capacity_and_hasher(nodes, S::default()),
+            edges: IndexMap::with_capacity_and_hasher(edges, S::default()),
+            next_key: 0,
+            ty: PhantomData,
+        }
+    }
+
+    /// Create a new `MultiGraphMap` with estimated capacity, and specified hasher.
+    pub fn with_capacity_and_hasher(nodes: usize, edges: usize, hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        Self {
+            nodes: IndexMap::with_capacity_and_hasher(nodes, hasher.clone()),
+            edges: IndexMap::with_capacity_and_hasher(edges, hasher),
+            next_key: 0,
+            ty: PhantomData,
+        }
+    }
+
+    /// Return the current node and edge capacity of the graph.
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.nodes.capacity(), self.edges.capacity())
+    }
+
+    /// Whether the graph has directed edges.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Create a new `MultiGraphMap` from an iterable of edges.
+    ///
+    /// Node values are taken directly from the list.
+    /// Edge weights `E` may either be specified in the list,
+    /// or they are filled with default values.
+    ///
+    /// Nodes are inserted automatically to match the edges, and repeated
+    /// edges are all kept.
+    ///
+    /// ```
+    /// use petgraph::multigraphmap::DiMultiGraphMap;
+    ///
+    /// let gr = DiMultiGraphMap::<_, ()>::from_edges(&[(0, 1), (0, 1), (1, 0)]);
+    /// assert_eq!(gr.edge_count(), 3);
+    /// ```
+    pub fn from_edges<I>(iterable: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoWeightedEdge<E, NodeId = N>,
+        S: Default,
+    {
+        Self::from_iter(iterable)
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Remove all nodes and edges
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+    }
+
+    /// Add node `n` to the graph.
+    pub fn add_node(&mut self, n: N) -> N {
+        self.nodes.entry(n).or_default();
+        n
+    }
+
+    /// Remove node `n` from the graph, with all its edges.
+    ///
+    /// Return `true` if it did exist.
+    ///
+    /// Computes in **O(V)** time, due to the removal of edges with other nodes.
+    pub fn remove_node(&mut self, n: N) -> bool {
+        let links = match self.nodes.swap_remove(&n) {
+            None => return false,
+            Some(links) => links,
+        };
+        for (other, _, key) in links {
+            self.edges.swap_remove(&key);
+            if other != n {
+                self.remove_link(other, key);
+            }
+        }
+        true
+    }
+
+    /// Return `true` if the node is contained in the graph.
+    pub fn contains_node(&self, n: N) -> bool {
+        self.nodes.contains_key(&n)
+    }
+
+    /// Add an edge connecting `a` and `b` to the graph, with associated
+    /// data `weight`, and return its key. For a directed graph, the edge is
+    /// directed from `a` to `b`.
+    ///
+    /// Inserts nodes `a` and/or `b` if they aren't already part of the graph.
+    /// The edge is added even if `a` and `b` are already connected.
+    pub fn add_edge(&mut self, a: N, b: N, weight: E) -> EdgeKey {
+        let key = EdgeKey(self.next_key);
+        self.next_key += 1;
+        self.edges.insert(key, (a, b, weight));
+        self.nodes
+            .entry(a)
+            .or_insert_with(|| Vec::with_capacity(1))
+            .push((b, Outgoing, key));
+        if a != b {
+            // self loops don't have the Incoming entry
+            self.nodes
+                .entry(b)
+                .or_insert_with(|| Vec::with_capacity(1))
+                .push((a, Incoming, key));
+        }
+        key
+    }
+
+    /// Remove the edge with `key` from the adjacency list of `n`.
+    fn remove_link(&mut self, n: N, key: EdgeKey) {
+        if let Some(links) = self.nodes.get_mut(&n) {
+            if let Some(index) = links.iter().position(|&(_, _, k)| k == key) {
+                links.swap_remove(index);
+            }
+        }
+    }
+
+    /// Remove the edge with key `key` from the graph and return the edge weight.
+    ///
+    /// Return `None` if the edge didn't exist.
+    pub fn remove_edge(&mut self, key: EdgeKey) -> Option<E> {
+        let (a, b, weight) = self.edges.swap_remove(&key)?;
+        self.remove_link(a, key);
+        if a != b {
+            self.remove_link(b, key);
+        }
+        Some(weight)
+    }
+
+    /// Return `true` if at least one edge connects `a` with `b`.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn contains_edge(&self, a: N, b: N) -> bool {
+        self.edges_connecting(a, b).next().is_some()
+    }
+
+    /// Return `true` if the edge with key `key` is contained in the graph.
+    pub fn contains_edge_key(&self, key: EdgeKey) -> bool {
+        self.edges.contains_key(&key)
+    }
+
+    /// Return the source and target of the edge with key `key`, or `None`
+    /// if the edge does not exist in the graph.
+    pub fn edge_endpoints(&self, key: EdgeKey) -> Option<(N, N)> {
+        self.edges.get(&key).map(|&(a, b, _)| (a, b))
+    }
+
+    /// Return a reference to the weight of the edge with key `key`, or `None`
+    /// if the edge does not exist in the graph.
+    pub fn edge_weight(&self, key: EdgeKey) -> Option<&E> {
+        self.edges.get(&key).map(|(_, _, weight)| weight)
+    }
+
+    /// Return a mutable reference to the weight of the edge with key `key`, or
+    /// `None` if the edge does not exist in the graph.
+    pub fn edge_weight_mut(&mut self, key: EdgeKey) -> Option<&mut E> {
+        self.edges.get_mut(&key).map(|(_, _, weight)| weight)
+    }
+
+    /// Return an iterator over the nodes of the graph.
+    ///
+    /// Iterator element type is `N`.
+    pub fn nodes(&self) -> Nodes<'_, N> {
+        Nodes {
+            iter: self.nodes.keys().copied(),
+        }
+    }
+
+    /// Return an iterator of all nodes with an edge starting from `a`, once
+    /// per edge.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `N`.
+    pub fn neighbors(&self, a: N) -> Neighbors<'_, N, Ty> {
+        self.neighbors_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of all neighbors that have an edge between them and
+    /// `a`, in the specified direction, once per edge.
+    /// If the graph's edges are undirected, this is equivalent to *.neighbors(a)*.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `N`.
+    pub fn neighbors_directed(&self, a: N, dir: Direction) -> Neighbors<'_, N, Ty> {
+        Neighbors {
+            iter: self.links(a),
+            start_node: a,
+            dir,
+            ty: PhantomData,
+        }
+    }
+
+    /// Return an iterator of the edges starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`, with `a` being the source of
+    ///   each edge.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is [`EdgeReference`].
+    pub fn edges(&self, a: N) -> Edges<'_, N, E, Ty, S> {
+        self.edges_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of the edges of `a` in the specified direction.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`, `Outgoing`: All edges connected to `a`, with `a` being the source of each
+    ///   edge.
+    /// - `Undirected`, `Incoming`: All edges connected to `a`, with `a` being the target of each
+    ///   edge.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is [`EdgeReference`].
+    pub fn edges_directed(&self, a: N, dir: Direction) -> Edges<'_, N, E, Ty, S> {
+        Edges {
+            iter: self.neighbors_directed(a, dir),
+            edges: &self.edges,
+        }
+    }
+
+    /// Return an iterator of all the edges connecting `a` with `b`.
+    ///
+    /// - `Directed`: All edges from `a` to `b`.
+    /// - `Undirected`: All edges between `a` and `b`, with `a` being the source
+    ///   of each edge.
+    ///
+    /// Produces an empty iterator if the node `a` doesn't exist.<br>
+    /// Iterator element type is [`EdgeReference`].
+    pub fn edges_connecting(&self, a: N, b: N) -> EdgesConnecting<'_, N, E, Ty, S> {
+        EdgesConnecting {
+            target_node: b,
+            edges: self.edges(a),
+        }
+    }
+
+    /// Return an iterator over all edges of the graph with their weight in
+    /// arbitrary order.
+    ///
+    /// Iterator element type is [`EdgeReference`].
+    pub fn all_edges(&self) -> AllEdges<'_, N, E> {
+        AllEdges {
+            iter: self.edges.iter(),
+        }
+    }
+
+    /// Return a `Graph` that corresponds to this `MultiGraphMap`, with all its
+    /// parallel edges.
+    ///
+    /// 1. Note that node and edge indices in the `Graph` have nothing in common
+    ///    with the `MultiGraphMap`s node weights `N` and edge keys. The node
+    ///    weights `N` are used as node weights in the resulting `Graph`, too.
+    /// 2. Note that the index type is user-chosen.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average) where V is the set of nodes and E is the set of edges.
+    ///
+    /// **Panics** if the number of nodes or edges does not fit with
+    /// the resulting graph's index type.
+    #[track_caller]
+    pub fn into_graph<Ix>(self) -> Graph<N, E, Ty, Ix>
+    where
+        Ix: crate::graph::IndexType,
+    {
+        let mut gr = Graph::with_capacity(self.node_count(), self.edge_count());
+        for (&node, _) in &self.nodes {
+            gr.add_node(node);
+        }
+        for (_, (a, b, edge_weight)) in self.edges {
+            let ai = self.nodes.get_index_of(&a).unwrap();
+            let bi = self.nodes.get_index_of(&b).unwrap();
+            gr.add_edge(node_index(ai), node_index(bi), edge_weight);
+        }
+        gr
+    }
+
+    /// Creates a `MultiGraphMap` that corresponds to the given `Graph`, with
+    /// all its parallel edges.
+    ///
+    /// **Warning**: Nodes with the same weight are merged. Node and edge
+    /// indices of the `Graph` are lost.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    pub fn from_graph<Ix>(graph: Graph<N, E, Ty, Ix>) -> Self
+    where
+        Ix: crate::graph::IndexType,
+        S: Default,
+    {
+        let mut new_graph = Self::with_capacity(graph.node_count(), graph.edge_count());
+        let (nodes, edges) = graph.into_nodes_edges();
+        let weights: Vec<_> = nodes.iter().map(|node| node.weight).collect();
+        for &weight in &weights {
+            new_graph.add_node(weight);
+        }
+        for edge in edges {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            new_graph.add_edge(weights[a], weights[b], edge.weight);
+        }
+        new_graph
+    }
+
+    /// Return the adjacency list of `a`, which is empty if `a` doesn't exist.
+    fn links(&self, a: N) -> Iter<'_, (N, Direction, EdgeKey)> {
+        match self.nodes.get(&a) {
+            Some(links) => links.iter(),
+            None => [].iter(),
+        }
+    }
+}
+
+/// Create a new `MultiGraphMap` from an iterable of edges.
+impl<N, E, Ty, Item, S> FromIterator<Item> for MultiGraphMap<N, E, Ty, S>
+where
+    Item: IntoWeightedEdge<E, NodeId = N>,
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iterable: I) -> Self
+    where
+        I: IntoIterator<Item = Item>,
+    {
+        let iter = iterable.into_iter();
+        let (low, _) = iter.size_hint();
+        let mut g = Self::with_capacity(0, low);
+        g.extend(iter);
+        g
+    }
+}
+
+/// Extend the graph from an iterable of edges.
+///
+/// Nodes are inserted automatically to match the edges.
+impl<N, E, Ty, Item, S> Extend<Item> for MultiGraphMap<N, E, Ty, S>
+where
+    Item: IntoWeightedEdge<E, NodeId = N>,
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn extend<I>(&mut self, iterable: I)
+    where
+        I: IntoIterator<Item = Item>,
+    {
+        let iter = iterable.into_iter();
+        let (low, _) = iter.size_hint();
+        self.edges.reserve(low);
+
+        for elt in iter {
+            let (source, target, weight) = elt.into_weighted_edge();
+            self.add_edge(source, target, weight);
+        }
+    }
+}
+
+/// Index `MultiGraphMap` by edge keys to access edge weights.
+impl<N, E, Ty, S> Index<EdgeKey> for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Output = E;
+    fn index(&self, key: EdgeKey) -> &E {
+        self.edge_weight(key)
+            .expect("MultiGraphMap::index: no such edge")
+    }
+}
+
+/// Index `MultiGraphMap` by edge keys to access edge weights.
+impl<N, E, Ty, S> IndexMut<EdgeKey> for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn index_mut(&mut self, key: EdgeKey) -> &mut E {
+        self.edge_weight_mut(key)
+            .expect("MultiGraphMap::index: no such edge")
+    }
+}
+
+/// Create a new empty `MultiGraphMap`.
+impl<N, E, Ty, S> Default for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        MultiGraphMap::with_capacity(0, 0)
+    }
+}
+
+/// A reference to an edge of a [`MultiGraphMap`], with its key and weight.
+#[derive(Debug)]
+pub struct EdgeReference<'a, N, E> {
+    source: N,
+    target: N,
+    key: EdgeKey,
+    weight: &'a E,
+}
+
+impl<N: Copy, E> Clone for EdgeReference<'_, N, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N: Copy, E> Copy for EdgeReference<'_, N, E> {}
+
+impl<N: Copy, E> visit::EdgeRef for EdgeReference<'_, N, E> {
+    type NodeId = N;
+    type EdgeId = EdgeKey;
+    type Weight = E;
+
+    fn source(&self) -> N {
+        self.source
+    }
+    fn target(&self) -> N {
+        self.target
+    }
+    fn weight(&self) -> &E {
+        self.weight
+    }
+    fn id(&self) -> EdgeKey {
+        self.key
+    }
+}
+
+iterator_wrap! {
+    impl (Iterator DoubleEndedIterator ExactSizeIterator) for
+    #[derive(Debug, Clone)]
+    struct Nodes <'a, N> where { N: 'a + NodeTrait }
+    item: N,
+    iter: Copied<Keys<'a, N, Vec<(N, Direction, EdgeKey)>>>,
+}
+
+/// Iterator over the neighbors of a node, once per edge.
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a, N, Ty = Undirected>
+where
+    N: 'a,
+    Ty: EdgeType,
+{
+    iter: Iter<'a, (N, Direction, EdgeKey)>,
+    start_node: N,
+    dir: Direction,
+    ty: PhantomData<Ty>,
+}
+
+impl<N, Ty> Neighbors<'_, N, Ty>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+{
+    /// Return the next neighbor with the key of the edge connecting it, and
+    /// whether it is the target of the edge.
+    fn next_link(&mut self) -> Option<(N, EdgeKey, bool)> {
+        let self_dir = self.dir;
+        let start_node = self.start_node;
+        (&mut self.iter)
+            .filter(|&&(n, dir, _)| !Ty::is_directed() || dir == self_dir || n == start_node)
+            .map(|&(n, dir, key)| (n, key, dir == Outgoing || n == start_node))
+            .next()
+    }
+}
+
+impl<N, Ty> Iterator for Neighbors<'_, N, Ty>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+{
+    type Item = N;
+    fn next(&mut self) -> Option<N> {
+        self.next_link().map(|(n, _, _)| n)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if Ty::is_directed() {
+            (0, upper)
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+/// Iterator over the edges of a node.
+#[derive(Debug, Clone)]
+pub struct Edges<
+    'a,
+    N,
+    E: 'a,
+    Ty,
+    #[cfg(not(feature = "std"))] S,
+    #[cfg(feature = "std")] S = RandomState,
+> where
+    N: 'a + NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    edges: &'a IndexMap<EdgeKey, (N, N, E), S>,
+    iter: Neighbors<'a, N, Ty>,
+}
+
+impl<'a, N, E, Ty, S> Iterator for Edges<'a, N, E, Ty, S>
+where
+    N: 'a + NodeTrait,
+    E: 'a,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Item = EdgeReference<'a, N, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (other, key, is_target) = self.iter.next_link()?;
+        let start_node = self.iter.start_node;
+        // In undirected graphs, the edges point away from `start_node` when
+        // iterating outgoing edges, and towards it otherwise.
+        let outgoing = if Ty::is_directed() {
+            is_target
+        } else {
+            self.iter.dir == Outgoing
+        };
+        let (source, target) = if outgoing {
+            (start_node, other)
+        } else {
+            (other, start_node)
+        };
+        let (_, _, weight) = &self.edges[&key];
+        Some(EdgeReference {
+            source,
+            target,
+            key,
+            weight,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator over the edges connecting two nodes.
+#[derive(Debug, Clone)]
+pub struct EdgesConnecting<
+    'a,
+    N,
+    E: 'a,
+    Ty,
+    #[cfg(not(feature = "std"))] S,
+    #[cfg(feature = "std")] S = RandomState,
+> where
+    N: 'a + NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    target_node: N,
+    edges: Edges<'a, N, E, Ty, S>,
+}
+
+impl<'a, N, E, Ty, S> Iterator for EdgesConnecting<'a, N, E, Ty, S>
+where
+    N: 'a + NodeTrait,
+    E: 'a,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Item = EdgeReference<'a, N, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let target_node = self.target_node;
+        self.edges.find(|edge| edge.target == target_node)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.edges.size_hint().1)
+    }
+}
+
+/// Iterator over all the edges of a graph.
+#[derive(Debug, Clone)]
+pub struct AllEdges<'a, N, E: 'a> {
+    iter: IndexMapIter<'a, EdgeKey, (N, N, E)>,
+}
+
+impl<'a, N, E> Iterator for AllEdges<'a, N, E>
+where
+    N: 'a + NodeTrait,
+    E: 'a,
+{
+    type Item = EdgeReference<'a, N, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(&key, (source, target, weight))| EdgeReference {
+                source: *source,
+                target: *target,
+                key,
+                weight,
+            })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator over the nodes of a graph, with references to their weight.
+#[derive(Debug, Clone)]
+pub struct NodeReferences<'a, N>
+where
+    N: 'a + NodeTrait,
+{
+    iter: IndexMapIter<'a, N, Vec<(N, Direction, EdgeKey)>>,
+}
+
+impl<'a, N> Iterator for NodeReferences<'a, N>
+where
+    N: 'a + NodeTrait,
+{
+    type Item = (N, &'a N);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(n, _)| (*n, n))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<N, E, Ty, S> visit::GraphBase for MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + PartialEq,
+    S: BuildHasher,
+{
+    type NodeId = N;
+    type EdgeId = EdgeKey;
+}
+
+impl<N, E, Ty, S> visit::Data for MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + PartialEq,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, Ty, S> visit::Visitable for MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + Ord + Hash,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Map = HashSet<N>;
+    fn visit_map(&self) -> HashSet<N> {
+        HashSet::with_capacity(self.node_count())
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+    }
+}
+
+impl<N, E, Ty, S> visit::GraphProp for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type EdgeType = Ty;
+}
+
+impl<'a, N, E, Ty, S> visit::IntoNodeReferences for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type NodeRef = (N, &'a N);
+    type NodeReferences = NodeReferences<'a, N>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences {
+            iter: self.nodes.iter(),
+        }
+    }
+}
+
+impl<'a, N, E: 'a, Ty, S> visit::IntoNodeIdentifiers for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type NodeIdentifiers = Nodes<'a, N>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.nodes()
+    }
+}
+
+impl<N, E, Ty, S> visit::NodeCount for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn node_count(&self) -> usize {
+        (*self).node_count()
+    }
+}
+
+impl<N, E, Ty, S> visit::NodeIndexable for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn node_bound(&self) -> usize {
+        self.node_count()
+    }
+    fn to_index(&self, ix: Self::NodeId) -> usize {
+        self.nodes.get_index_of(&ix).expect("node not found")
+    }
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        assert!(
+            ix < self.nodes.len(),
+            "The requested index {ix} is out-of-bounds."
+        );
+        let (&key, _) = self.nodes.get_index(ix).unwrap();
+        key
+    }
+}
+
+impl<N, E, Ty, S> visit::NodeCompactIndexable for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+}
+
+impl<'a, N: 'a, E, Ty, S> visit::IntoNeighbors for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + Ord + Hash,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Neighbors = Neighbors<'a, N, Ty>;
+    fn neighbors(self, n: Self::NodeId) -> Self::Neighbors {
+        self.neighbors(n)
+    }
+}
+
+impl<'a, N: 'a, E, Ty, S> visit::IntoNeighborsDirected for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + Ord + Hash,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type NeighborsDirected = Neighbors<'a, N, Ty>;
+    fn neighbors_directed(self, n: N, dir: Direction) -> Self::NeighborsDirected {
+        self.neighbors_directed(n, dir)
+    }
+}
+
+impl<N, E, Ty, S> visit::EdgeIndexable for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn edge_bound(&self) -> usize {
+        self.edge_count()
+    }
+
+    fn to_index(&self, ix: Self::EdgeId) -> usize {
+        self.edges.get_index_of(&ix).expect("edge not found")
+    }
+
+    fn from_index(&self, ix: usize) -> Self::EdgeId {
+        assert!(
+            ix < self.edges.len(),
+            "The requested index {ix} is out-of-bounds."
+        );
+        let (&key, _) = self.edges.get_index(ix).unwrap();
+        key
+    }
+}
+
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdges for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Edges = Edges<'a, N, E, Ty, S>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        self.edges(a)
+    }
+}
+
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdgesDirected for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type EdgesDirected = Edges<'a, N, E, Ty, S>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        self.edges_directed(a, dir)
+    }
+}
+
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdgeReferences for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type EdgeRef = EdgeReference<'a, N, E>;
+    type EdgeReferences = AllEdges<'a, N, E>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        self.all_edges()
+    }
+}
+
+impl<N, E, Ty, S> visit::EdgeCount for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+/// The `MultiGraphMap` answers adjacency queries from its adjacency lists.
+impl<N, E, Ty, S> visit::GetAdjacencyMatrix for MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + Ord + Hash,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type AdjMatrix = ();
+    #[inline]
+    fn adjacency_matrix(&self) {}
+    #[inline]
+    fn is_adjacent(&self, _: &(), a: N, b: N) -> bool {
+        self.contains_edge(a, b)
+    }
+}
+
+impl<N, E, Ty, S> data::DataMap for MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + Ord + Hash,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn edge_weight(&self, id: Self::EdgeId) -> Option<&Self::EdgeWeight> {
+        self.edge_weight(id)
+    }
+
+    fn node_weight(&self, id: Self::NodeId) -> Option<&Self::NodeWeight> {
+        self.nodes.get_key_value(&id).map(|(k, _)| k)
+    }
+}
@@ -40,25 +40,25 @@
 //!
 //! The following table lists the traits that are implemented for each graph type:
 //!
-//! |                       | Graph | StableGraph | GraphMap | MatrixGraph | Csr   | List  |
-//! | --------------------- | :---: | :---------: | :------: | :---------: | :---: | :---: |
-//! | GraphBase             | x     |  x          |    x     | x           | x     |  x    |
-//! | GraphProp             | x     |  x          |    x     | x           | x     |  x    |
-//! | NodeCount             | x     |  x          |    x     | x           | x     |  x    |
-//! | NodeIndexable         | x     |  x          |    x     | x           | x     |  x    |
-//! | NodeCompactIndexable  | x     |             |    x     |             | x     |  x    |
-//! | EdgeCount             | x     |  x          |    x     | x           | x     |  x    |
-//! | EdgeIndexable         | x     |  x          |    x     |             |       |       |
-//! | Data                  | x     |  x          |    x     | x           | x     |  x    |
-//! | IntoNodeIdentifiers   | x     |  x          |    x     | x           | x     |  x    |
-//! | IntoNodeReferences    | x     |  x          |    x     | x           | x     |  x    |
-//! | IntoEdgeReferences    | x     |  x          |    x     | x           | x     |  x    |
-//! | IntoNeighbors         | x     |  x          |    x     | x           | x     |  x    |
-//! | IntoNeighborsDirected | x     |  x          |    x     | x           |       |       |
-//! | IntoEdges             | x     |  x          |    x     | x           | x     |  x    |
-//! | IntoEdgesDirected     | x     |  x          |    x     | x           |       |       |
-//! | Visitable             | x     |  x          |    x     | x           | x     |  x    |
-//! | GetAdjacencyMatrix    | x     |  x          |    x     | x           | x     |  x    |
+//! |                       | Graph | StableGraph | GraphMap | MultiGraphMap | MatrixGraph | Csr   | List  |
+//! | --------------------- | :---: | :---------: | :------: | :-----------: | :---------: | :---: | :---: |
+//! | GraphBase             | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | GraphProp             | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | NodeCount             | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | NodeIndexable         | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | NodeCompactIndexable  | x     |             |    x     |       x       |             | x     |  x    |
+//! | EdgeCount             | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | EdgeIndexable         | x     |  x          |    x     |       x       |             | x     |       |
+//! | Data                  | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | IntoNodeIdentifiers   | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | IntoNodeReferences    | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | IntoEdgeReferences    | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | IntoNeighbors         | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | IntoNeighborsDirected | x     |  x          |    x     |       x       | x           |       |       |
+//! | IntoEdges             | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | IntoEdgesDirected     | x     |  x          |    x     |       x       | x           |       |       |
+//! | Visitable             | x     |  x          |    x     |       x       | x           | x     |  x    |
+//! | GetAdjacencyMatrix    | x     |  x          |    x     |       x       | x           | x     |  x    |
 
 // filter, reversed have their `mod` lines at the end,
 // so that they can use the trait template macros
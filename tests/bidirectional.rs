@@ -0,0 +1,126 @@
+use petgraph::algo::{astar, bidirectional_astar, bidirectional_dijkstra, dijkstra};
+use petgraph::prelude::*;
+use petgraph::Graph;
+
+#[test]
+fn bidirectional_dijkstra_directed() {
+    let mut g = Graph::new();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    g.add_edge(a, b, 7);
+    g.add_edge(c, a, 9);
+    g.add_edge(a, d, 14);
+    g.add_edge(b, c, 10);
+    g.add_edge(d, c, 2);
+    g.add_edge(d, e, 9);
+    g.add_edge(b, f, 15);
+    g.add_edge(c, f, 11);
+    g.add_edge(e, f, 6);
+
+    assert_eq!(
+        bidirectional_dijkstra(&g, a, f, |e| *e.weight()),
+        Some((22, vec![a, b, f]))
+    );
+    assert_eq!(
+        bidirectional_dijkstra(&g, d, a, |e| *e.weight()),
+        Some((11, vec![d, c, a]))
+    );
+    assert_eq!(
+        bidirectional_dijkstra(&g, e, e, |e| *e.weight()),
+        Some((0, vec![e]))
+    );
+    // f has no outgoing edges.
+    assert_eq!(bidirectional_dijkstra(&g, f, a, |e| *e.weight()), None);
+}
+
+#[test]
+fn bidirectional_dijkstra_undirected() {
+    let mut g = Graph::new_undirected();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    let d = g.add_node(());
+    let z = g.add_node(());
+    g.extend_with_edges([(a, b, 1), (b, c, 1), (c, d, 1), (a, d, 5)]);
+
+    assert_eq!(
+        bidirectional_dijkstra(&g, d, a, |e| *e.weight()),
+        Some((3, vec![d, c, b, a]))
+    );
+    assert_eq!(bidirectional_dijkstra(&g, a, z, |e| *e.weight()), None);
+}
+
+#[test]
+fn bidirectional_astar_grid() {
+    // A 10x10 grid with unit costs in both directions, except for a wall with a gap.
+    let size = 10;
+    let mut g = DiGraph::new();
+    let nodes: Vec<Vec<NodeIndex>> = (0..size)
+        .map(|x| (0..size).map(|y| g.add_node((x, y))).collect())
+        .collect();
+    let is_wall = |x: i32, y: i32| x == 5 && y != 0;
+    for x in 0..size {
+        for y in 0..size {
+            if is_wall(x, y) {
+                continue;
+            }
+            for (nx, ny) in [(x + 1, y), (x, y + 1)] {
+                if nx < size && ny < size && !is_wall(nx, ny) {
+                    let (u, v) = (
+                        nodes[x as usize][y as usize],
+                        nodes[nx as usize][ny as usize],
+                    );
+                    g.add_edge(u, v, 1);
+                    g.add_edge(v, u, 1);
+                }
+            }
+        }
+    }
+
+    let manhattan = |(x1, y1): (i32, i32), (x2, y2): (i32, i32)| (x1 - x2).abs() + (y1 - y2).abs();
+    let start = nodes[0][9];
+    let finish = nodes[9][9];
+    let expected = astar(
+        &g,
+        start,
+        |n| n == finish,
+        |e| *e.weight(),
+        |n| manhattan(g[n], g[finish]),
+    )
+    .unwrap();
+    let result = bidirectional_astar(
+        &g,
+        start,
+        finish,
+        |e| *e.weight(),
+        |n| manhattan(g[n], g[finish]),
+        |n| manhattan(g[start], g[n]),
+    )
+    .unwrap();
+
+    assert_eq!(result.0, 27);
+    assert_eq!(result.0, expected.0);
+    assert_eq!(result.1.first(), Some(&start));
+    assert_eq!(result.1.last(), Some(&finish));
+    assert_eq!(result.1.len(), 28);
+    for pair in result.1.windows(2) {
+        assert!(g.contains_edge(pair[0], pair[1]));
+    }
+
+    let dijkstra_run = dijkstra(&g, start, None, |e| *e.weight());
+    for &end in nodes.iter().flatten() {
+        let path = bidirectional_astar(
+            &g,
+            start,
+            end,
+            |e| *e.weight(),
+            |n| manhattan(g[n], g[end]),
+            |n| manhattan(g[start], g[n]),
+        );
+        assert_eq!(dijkstra_run.get(&end).cloned(), path.map(|t| t.0));
+    }
+}
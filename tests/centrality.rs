@@ -0,0 +1,412 @@
+use petgraph::algo::{
+    betweenness_centrality, closeness_centrality, edge_betweenness_centrality,
+    eigenvector_centrality, harmonic_centrality, hits, katz_centrality,
+    weighted_betweenness_centrality, weighted_edge_betweenness_centrality,
+};
+use petgraph::csr::{BiCsr, Csr};
+use petgraph::prelude::*;
+use petgraph::visit::Reversed;
+use petgraph::Graph;
+
+#[cfg(feature = "rayon")]
+use petgraph::algo::{
+    parallel_betweenness_centrality, parallel_closeness_centrality,
+    parallel_edge_betweenness_centrality, parallel_harmonic_centrality,
+    parallel_weighted_betweenness_centrality, parallel_weighted_edge_betweenness_centrality,
+};
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+    }
+}
+
+fn path_graph(n: usize) -> UnGraph<(), f64> {
+    let mut graph = Graph::new_undirected();
+    let nodes: Vec<_> = (0..n).map(|_| graph.add_node(())).collect();
+    for pair in nodes.windows(2) {
+        graph.add_edge(pair[0], pair[1], 1.);
+    }
+    graph
+}
+
+/// Two triangles joined by a bridge, with a pendant node and a separate component.
+fn graph_example() -> UnGraph<(), f64> {
+    let mut graph = Graph::new_undirected();
+    for _ in 0..9 {
+        graph.add_node(());
+    }
+    graph.extend_with_edges([
+        (0, 1, 1.),
+        (1, 2, 3.),
+        (2, 0, 1.),
+        (2, 3, 1.),
+        (3, 4, 1.),
+        (4, 5, 3.),
+        (5, 3, 1.),
+        (5, 6, 1.),
+        (7, 8, 1.),
+    ]);
+    graph
+}
+
+#[test]
+fn betweenness_path() {
+    let graph = path_graph(5);
+    assert_close(
+        &betweenness_centrality(&graph, false),
+        &[0., 3., 4., 3., 0.],
+    );
+    assert_close(
+        &betweenness_centrality(&graph, true),
+        &[0., 0.5, 2. / 3., 0.5, 0.],
+    );
+}
+
+#[test]
+fn betweenness_directed() {
+    // a -> b -> c -> d, and a -> c
+    let mut graph = Graph::<(), f64>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges([(a, b, 1.), (b, c, 1.), (c, d, 1.), (a, c, 1.)]);
+
+    assert_close(&betweenness_centrality(&graph, false), &[0., 0., 2., 0.]);
+    assert_close(
+        &betweenness_centrality(&graph, true),
+        &[0., 0., 1. / 3., 0.],
+    );
+    // With a costly shortcut, the paths from a go through b.
+    let ac = graph.find_edge(a, c).unwrap();
+    graph[ac] = 3.;
+    assert_close(
+        &weighted_betweenness_centrality(&graph, false, |e| *e.weight()),
+        &[0., 2., 2., 0.],
+    );
+}
+
+#[test]
+fn betweenness_parallel_edges() {
+    // Two parallel edges a - b, then b - c: the path a - c is unique through b.
+    let mut graph = Graph::<(), f64, Undirected>::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let ab1 = graph.add_edge(a, b, 1.);
+    let ab2 = graph.add_edge(a, b, 1.);
+    let bc = graph.add_edge(b, c, 1.);
+
+    assert_close(&betweenness_centrality(&graph, false), &[0., 1., 0.]);
+    let edges = edge_betweenness_centrality(&graph, false);
+    assert_close(&[edges[ab1.index()], edges[ab2.index()]], &[1., 1.]);
+    assert_close(&[edges[bc.index()]], &[2.]);
+}
+
+#[test]
+fn betweenness_weighted_matches_unweighted() {
+    let graph = graph_example();
+    assert_close(
+        &weighted_betweenness_centrality(&graph, false, |_| 1),
+        &betweenness_centrality(&graph, false),
+    );
+    assert_close(
+        &weighted_edge_betweenness_centrality(&graph, true, |_| 1),
+        &edge_betweenness_centrality(&graph, true),
+    );
+}
+
+#[test]
+fn betweenness_weighted() {
+    let graph = graph_example();
+    // The cheapest path between 1 and 2 goes through 0, and between 4 and 5 through 3.
+    assert_close(
+        &weighted_betweenness_centrality(&graph, false, |e| *e.weight()),
+        &[5., 0., 8., 11., 0., 5., 0., 0., 0.],
+    );
+}
+
+#[test]
+fn betweenness_stable_graph_with_holes() {
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let a = graph.add_node(());
+    let removed = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(a, b, ());
+    graph.add_edge(b, c, ());
+    graph.remove_node(removed);
+
+    assert_close(&betweenness_centrality(&graph, false), &[0., 0., 1., 0.]);
+    assert_close(&betweenness_centrality(&graph, true), &[0., 0., 1., 0.]);
+}
+
+#[test]
+fn edge_betweenness_path() {
+    let graph = path_graph(4);
+    assert_close(&edge_betweenness_centrality(&graph, false), &[3., 4., 3.]);
+    assert_close(
+        &edge_betweenness_centrality(&graph, true),
+        &[0.5, 2. / 3., 0.5],
+    );
+}
+
+#[test]
+fn edge_betweenness_undirected_csr() {
+    let graph = graph_example();
+    let (csr, _) = Csr::<(), f64, Undirected>::from_graph(&graph);
+    let bicsr = BiCsr::new(csr.clone());
+    let expected = edge_betweenness_centrality(&graph, false);
+    let expected_weighted = weighted_edge_betweenness_centrality(&graph, false, |e| *e.weight());
+    let actual = edge_betweenness_centrality(&csr, false);
+    let actual_weighted = weighted_edge_betweenness_centrality(&csr, false, |e| *e.weight());
+    assert_close(&edge_betweenness_centrality(&bicsr, false), &actual);
+
+    // Both stored directions of an edge have the same id.
+    let mut ids = vec![];
+    for edge in graph.edge_references() {
+        let (a, b) = (edge.source().index() as u32, edge.target().index() as u32);
+        let id = csr.edges(a).find(|e| e.target() == b).unwrap().id();
+        assert_eq!(csr.edges(b).find(|e| e.target() == a).unwrap().id(), id);
+        for (s, t) in [(a, b), (b, a)] {
+            let mut incoming = bicsr.edges_directed(t, Incoming);
+            assert_eq!(incoming.find(|e| e.source() == s).unwrap().id(), id);
+        }
+        assert_close(&[actual[id]], &[expected[edge.id().index()]]);
+        assert_close(
+            &[actual_weighted[id]],
+            &[expected_weighted[edge.id().index()]],
+        );
+        ids.push(id);
+    }
+    // The ids left unused by the second direction of each edge score zero.
+    for (id, c) in actual.iter().enumerate() {
+        assert!(ids.contains(&id) || *c == 0.);
+    }
+}
+
+#[test]
+fn closeness() {
+    let graph = graph_example();
+    let centrality = closeness_centrality(&graph, false, |_| 1.);
+    assert_close(
+        &centrality,
+        &[
+            6. / 14.,
+            6. / 14.,
+            6. / 10.,
+            6. / 9.,
+            6. / 12.,
+            6. / 11.,
+            6. / 16.,
+            1.,
+            1.,
+        ],
+    );
+
+    let centrality = closeness_centrality(&graph, true, |_| 1.);
+    assert_close(&centrality[..1], &[6. / 14. * 6. / 8.]);
+    assert_close(&centrality[7..], &[1. / 8., 1. / 8.]);
+}
+
+#[test]
+fn closeness_directed() {
+    // a -> b -> c
+    let mut graph = Graph::<(), f64>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges([(a, b, 2.), (b, c, 1.)]);
+
+    assert_close(
+        &closeness_centrality(&graph, false, |e| *e.weight()),
+        &[2. / 5., 1., 0.],
+    );
+    assert_close(
+        &closeness_centrality(Reversed(&graph), false, |e| *e.weight()),
+        &[0., 1. / 2., 2. / 4.],
+    );
+}
+
+#[test]
+fn harmonic() {
+    let graph = path_graph(4);
+    assert_close(
+        &harmonic_centrality(&graph, |_| 1.),
+        &[
+            1. + 1. / 2. + 1. / 3.,
+            2. + 1. / 2.,
+            2. + 1. / 2.,
+            1. + 1. / 2. + 1. / 3.,
+        ],
+    );
+
+    // Nodes at distance zero are ignored.
+    let graph = graph_example();
+    let centrality = harmonic_centrality(&graph, |_| 0.);
+    assert_close(&centrality, &[0.; 9]);
+}
+
+#[test]
+fn empty_graph() {
+    let graph = UnGraph::<(), f64>::new_undirected();
+    assert!(betweenness_centrality(&graph, true).is_empty());
+    assert!(edge_betweenness_centrality(&graph, true).is_empty());
+    assert!(closeness_centrality(&graph, true, |_| 1.).is_empty());
+    assert!(harmonic_centrality(&graph, |_| 1.).is_empty());
+
+    let mut graph = UnGraph::<(), f64>::new_undirected();
+    graph.add_node(());
+    assert_close(&betweenness_centrality(&graph, true), &[0.]);
+    assert_close(&closeness_centrality(&graph, true, |_| 1.), &[0.]);
+}
+
+#[test]
+fn eigenvector_star() {
+    // The center of a star with k leaves has √k times the score of a leaf.
+    let mut graph = UnGraph::<(), f64>::new_undirected();
+    let center = graph.add_node(());
+    for _ in 0..4 {
+        let leaf = graph.add_node(());
+        graph.add_edge(center, leaf, 1.);
+    }
+    let (centrality, converged) = eigenvector_centrality(&graph, |_| 1., 100, Some(1e-12));
+    assert!(converged);
+    assert_close(&centrality, &[2. / 6., 1. / 6., 1. / 6., 1. / 6., 1. / 6.]);
+
+    let (_, converged) = eigenvector_centrality(&graph, |_| 1., 2, Some(1e-12));
+    assert!(!converged);
+}
+
+#[test]
+fn eigenvector_directed_weighted() {
+    // a <-> b, with a weight of 4 from a to b, and c -> a.
+    let mut graph = Graph::<(), f64>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges([(a, b, 4.), (b, a, 1.), (c, a, 1.)]);
+
+    // The scores are based on incoming edges: c gets nothing, and b twice the score of a.
+    let (centrality, converged) = eigenvector_centrality(&graph, |e| *e.weight(), 1000, None);
+    assert!(converged);
+    assert!((centrality[0] - 1. / 3.).abs() < 1e-4);
+    assert!((centrality[1] - 2. / 3.).abs() < 1e-4);
+    assert!(centrality[2] < 1e-4);
+}
+
+#[test]
+fn eigenvector_stable_graph_with_holes() {
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let a = graph.add_node(());
+    let removed = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge(a, b, ());
+    graph.remove_node(removed);
+
+    let (centrality, converged) = eigenvector_centrality(&graph, |_| 1_f32, 100, None);
+    assert!(converged);
+    assert_eq!(centrality, vec![0.5, 0., 0.5]);
+
+    let empty = UnGraph::<(), ()>::new_undirected();
+    assert_eq!(
+        eigenvector_centrality(&empty, |_| 1_f64, 100, None),
+        (vec![], true)
+    );
+}
+
+#[test]
+fn katz() {
+    // a <- b <- c, d -> a
+    let mut graph = Graph::<(), f64>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges([(b, a, 1.), (c, b, 1.), (d, a, 2.)]);
+
+    let (centrality, converged) = katz_centrality(&graph, 0.1, 1., |e| *e.weight(), 100, None);
+    assert!(converged);
+    assert_close(&centrality, &[1. + 0.1 * 1.1 + 0.2, 1.1, 1., 1.]);
+
+    // In a cycle, alpha must be smaller than one for the walks to be attenuated.
+    graph.add_edge(a, c, 1.);
+    graph.remove_edge(graph.find_edge(d, a).unwrap());
+    let (_, converged) = katz_centrality(&graph, 0.5, 1., |_| 1., 1000, None);
+    assert!(converged);
+    let (_, converged) = katz_centrality(&graph, 1.5, 1., |_| 1., 1000, None);
+    assert!(!converged);
+}
+
+#[test]
+fn hits_scores() {
+    // a -> c, b -> c, b -> d, and an isolated node.
+    let mut graph = Graph::<(), f64>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.add_node(());
+    graph.extend_with_edges([(a, c, 1.), (b, c, 1.), (b, d, 1.)]);
+
+    let (hubs, authorities, converged) = hits(&graph, |_| 1., 1000, Some(1e-12));
+    assert!(converged);
+    // The scores of the principal eigenvectors are in the golden ratio.
+    let phi = (1. + 5_f64.sqrt()) / 2.;
+    assert_close(&hubs, &[1. / phi / phi, 1. / phi, 0., 0., 0.]);
+    assert_close(&authorities, &[0., 0., 1. / phi, 1. / phi / phi, 0.]);
+
+    let graph = Graph::<(), f64>::new();
+    assert_eq!(hits(&graph, |_| 1., 10, None), (vec![], vec![], true));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_centralities() {
+    let mut graph = graph_example().into_edge_type::<Directed>();
+    graph.add_edge(NodeIndex::new(6), NodeIndex::new(0), 2.);
+
+    assert_close(
+        &parallel_betweenness_centrality(&graph, true),
+        &betweenness_centrality(&graph, true),
+    );
+    assert_close(
+        &parallel_weighted_betweenness_centrality(&graph, false, |e| *e.weight()),
+        &weighted_betweenness_centrality(&graph, false, |e| *e.weight()),
+    );
+    assert_close(
+        &parallel_edge_betweenness_centrality(&graph, false),
+        &edge_betweenness_centrality(&graph, false),
+    );
+    assert_close(
+        &parallel_weighted_edge_betweenness_centrality(&graph, true, |e| *e.weight()),
+        &weighted_edge_betweenness_centrality(&graph, true, |e| *e.weight()),
+    );
+    assert_close(
+        &parallel_closeness_centrality(&graph, true, |e| *e.weight()),
+        &closeness_centrality(&graph, true, |e| *e.weight()),
+    );
+    assert_close(
+        &parallel_harmonic_centrality(&graph, |e| *e.weight()),
+        &harmonic_centrality(&graph, |e| *e.weight()),
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_stable_graph_with_holes() {
+    let mut graph = StableUnGraph::<(), f64>::default();
+    let a = graph.add_node(());
+    let removed = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge(a, b, 1.);
+    graph.remove_node(removed);
+
+    assert_close(
+        &parallel_closeness_centrality(&graph, false, |_| 1.),
+        &[1., 0., 1.],
+    );
+    assert_close(&parallel_harmonic_centrality(&graph, |_| 1.), &[1., 0., 1.]);
+}
@@ -1,4 +1,10 @@
-use petgraph::algo::dsatur_coloring;
+use hashbrown::{HashMap, HashSet};
+
+use petgraph::algo::{
+    dsatur_coloring, exact_coloring, greedy_coloring, misra_gries_edge_coloring, ColoringStrategy,
+};
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::EdgeRef;
 use petgraph::{Graph, Undirected};
 
 #[test]
@@ -57,3 +63,132 @@ fn dsatur_coloring_bipartite() {
     let (_, nb_colors) = dsatur_coloring(&graph);
     assert_eq!(nb_colors, 2);
 }
+
+/// The Petersen graph, with chromatic number 3 and chromatic index 4.
+fn petersen() -> Graph<(), (), Undirected> {
+    let mut edges = Vec::new();
+    for i in 0..5 {
+        edges.push((i, (i + 1) % 5));
+        edges.push((i, i + 5));
+        edges.push((i + 5, (i + 2) % 5 + 5));
+    }
+    Graph::from_edges(edges)
+}
+
+/// The Grötzsch graph, which has no triangle but needs 4 colors.
+fn grotzsch() -> Graph<(), (), Undirected> {
+    let mut edges = Vec::new();
+    for i in 0..5 {
+        edges.push((i, (i + 1) % 5));
+        edges.push((i + 5, (i + 1) % 5));
+        edges.push((i + 5, (i + 4) % 5));
+        edges.push((10, i + 5));
+    }
+    Graph::from_edges(edges)
+}
+
+fn complete(n: u32) -> Graph<(), (), Undirected> {
+    let mut graph = Graph::new_undirected();
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    graph.extend_with_edges((0..n).flat_map(|i| (i + 1..n).map(move |j| (i, j))));
+    graph
+}
+
+fn is_proper_coloring(
+    graph: &Graph<(), (), Undirected>,
+    coloring: &HashMap<NodeIndex, usize>,
+) -> bool {
+    coloring.len() == graph.node_count()
+        && graph
+            .edge_references()
+            .all(|e| e.source() == e.target() || coloring[&e.source()] != coloring[&e.target()])
+}
+
+fn is_proper_edge_coloring(
+    graph: &Graph<(), (), Undirected>,
+    coloring: &HashMap<EdgeIndex, usize>,
+) -> bool {
+    graph.node_indices().all(|node| {
+        let colors: HashSet<_> = graph.edges(node).map(|e| coloring[&e.id()]).collect();
+        colors.len() == graph.edges(node).count()
+    })
+}
+
+#[test]
+fn greedy_coloring_strategies() {
+    let strategies = [
+        ColoringStrategy::LargestFirst,
+        ColoringStrategy::SmallestLast,
+        ColoringStrategy::RandomSequential(0),
+        ColoringStrategy::RandomSequential(1),
+    ];
+    for graph in [petersen(), grotzsch(), complete(6)] {
+        for strategy in strategies {
+            let (coloring, nb_colors) = greedy_coloring(&graph, strategy);
+            assert!(is_proper_coloring(&graph, &coloring));
+            assert_eq!(nb_colors, coloring.values().max().unwrap() + 1);
+        }
+    }
+
+    // A tree has degeneracy 1, so the smallest-last order uses 2 colors.
+    let tree: Graph<(), (), Undirected> =
+        Graph::from_edges([(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (5, 6), (5, 7)]);
+    let (_, nb_colors) = greedy_coloring(&tree, ColoringStrategy::SmallestLast);
+    assert_eq!(nb_colors, 2);
+
+    // The same seed gives the same coloring.
+    let graph = grotzsch();
+    assert_eq!(
+        greedy_coloring(&graph, ColoringStrategy::RandomSequential(7)),
+        greedy_coloring(&graph, ColoringStrategy::RandomSequential(7))
+    );
+}
+
+#[test]
+fn exact_coloring_chromatic_numbers() {
+    for (graph, chromatic_number) in [
+        (petersen(), 3),
+        (grotzsch(), 4),
+        (complete(5), 5),
+        (complete(1), 1),
+        (Graph::new_undirected(), 0),
+    ] {
+        let (coloring, nb_colors) = exact_coloring(&graph);
+        assert!(is_proper_coloring(&graph, &coloring));
+        assert_eq!(nb_colors, chromatic_number);
+    }
+
+    // Loops and isolated nodes are ignored.
+    let mut graph = petersen();
+    let a = graph.add_node(());
+    graph.add_edge(a, a, ());
+    let (coloring, nb_colors) = exact_coloring(&graph);
+    assert_eq!((coloring.len(), nb_colors), (11, 3));
+}
+
+#[test]
+fn misra_gries_edge_coloring_bounds() {
+    let star: Graph<(), (), Undirected> = Graph::from_edges((1..8).map(|leaf| (0, leaf)));
+    // The chromatic index is the maximum degree, or one more (Vizing's theorem).
+    for (graph, max_degree, chromatic_index) in [
+        (petersen(), 3, 4),
+        (grotzsch(), 5, 5),
+        (complete(5), 4, 5),
+        (complete(6), 5, 5),
+        (star, 7, 7),
+    ] {
+        let (coloring, nb_colors) = misra_gries_edge_coloring(&graph);
+        assert_eq!(coloring.len(), graph.edge_count());
+        assert!(is_proper_edge_coloring(&graph, &coloring));
+        assert!(chromatic_index <= nb_colors && nb_colors <= max_degree + 1);
+    }
+
+    let mut graph = complete(3);
+    let loop_edge = graph.add_edge(NodeIndex::new(0), NodeIndex::new(0), ());
+    let (coloring, nb_colors) = misra_gries_edge_coloring(&graph);
+    assert!(!coloring.contains_key(&loop_edge));
+    assert_eq!((coloring.len(), nb_colors), (3, 3));
+    assert_eq!(misra_gries_edge_coloring(&Graph::<(), ()>::new()).1, 0);
+}
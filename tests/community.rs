@@ -0,0 +1,125 @@
+use petgraph::algo::{label_propagation, leiden, louvain, modularity};
+use petgraph::graph::EdgeReference;
+use petgraph::prelude::*;
+
+/// `count` cliques of `size` nodes, each joined to the next one by a single edge.
+fn ring_of_cliques(count: usize, size: usize) -> UnGraph<(), f64> {
+    let mut graph = UnGraph::new_undirected();
+    let nodes: Vec<_> = (0..count * size).map(|_| graph.add_node(())).collect();
+    for clique in nodes.chunks(size) {
+        for (i, &a) in clique.iter().enumerate() {
+            for &b in &clique[i + 1..] {
+                graph.add_edge(a, b, 1.);
+            }
+        }
+    }
+    for c in 0..count {
+        let next = (c + 1) % count;
+        graph.add_edge(nodes[c * size], nodes[next * size + 1], 1.);
+    }
+    graph
+}
+
+fn clique_labels(count: usize, size: usize) -> Vec<usize> {
+    (0..count * size).map(|v| v / size).collect()
+}
+
+#[test]
+fn ring_of_cliques_communities() {
+    let graph = ring_of_cliques(6, 5);
+    let expected = clique_labels(6, 5);
+    for seed in 0..10 {
+        assert_eq!(louvain(&graph, |e| *e.weight(), 1., seed), expected);
+        assert_eq!(leiden(&graph, |e| *e.weight(), 1., seed), expected);
+        // Label propagation can merge neighboring cliques, but never splits one.
+        let labels = label_propagation(&graph, |e| *e.weight(), seed);
+        assert!((0..30).all(|v| labels[v] == labels[v - v % 5]));
+    }
+}
+
+#[test]
+fn resolution() {
+    let graph = ring_of_cliques(4, 4);
+    // A null resolution favours a single community per connected component.
+    assert_eq!(louvain(&graph, |_| 1., 0., 1), vec![0; 16]);
+    assert_eq!(leiden(&graph, |_| 1., 0., 1), vec![0; 16]);
+    // A huge resolution makes every node its own community.
+    let singletons: Vec<usize> = (0..16).collect();
+    assert_eq!(louvain(&graph, |_| 1., 100., 1), singletons);
+    assert_eq!(leiden(&graph, |_| 1., 100., 1), singletons);
+}
+
+#[test]
+fn edge_weights() {
+    // A square a - b - c - d - a, where the weights decide which opposite edges are inside
+    // communities.
+    let mut graph = UnGraph::<(), f64>::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges([(a, b, 10.), (b, c, 1.), (c, d, 10.), (d, a, 1.)]);
+
+    let heavy = |e: EdgeReference<f64>| *e.weight();
+    let light = |e: EdgeReference<f64>| 11. - *e.weight();
+    for seed in 0..5 {
+        assert_eq!(louvain(&graph, heavy, 1., seed), vec![0, 0, 1, 1]);
+        assert_eq!(louvain(&graph, light, 1., seed), vec![0, 1, 1, 0]);
+        assert_eq!(leiden(&graph, heavy, 1., seed), vec![0, 0, 1, 1]);
+        assert_eq!(leiden(&graph, light, 1., seed), vec![0, 1, 1, 0]);
+        assert_eq!(label_propagation(&graph, heavy, seed), vec![0, 0, 1, 1]);
+        assert_eq!(label_propagation(&graph, light, seed), vec![0, 1, 1, 0]);
+    }
+}
+
+#[test]
+fn directed_and_stable_graphs() {
+    let mut graph = StableDiGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..7).map(|_| graph.add_node(())).collect();
+    // Two directed triangles joined by an edge, and a removed node in between.
+    graph.extend_with_edges([(0, 1), (1, 2), (2, 0), (4, 5), (5, 6), (6, 4), (2, 4)]);
+    graph.remove_node(nodes[3]);
+
+    let expected = vec![0, 0, 0, usize::MAX, 1, 1, 1];
+    assert_eq!(louvain(&graph, |_| 1., 1., 3), expected);
+    assert_eq!(leiden(&graph, |_| 1., 1., 3), expected);
+    assert_eq!(label_propagation(&graph, |_| 1., 3), expected);
+    assert!((modularity(&graph, &expected, |_| 1., 1.) - 5. / 14.).abs() < 1e-9);
+}
+
+#[test]
+fn isolated_nodes_and_empty_graph() {
+    let mut graph = UnGraph::<(), ()>::new_undirected();
+    assert!(louvain(&graph, |_| 1., 1., 0).is_empty());
+    assert!(leiden(&graph, |_| 1., 1., 0).is_empty());
+    assert!(label_propagation(&graph, |_| 1., 0).is_empty());
+    assert_eq!(modularity(&graph, &[], |_| 1., 1.), 0.);
+
+    for _ in 0..3 {
+        graph.add_node(());
+    }
+    assert_eq!(louvain(&graph, |_| 1., 1., 0), vec![0, 1, 2]);
+    assert_eq!(leiden(&graph, |_| 1., 1., 0), vec![0, 1, 2]);
+    assert_eq!(label_propagation(&graph, |_| 1., 0), vec![0, 1, 2]);
+    assert_eq!(modularity(&graph, &[0, 1, 2], |_| 1., 1.), 0.);
+}
+
+#[test]
+fn modularity_values() {
+    // a - b, with a self-loop on a.
+    let mut graph = UnGraph::<(), f64>::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge(a, a, 1.);
+    graph.add_edge(a, b, 1.);
+
+    assert_eq!(modularity(&graph, &[7, 7], |e| *e.weight(), 1.), 0.);
+    assert_eq!(modularity(&graph, &[3, 1], |e| *e.weight(), 1.), -0.125);
+    assert_eq!(modularity(&graph, &[3, 1], |e| *e.weight(), 0.), 0.5);
+
+    let graph = ring_of_cliques(3, 3);
+    // 3 internal edges per clique and 3 edges between cliques, with degrees 8 per clique.
+    let expected = 9. / 12. - 3. * (8. / 24_f64).powi(2);
+    let score = modularity(&graph, &clique_labels(3, 3), |_| 1., 1.);
+    assert!((score - expected).abs() < 1e-12);
+}
@@ -0,0 +1,165 @@
+#![cfg(all(
+    feature = "graphmap",
+    feature = "stable_graph",
+    feature = "matrix_graph"
+))]
+
+use petgraph::adj::List;
+use petgraph::csr::Csr;
+use petgraph::data::convert;
+use petgraph::graph::node_index;
+use petgraph::matrix_graph::{DiMatrix, UnMatrix};
+use petgraph::multigraphmap::DiMultiGraphMap;
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNeighbors};
+
+/// Return the edges of a graph with its node and edge weights, sorted.
+fn weighted_edges<G>(graph: G) -> Vec<(char, char, u32)>
+where
+    G: IntoEdgeReferences + petgraph::data::DataMap<NodeWeight = char, EdgeWeight = u32>,
+{
+    let mut edges: Vec<_> = graph
+        .edge_references()
+        .map(|e| {
+            (
+                *graph.node_weight(e.source()).unwrap(),
+                *graph.node_weight(e.target()).unwrap(),
+                *e.weight(),
+            )
+        })
+        .collect();
+    edges.sort();
+    edges
+}
+
+fn sample() -> DiGraph<char, u32> {
+    let mut graph = DiGraph::new();
+    let a = graph.add_node('a');
+    let b = graph.add_node('b');
+    let c = graph.add_node('c');
+    let d = graph.add_node('d');
+    graph.extend_with_edges([(a, b, 1), (b, c, 2), (c, a, 3), (c, c, 4), (a, d, 5)]);
+    graph
+}
+
+#[test]
+fn convert_round_trips() {
+    let graph = sample();
+    let expected = weighted_edges(&graph);
+
+    let (stable, node_map): (StableDiGraph<_, _>, _) = convert(&graph);
+    assert_eq!(weighted_edges(&stable), expected);
+    for node in graph.node_indices() {
+        assert_eq!(node_map[node.index()], Some(node));
+    }
+
+    let (map, node_map): (DiGraphMap<_, _>, _) = convert(&stable);
+    assert_eq!(node_map, [Some('a'), Some('b'), Some('c'), Some('d')]);
+    let (multi, _): (DiMultiGraphMap<_, _>, _) = convert(&map);
+    let (matrix, _): (DiMatrix<_, _>, _) = convert(&multi);
+    let (csr, node_map): (Csr<_, _>, _) = convert(&matrix);
+    assert_eq!(node_map, [Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(csr[2], 'c');
+    assert_eq!(csr.neighbors_slice(2), &[0, 2]);
+
+    let (back, _): (DiGraph<_, _>, _) = convert(&csr);
+    assert_eq!(back.node_weights().collect::<String>(), "abcd");
+    let mut edges: Vec<_> = back
+        .edge_references()
+        .map(|e| (back[e.source()], back[e.target()], *e.weight()))
+        .collect();
+    edges.sort();
+    assert_eq!(edges, expected);
+}
+
+#[test]
+fn convert_node_map() {
+    let mut graph = sample();
+    graph.add_node('b');
+    graph.add_edge(node_index(4), node_index(3), 6);
+    graph.add_edge(node_index(0), node_index(1), 7);
+    let mut stable = StableDiGraph::from(graph);
+    stable.remove_node(node_index(2));
+
+    // Parallel edges are kept, and so are the indices of the nodes.
+    let (copy, node_map): (StableDiGraph<_, _>, _) = convert(&stable);
+    assert_eq!(copy.edge_count(), 4);
+    assert_eq!(node_map[4], Some(node_index(3)));
+
+    // Nodes with equal weights are merged, and only the first parallel edge is kept.
+    let (map, node_map): (DiGraphMap<_, _>, _) = convert(&stable);
+    assert_eq!(node_map, [Some('a'), Some('b'), None, Some('d'), Some('b')]);
+    assert_eq!(map.node_count(), 3);
+    assert_eq!(map.edge_weight('a', 'b'), Some(&1));
+    assert_eq!(map.edge_weight('b', 'd'), Some(&6));
+
+    let unweighted = stable.map(|_, _| (), |_, &w| w);
+    let (list, node_map): (List<u32>, _) = convert(&unweighted);
+    assert_eq!(node_map, [Some(0), Some(1), None, Some(2), Some(3)]);
+    assert_eq!(list.edge_count(), 4);
+    assert_eq!((&list).neighbors(0).collect::<Vec<_>>(), [1, 2, 1]);
+}
+
+#[test]
+fn convert_undirected() {
+    let mut graph = UnGraph::<char, u32>::new_undirected();
+    let a = graph.add_node('a');
+    let b = graph.add_node('b');
+    let c = graph.add_node('c');
+    graph.extend_with_edges([(b, a, 1), (b, c, 2), (c, c, 3)]);
+
+    let (csr, _): (Csr<_, _, Undirected>, _) = convert(&graph);
+    assert_eq!(csr.edge_count(), 3);
+    assert_eq!(csr.neighbors_slice(1), &[0, 2]);
+    assert_eq!(csr.edge_references().count(), 5);
+    let (back, _): (UnGraph<_, _>, _) = convert(&csr);
+    assert_eq!(
+        weighted_edges(&back),
+        [('a', 'b', 1), ('b', 'c', 2), ('c', 'c', 3)]
+    );
+    let (copy, _) = Csr::<_, _, Undirected>::from_graph(&csr);
+    assert_eq!(copy.edge_count(), 3);
+    assert_eq!(copy.edges_slice(1), csr.edges_slice(1));
+
+    let (matrix, _): (UnMatrix<_, _>, _) = convert(&csr);
+    assert_eq!(matrix.edge_count(), 3);
+    let (map, _): (UnGraphMap<_, _>, _) = convert(&matrix);
+    assert_eq!(map.edge_weight('a', 'b'), Some(&1));
+
+    let (back, _): (UnGraph<_, _>, _) = convert(&map);
+    assert_eq!(back.edge_count(), 3);
+    assert_eq!(weighted_edges(&back).len(), 3);
+}
+
+#[test]
+fn csr_from_graph() {
+    let mut graph = sample();
+    graph.add_edge(node_index(0), node_index(1), 6);
+    let mut stable = StableDiGraph::from(graph);
+    stable.remove_node(node_index(3));
+
+    // Same result as adding the edges one by one.
+    let (csr, node_map) = Csr::<_, _>::from_graph(&stable);
+    let (expected, expected_map): (Csr<_, _>, _) = convert(&stable);
+    assert_eq!(node_map, expected_map);
+    assert_eq!(node_map, [Some(0), Some(1), Some(2)]);
+    assert_eq!(csr.node_count(), 3);
+    assert_eq!(csr.edge_count(), 4);
+    for a in 0..3 {
+        assert_eq!(csr[a], expected[a]);
+        assert_eq!(csr.neighbors_slice(a), expected.neighbors_slice(a));
+        assert_eq!(csr.edges_slice(a), expected.edges_slice(a));
+    }
+    // Only the first of the parallel edges is kept.
+    assert_eq!(csr.edges_slice(0), &[1]);
+
+    let mut undirected = UnGraph::<char, u32>::new_undirected();
+    let a = undirected.add_node('a');
+    let b = undirected.add_node('b');
+    undirected.extend_with_edges([(b, a, 1), (b, b, 2), (a, b, 3)]);
+    let (csr, _) = Csr::<_, _, Undirected>::from_graph(&undirected);
+    assert_eq!(csr.edge_count(), 2);
+    assert_eq!(csr.neighbors_slice(0), &[1]);
+    assert_eq!(csr.neighbors_slice(1), &[0, 1]);
+    assert_eq!(csr.edges_slice(1), &[1, 2]);
+}
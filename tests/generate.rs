@@ -0,0 +1,270 @@
+#![cfg(feature = "generate")]
+
+use hashbrown::HashSet;
+
+use petgraph::algo::connected_components;
+use petgraph::generate::{
+    barabasi_albert_graph, complete_bipartite_graph, complete_graph, cycle_graph, gnm_random_graph,
+    gnp_random_graph, grid_graph, hypercube_graph, path_graph, petersen_graph,
+    random_regular_graph, star_graph, stochastic_block_model, watts_strogatz_graph,
+};
+use petgraph::graphmap::{DiGraphMap, UnGraphMap};
+use petgraph::matrix_graph::{DiMatrix, UnMatrix};
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+/// Check that a graph has no self-loops and no parallel edges.
+fn is_simple<G>(graph: G, directed: bool) -> bool
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut seen = HashSet::new();
+    graph.edge_references().all(|edge| {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        let key = if directed || a < b { (a, b) } else { (b, a) };
+        a != b && seen.insert(key)
+    })
+}
+
+fn edges<E, Ty: petgraph::EdgeType>(graph: &Graph<(), E, Ty>) -> Vec<(usize, usize)> {
+    graph
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index()))
+        .collect()
+}
+
+fn degrees<E>(graph: &UnGraph<(), E>) -> Vec<usize> {
+    graph
+        .node_indices()
+        .map(|node| graph.neighbors(node).count())
+        .collect()
+}
+
+#[test]
+fn gnp() {
+    let empty: UnGraph<(), ()> = gnp_random_graph(20, 0., 1, |_| (), |_, _| ());
+    assert_eq!((empty.node_count(), empty.edge_count()), (20, 0));
+    let complete: DiGraph<(), ()> = gnp_random_graph(20, 1., 1, |_| (), |_, _| ());
+    assert_eq!(complete.edge_count(), 20 * 19);
+    assert!(is_simple(&complete, true));
+
+    let graph: UnGraph<(), ()> = gnp_random_graph(100, 0.1, 1, |_| (), |_, _| ());
+    assert!(is_simple(&graph, false));
+    // The number of edges is close to its expected value of 495.
+    assert!((400..600).contains(&graph.edge_count()));
+
+    let same: UnGraph<(), ()> = gnp_random_graph(100, 0.1, 1, |_| (), |_, _| ());
+    let other: UnGraph<(), ()> = gnp_random_graph(100, 0.1, 2, |_| (), |_, _| ());
+    assert_eq!(edges(&graph), edges(&same));
+    assert_ne!(edges(&graph), edges(&other));
+}
+
+#[test]
+fn gnm() {
+    for m in [0, 10, 100, 150, 189, 190] {
+        let graph: UnGraph<(), ()> = gnm_random_graph(20, m, 3, |_| (), |_, _| ());
+        assert_eq!((graph.node_count(), graph.edge_count()), (20, m));
+        assert!(is_simple(&graph, false));
+    }
+    for m in [0, 100, 379, 380] {
+        let graph: DiGraph<(), ()> = gnm_random_graph(20, m, 3, |_| (), |_, _| ());
+        assert_eq!(graph.edge_count(), m);
+        assert!(is_simple(&graph, true));
+    }
+    let graph: UnGraph<(), ()> = gnm_random_graph(1, 0, 3, |_| (), |_, _| ());
+    assert_eq!(graph.node_count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn gnm_too_many_edges() {
+    let _: UnGraph<(), ()> = gnm_random_graph(20, 191, 3, |_| (), |_, _| ());
+}
+
+#[test]
+fn barabasi_albert() {
+    let graph: UnGraph<(), ()> = barabasi_albert_graph(200, 4, 5, |_| (), |_, _| ());
+    assert_eq!(graph.edge_count(), 4 + 195 * 4);
+    assert!(is_simple(&graph, false));
+    assert!(degrees(&graph).iter().all(|&degree| degree >= 4));
+    assert_eq!(connected_components(&graph), 1);
+    // Preferential attachment produces hubs.
+    assert!(degrees(&graph).iter().any(|&degree| degree > 20));
+
+    let graph: DiGraph<(), ()> = barabasi_albert_graph(50, 2, 5, |_| (), |_, _| ());
+    assert!(graph
+        .edge_references()
+        .skip(2)
+        .all(|e| e.source().index() > e.target().index()));
+}
+
+#[test]
+fn watts_strogatz() {
+    let ring: UnGraph<(), ()> = watts_strogatz_graph(30, 6, 0., 9, |_| (), |_, _| ());
+    assert_eq!(ring.edge_count(), 90);
+    assert!(degrees(&ring).iter().all(|&degree| degree == 6));
+    assert!(ring.contains_edge(NodeIndex::new(0), NodeIndex::new(27)));
+
+    for p in [0.2, 1.] {
+        let graph: UnGraph<(), ()> = watts_strogatz_graph(30, 6, p, 9, |_| (), |_, _| ());
+        assert_eq!(graph.edge_count(), 90);
+        assert!(is_simple(&graph, false));
+        assert_ne!(edges(&graph), edges(&ring));
+    }
+
+    // Odd numbers of neighbors are rounded down.
+    let graph: UnGraph<(), ()> = watts_strogatz_graph(10, 3, 0.5, 9, |_| (), |_, _| ());
+    assert_eq!(graph.edge_count(), 10);
+}
+
+#[test]
+fn random_regular() {
+    for (d, n) in [(0, 5), (1, 10), (3, 20), (4, 9), (8, 9), (5, 100)] {
+        let graph: UnGraph<(), ()> = random_regular_graph(d, n, 11, |_| (), |_, _| ());
+        assert_eq!(graph.edge_count(), n * d / 2);
+        assert!(is_simple(&graph, false));
+        assert!(degrees(&graph).iter().all(|&degree| degree == d));
+    }
+}
+
+#[test]
+#[should_panic]
+fn random_regular_odd_endpoints() {
+    let _: UnGraph<(), ()> = random_regular_graph(3, 9, 11, |_| (), |_, _| ());
+}
+
+#[test]
+fn stochastic_block_model_blocks() {
+    let probabilities = vec![vec![1., 0., 0.], vec![0., 1., 1.], vec![0., 1., 0.]];
+    let graph: UnGraph<(), ()> =
+        stochastic_block_model(&[3, 4, 2], &probabilities, 13, |_| (), |_, _| ());
+    assert_eq!(graph.node_count(), 9);
+    // A triangle, then a 4-clique fully connected to an independent pair.
+    assert_eq!(graph.edge_count(), 3 + 6 + 8);
+    assert_eq!(connected_components(&graph), 2);
+
+    let probabilities = [[0., 1.], [0., 0.]];
+    let graph: DiGraph<(), ()> =
+        stochastic_block_model(&[2, 3], &probabilities, 13, |_| (), |_, _| ());
+    assert_eq!(graph.edge_count(), 6);
+    assert!(graph
+        .edge_references()
+        .all(|e| e.source().index() < 2 && e.target().index() >= 2));
+}
+
+#[test]
+#[should_panic]
+fn stochastic_block_model_asymmetric() {
+    let probabilities = [[0., 1.], [0., 0.]];
+    let _: UnGraph<(), ()> = stochastic_block_model(&[2, 3], &probabilities, 13, |_| (), |_, _| ());
+}
+
+#[test]
+fn weights_and_graph_types() {
+    let graph: UnGraphMap<u32, usize> =
+        gnm_random_graph(10, 20, 17, |i| i as u32 * 10, |i, j| i + j);
+    assert_eq!((graph.node_count(), graph.edge_count()), (10, 20));
+    assert!(graph
+        .all_edges()
+        .all(|(a, b, &weight)| weight == (a + b) as usize / 10));
+
+    let graph: StableDiGraph<usize, ()> = barabasi_albert_graph(10, 2, 17, |i| i, |_, _| ());
+    assert!(graph.node_indices().all(|node| graph[node] == node.index()));
+}
+
+#[test]
+fn classic_families() {
+    let complete: UnGraph<(), ()> = complete_graph(6, |_| (), |_, _| ());
+    assert_eq!(complete.edge_count(), 15);
+    assert!(is_simple(&complete, false));
+    assert!(degrees(&complete).iter().all(|&degree| degree == 5));
+
+    let path: UnGraph<(), ()> = path_graph(5, |_| (), |_, _| ());
+    assert_eq!(edges(&path), vec![(0, 1), (1, 2), (2, 3), (3, 4)]);
+
+    let cycle: UnGraph<(), ()> = cycle_graph(5, |_| (), |_, _| ());
+    assert_eq!(cycle.edge_count(), 5);
+    assert!(degrees(&cycle).iter().all(|&degree| degree == 2));
+
+    let star: UnGraph<(), ()> = star_graph(4, |_| (), |_, _| ());
+    assert_eq!(degrees(&star), vec![4, 1, 1, 1, 1]);
+
+    let grid: UnGraph<(), ()> = grid_graph(3, 4, |_| (), |_, _| ());
+    let mut grid_degrees = degrees(&grid);
+    grid_degrees.sort_unstable();
+    assert_eq!(grid_degrees, [2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 4, 4]);
+    assert!(is_simple(&grid, false));
+
+    let hypercube: UnGraph<(), ()> = hypercube_graph(4, |_| (), |_, _| ());
+    assert_eq!((hypercube.node_count(), hypercube.edge_count()), (16, 32));
+    assert!(degrees(&hypercube).iter().all(|&degree| degree == 4));
+    assert!(hypercube
+        .edge_references()
+        .all(|e| (e.source().index() ^ e.target().index()).count_ones() == 1));
+
+    let petersen: UnGraph<(), ()> = petersen_graph(|_| (), |_, _| ());
+    assert!(is_simple(&petersen, false));
+    assert!(degrees(&petersen).iter().all(|&degree| degree == 3));
+    // The Petersen graph has no triangle and no square.
+    for a in petersen.node_indices() {
+        for b in petersen.neighbors(a) {
+            for c in petersen.neighbors(b).filter(|&c| c != a) {
+                assert!(!petersen.contains_edge(a, c));
+                assert!(petersen
+                    .neighbors(c)
+                    .all(|d| d == b || !petersen.contains_edge(a, d)));
+            }
+        }
+    }
+
+    let bipartite: UnGraph<(), ()> = complete_bipartite_graph(2, 3, |_| (), |_, _| ());
+    assert_eq!(degrees(&bipartite), vec![3, 3, 2, 2, 2]);
+}
+
+#[test]
+fn classic_small_and_directed() {
+    for n in 0..3 {
+        let cycle: UnGraph<(), ()> = cycle_graph(n, |_| (), |_, _| ());
+        assert_eq!(cycle.edge_count(), n.saturating_sub(1));
+    }
+    let cycle: DiGraph<(), ()> = cycle_graph(2, |_| (), |_, _| ());
+    assert_eq!(edges(&cycle), vec![(0, 1), (1, 0)]);
+    let cycle: DiGraph<(), ()> = cycle_graph(1, |_| (), |_, _| ());
+    assert_eq!(cycle.edge_count(), 0);
+
+    let complete: DiGraph<(), ()> = complete_graph(4, |_| (), |_, _| ());
+    assert_eq!(complete.edge_count(), 12);
+    assert!(is_simple(&complete, true));
+
+    let grid: UnGraph<(), ()> = grid_graph(1, 0, |_| (), |_, _| ());
+    assert_eq!(grid.node_count(), 0);
+    let grid: UnGraph<(), ()> = grid_graph(1, 5, |_| (), |_, _| ());
+    assert_eq!(edges(&grid), [(0, 1), (1, 2), (2, 3), (3, 4)]);
+    let hypercube: UnGraph<(), ()> = hypercube_graph(0, |_| (), |_, _| ());
+    assert_eq!((hypercube.node_count(), hypercube.edge_count()), (1, 0));
+
+    let petersen: DiGraph<(), ()> = petersen_graph(|_| (), |_, _| ());
+    assert!(edges(&petersen).iter().all(|&(i, j)| i < j));
+    assert!(petersen.contains_edge(NodeIndex::new(5), NodeIndex::new(8)));
+    assert!(petersen.contains_edge(NodeIndex::new(0), NodeIndex::new(4)));
+}
+
+#[test]
+fn classic_weights_and_graph_types() {
+    let graph: DiGraphMap<usize, usize> = complete_graph(5, |i| i * 10, |i, j| i * 10 + j);
+    assert_eq!(graph.edge_count(), 20);
+    assert!(graph
+        .all_edges()
+        .all(|(a, b, &weight)| weight == a + b / 10));
+
+    let graph: StableUnGraph<char, ()> = petersen_graph(|i| (b'a' + i as u8) as char, |_, _| ());
+    assert_eq!(graph[NodeIndex::new(9)], 'j');
+
+    let graph: UnMatrix<(), u32> = grid_graph(2, 3, |_| (), |i, j| (i * j) as u32);
+    assert_eq!(graph.edge_count(), 7);
+    assert_eq!(*graph.edge_weight(NodeIndex::new(4), NodeIndex::new(5)), 20);
+
+    let graph: DiMatrix<(), ()> = cycle_graph(4, |_| (), |_, _| ());
+    assert!(graph.has_edge(NodeIndex::new(3), NodeIndex::new(0)));
+    assert!(!graph.has_edge(NodeIndex::new(0), NodeIndex::new(3)));
+}
@@ -16,7 +16,7 @@ use petgraph::algo::{
 use petgraph::graph::node_index as n;
 use petgraph::graph::{GraphError, IndexType};
 
-use petgraph::algo::{astar, dijkstra, DfsSpace};
+use petgraph::algo::{astar, dijkstra, dijkstra_with_paths, DfsSpace};
 use petgraph::visit::{
     IntoEdges, IntoEdgesDirected, IntoNodeIdentifiers, NodeFiltered, Reversed, Topo, VisitMap,
     Walker,
@@ -464,6 +464,44 @@ fn dijk() {
     assert_eq!(scores[&c], 9);
 }
 
+#[test]
+fn dijk_with_paths() {
+    let mut g = Graph::new();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let z = g.add_node("Z");
+    let ab = g.add_edge(a, b, 7);
+    g.add_edge(a, c, 9);
+    let ad = g.add_edge(a, d, 2);
+    let dc = g.add_edge(d, c, 3);
+    let be = g.add_edge(b, e, 1);
+    let ce = g.add_edge(c, e, 6);
+
+    let paths = dijkstra_with_paths(&g, [a], [], |e| *e.weight());
+    assert_eq!(paths.scores, dijkstra(&g, a, None, |e| *e.weight()));
+    assert_eq!(paths.path_to(a), Some((vec![a], vec![])));
+    assert_eq!(paths.path_to(c), Some((vec![a, d, c], vec![ad, dc])));
+    assert_eq!(paths.path_to(e), Some((vec![a, b, e], vec![ab, be])));
+    assert_eq!(paths.path_to(z), None);
+    assert_eq!(paths.cost(z), None);
+
+    // Multiple sources: every node is reached from its closest source.
+    let paths = dijkstra_with_paths(&g, [a, c], [], |e| *e.weight());
+    assert_eq!(paths.cost(c), Some(0));
+    assert_eq!(paths.path_to(c), Some((vec![c], vec![])));
+    assert_eq!(paths.path_to(e), Some((vec![c, e], vec![ce])));
+    assert_eq!(paths.path_to(b), Some((vec![a, b], vec![ab])));
+
+    // The search stops once both goals are settled, before reaching `e`.
+    let paths = dijkstra_with_paths(&g, [a], [d, c], |e| *e.weight());
+    assert_eq!(paths.cost(d), Some(2));
+    assert_eq!(paths.cost(c), Some(5));
+    assert_eq!(paths.cost(e), None);
+}
+
 #[test]
 fn test_astar_null_heuristic() {
     let mut g = Graph::new();
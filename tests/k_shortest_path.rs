@@ -1,5 +1,5 @@
 use hashbrown::HashMap;
-use petgraph::algo::k_shortest_path;
+use petgraph::algo::{k_shortest_path, shortest_simple_paths};
 use petgraph::prelude::*;
 use petgraph::Graph;
 
@@ -61,3 +61,108 @@ fn second_shortest_path() {
 
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn yen_simple_paths() {
+    // Example from https://en.wikipedia.org/wiki/Yen%27s_algorithm
+    let mut graph: Graph<(), u32, Directed> = Graph::new();
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    let e = graph.add_node(());
+    let f = graph.add_node(());
+    let g = graph.add_node(());
+    let h = graph.add_node(());
+
+    graph.extend_with_edges([
+        (c, d, 3),
+        (c, e, 2),
+        (d, f, 4),
+        (e, d, 1),
+        (e, f, 2),
+        (e, g, 3),
+        (f, g, 2),
+        (f, h, 1),
+        (g, h, 2),
+    ]);
+
+    let mut paths: Vec<_> = shortest_simple_paths(&graph, c, h, |e| *e.weight())
+        .map(|(cost, nodes, edges)| {
+            assert_eq!(nodes.len(), edges.len() + 1);
+            for (i, &edge) in edges.iter().enumerate() {
+                assert_eq!(graph.edge_endpoints(edge), Some((nodes[i], nodes[i + 1])));
+            }
+            (cost, nodes)
+        })
+        .collect();
+
+    // Paths are yielded by increasing cost; ties come in no particular order.
+    let costs: Vec<_> = paths.iter().map(|(cost, _)| *cost).collect();
+    assert_eq!(costs, vec![5, 7, 8, 8, 8, 11, 11]);
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![
+            (5, vec![c, e, f, h]),
+            (7, vec![c, e, g, h]),
+            (8, vec![c, d, f, h]),
+            (8, vec![c, e, d, f, h]),
+            (8, vec![c, e, f, g, h]),
+            (11, vec![c, d, f, g, h]),
+            (11, vec![c, e, d, f, g, h]),
+        ]
+    );
+}
+
+#[test]
+fn yen_parallel_edges_and_cycles() {
+    let mut graph: Graph<(), u32, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let ab1 = graph.add_edge(a, b, 1);
+    let ab2 = graph.add_edge(a, b, 2);
+    let bc = graph.add_edge(b, c, 1);
+    // A cycle that a simple path may never take.
+    graph.add_edge(b, a, 0);
+    graph.add_edge(c, c, 0);
+
+    let paths: Vec<_> = shortest_simple_paths(&graph, a, c, |e| *e.weight()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            (2, vec![a, b, c], vec![ab1, bc]),
+            (3, vec![a, b, c], vec![ab2, bc]),
+        ]
+    );
+
+    let mut paths = shortest_simple_paths(&graph, c, a, |e| *e.weight());
+    assert_eq!(paths.next(), None);
+    assert_eq!(paths.next(), None);
+
+    let paths: Vec<_> = shortest_simple_paths(&graph, a, a, |e| *e.weight()).collect();
+    assert_eq!(paths, vec![(0, vec![a], vec![])]);
+}
+
+#[test]
+fn yen_undirected() {
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges([(a, b, 1), (b, d, 1), (a, c, 2), (c, d, 2), (b, c, 1)]);
+
+    let mut paths: Vec<_> = shortest_simple_paths(&graph, a, d, |e| *e.weight())
+        .map(|(cost, nodes, _)| (cost, nodes))
+        .collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![
+            (2, vec![a, b, d]),
+            (4, vec![a, b, c, d]),
+            (4, vec![a, c, b, d]),
+            (4, vec![a, c, d]),
+        ]
+    );
+}
@@ -0,0 +1,88 @@
+use petgraph::acyclic::Acyclic;
+use petgraph::algo::{critical_path, longest_path};
+use petgraph::graph::EdgeReference;
+use petgraph::prelude::*;
+
+#[test]
+fn longest_path_weights() {
+    // a -> b -> d -> e, a -> c -> d, and a light shortcut a -> e.
+    let mut graph = DiGraph::<(), i32>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    let e = graph.add_node(());
+    graph.extend_with_edges([
+        (a, b, 1),
+        (b, d, 1),
+        (a, c, 4),
+        (c, d, 1),
+        (d, e, 2),
+        (a, e, 6),
+    ]);
+    assert_eq!(
+        longest_path(&graph, |e| *e.weight()),
+        Ok((7, vec![a, c, d, e]))
+    );
+    let (length, path) = longest_path(&graph, |_| 1).unwrap();
+    assert_eq!((length, path.len()), (3, 4));
+
+    // A path made of negative edges only is never longer than a single node.
+    assert_eq!(longest_path(&graph, |_| -1), Ok((0, vec![a])));
+    let mixed = |edge: EdgeReference<i32>| match edge.source() {
+        source if source == a => -5,
+        source if source == c => 1,
+        _ => 3,
+    };
+    assert_eq!(longest_path(&graph, mixed), Ok((6, vec![b, d, e])));
+}
+
+#[test]
+fn longest_path_edge_cases() {
+    let mut graph = DiGraph::<(), f64>::new();
+    assert_eq!(longest_path(&graph, |e| *e.weight()), Ok((0., vec![])));
+    let a = graph.add_node(());
+    assert_eq!(longest_path(&graph, |e| *e.weight()), Ok((0., vec![a])));
+
+    graph.add_edge(a, a, 1.);
+    let cycle = longest_path(&graph, |e| *e.weight()).unwrap_err();
+    assert_eq!(cycle.node_id(), a);
+    assert!(critical_path(&graph, |e| *e.weight()).is_err());
+}
+
+#[test]
+fn critical_path_schedule() {
+    // Two independent chains of tasks, the longest being critical, and a removed node.
+    let mut graph = StableDiGraph::<(), u32>::new();
+    let nodes: Vec<_> = (0..7).map(|_| graph.add_node(())).collect();
+    graph.extend_with_edges([(0, 1, 2), (1, 2, 3), (4, 5, 1), (5, 6, 1), (3, 2, 0)]);
+    graph.remove_node(nodes[3]);
+
+    let schedule = critical_path(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(schedule.length, 5);
+    assert_eq!(schedule.path, [nodes[0], nodes[1], nodes[2]]);
+    assert_eq!(schedule.earliest_start, [0, 2, 5, 0, 0, 1, 2]);
+    assert_eq!(schedule.latest_start, [0, 2, 5, 0, 3, 4, 5]);
+    assert_eq!(schedule.slack, [0, 0, 0, 0, 3, 3, 3]);
+}
+
+#[test]
+fn longest_path_graph_types() {
+    let mut graph = DiGraphMap::<&str, f64>::new();
+    graph.add_edge("fetch", "build", 2.5);
+    graph.add_edge("build", "test", 4.);
+    graph.add_edge("build", "lint", 1.);
+    graph.add_edge("lint", "deploy", 0.5);
+    graph.add_edge("test", "deploy", 1.);
+    let (length, path) = longest_path(&graph, |(_, _, &w)| w).unwrap();
+    assert_eq!(length, 7.5);
+    assert_eq!(path, ["fetch", "build", "test", "deploy"]);
+
+    let schedule = critical_path(&graph, |(_, _, &w)| w).unwrap();
+    let slack = |node| schedule.slack[graph.nodes().position(|n| n == node).unwrap()];
+    assert_eq!(slack("lint"), 3.5);
+    assert_eq!(slack("test"), 0.);
+
+    let acyclic = Acyclic::try_from(graph).unwrap();
+    assert_eq!(critical_path(&acyclic, |(_, _, &w)| w).unwrap(), schedule);
+}
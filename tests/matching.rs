@@ -2,7 +2,11 @@ use core::hash::Hash;
 
 use hashbrown::HashSet;
 
-use petgraph::algo::{greedy_matching, maximum_bipartite_matching, maximum_matching};
+use petgraph::algo::{
+    greedy_matching, maximum_bipartite_matching, maximum_matching,
+    maximum_weight_bipartite_matching, maximum_weight_matching,
+    minimum_cost_perfect_bipartite_matching,
+};
 use petgraph::prelude::*;
 
 macro_rules! assert_one_of {
@@ -205,3 +209,176 @@ fn maximum_bipartite_test() {
         set![a_1, a_3, a_4, a_5, a_6, b_1, b_2, b_3, b_4, b_6]
     );
 }
+
+#[test]
+fn maximum_weight_bipartite_empty() {
+    let g: UnGraph<(), u32> = UnGraph::default();
+    let (m, weight) = maximum_weight_bipartite_matching(&g, &[], &[], |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![]);
+    assert_eq!(weight, 0);
+}
+
+#[test]
+fn maximum_weight_bipartite_prefers_weight_over_cardinality() {
+    let mut g: UnGraph<(), f64> = UnGraph::new_undirected();
+    let a_1 = g.add_node(());
+    let a_2 = g.add_node(());
+    let b_1 = g.add_node(());
+    let b_2 = g.add_node(());
+    let b_3 = g.add_node(());
+    g.extend_with_edges([
+        (a_1, b_1, 10.),
+        (a_1, b_2, 4.),
+        (a_2, b_1, 4.),
+        (a_2, b_3, -1.),
+    ]);
+
+    let (m, weight) =
+        maximum_weight_bipartite_matching(&g, &[a_1, a_2], &[b_1, b_2, b_3], |e| *e.weight());
+    assert_eq!(weight, 10.);
+    assert_eq!(collect(m.edges()), set![(a_1, b_1)]);
+    assert_eq!(m.mate(a_2), None);
+}
+
+#[test]
+fn maximum_weight_bipartite_directed_and_parallel_edges() {
+    let mut g: DiGraph<(), u32> = DiGraph::new();
+    let a_1 = g.add_node(());
+    let a_2 = g.add_node(());
+    let b_1 = g.add_node(());
+    let b_2 = g.add_node(());
+    // Edges may point either way, and the heaviest parallel edge counts.
+    g.extend_with_edges([(a_1, b_1, 3), (b_1, a_1, 7), (b_2, a_2, 2), (a_2, b_1, 6)]);
+
+    let (m, weight) =
+        maximum_weight_bipartite_matching(&g, &[a_1, a_2], &[b_1, b_2], |e| *e.weight());
+    assert_eq!(weight, 9);
+    assert_eq!(m.mate(a_1), Some(b_1));
+    assert_eq!(m.mate(a_2), Some(b_2));
+}
+
+#[test]
+fn minimum_cost_perfect_bipartite_test() {
+    let mut g: UnGraph<(), i32> = UnGraph::new_undirected();
+    let workers: Vec<_> = (0..4).map(|_| g.add_node(())).collect();
+    let jobs: Vec<_> = (0..4).map(|_| g.add_node(())).collect();
+    let costs = [
+        [82, 83, 69, 92],
+        [77, 37, 49, 92],
+        [11, 69, 5, 86],
+        [8, 9, 98, 23],
+    ];
+    for (i, row) in costs.iter().enumerate() {
+        for (j, &cost) in row.iter().enumerate() {
+            g.add_edge(workers[i], jobs[j], cost - 50);
+        }
+    }
+
+    let (m, cost) =
+        minimum_cost_perfect_bipartite_matching(&g, &workers, &jobs, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 140 - 4 * 50);
+    assert!(m.is_perfect());
+    assert_eq!(
+        collect(m.edges()),
+        set![
+            (workers[0], jobs[2]),
+            (workers[1], jobs[1]),
+            (workers[2], jobs[0]),
+            (workers[3], jobs[3])
+        ]
+    );
+}
+
+#[test]
+fn minimum_cost_perfect_bipartite_none() {
+    let mut g: UnGraph<(), u32> = UnGraph::new_undirected();
+    let a_1 = g.add_node(());
+    let a_2 = g.add_node(());
+    let b_1 = g.add_node(());
+    let b_2 = g.add_node(());
+    g.extend_with_edges([(a_1, b_1, 1), (a_2, b_1, 1)]);
+
+    let weight = |e: petgraph::graph::EdgeReference<u32>| *e.weight();
+    assert!(
+        minimum_cost_perfect_bipartite_matching(&g, &[a_1, a_2], &[b_1, b_2], weight).is_none()
+    );
+    assert!(minimum_cost_perfect_bipartite_matching(&g, &[a_1, a_2], &[b_1], weight).is_none());
+}
+
+#[test]
+fn maximum_weight_empty() {
+    let g: UnGraph<(), u32> = UnGraph::default();
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![]);
+}
+
+#[test]
+fn maximum_weight_blossom() {
+    // A triangle a, b, c with a pendant edge at each corner, where the
+    // heaviest matching has to go through the blossom.
+    let mut g: UnGraph<(), u32> = UnGraph::new_undirected();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    let d = g.add_node(());
+    let e = g.add_node(());
+    let f = g.add_node(());
+    g.extend_with_edges([
+        (a, b, 8),
+        (b, c, 9),
+        (c, a, 10),
+        (a, d, 5),
+        (b, e, 3),
+        (c, f, 2),
+    ]);
+
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(b, c), (a, d)]);
+    assert_eq!(m.mate(e), None);
+    assert_eq!(m.mate(f), None);
+
+    let m = maximum_weight_matching(&g, true, |e| *e.weight() as i32);
+    assert!(m.is_perfect());
+    assert_eq!(collect(m.edges()), set![(a, d), (b, e), (c, f)]);
+}
+
+#[test]
+fn maximum_weight_nested_blossoms() {
+    // Van Rantwijk's test case with nested S-blossoms, augmented and expanded.
+    let mut g: UnGraph<(), i32> = UnGraph::new_undirected();
+    let n: Vec<_> = (0..7).map(|_| g.add_node(())).collect();
+    g.extend_with_edges([
+        (n[1], n[2], 9),
+        (n[1], n[3], 9),
+        (n[2], n[3], 10),
+        (n[2], n[4], 8),
+        (n[3], n[5], 8),
+        (n[4], n[5], 10),
+        (n[5], n[6], 6),
+    ]);
+
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(
+        collect(m.edges()),
+        set![(n[1], n[3]), (n[2], n[4]), (n[5], n[6])]
+    );
+}
+
+#[test]
+fn maximum_weight_negative_and_float_weights() {
+    let mut g: UnGraph<(), f64> = UnGraph::new_undirected();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    let d = g.add_node(());
+    g.extend_with_edges([(a, b, 2.5), (b, c, 3.5), (c, d, 2.5), (a, d, -1.)]);
+
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(a, b), (c, d)]);
+
+    let m = maximum_weight_matching(&g, false, |e| -*e.weight());
+    assert_eq!(collect(m.edges()), set![(a, d)]);
+
+    let m = maximum_weight_matching(&g, true, |e| -*e.weight());
+    assert!(m.is_perfect());
+}
@@ -0,0 +1,133 @@
+use petgraph::algo::{min_cost_flow, min_cost_max_flow, NegativeCycle};
+use petgraph::prelude::{Graph, StableGraph};
+use petgraph::visit::EdgeRef;
+
+#[test]
+fn test_min_cost_flow_value() {
+    // Two disjoint paths from the source to the sink, one cheap with small capacity.
+    let mut graph = Graph::<(), (i64, i64)>::new();
+    let source = graph.add_node(());
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let sink = graph.add_node(());
+    let sa = graph.add_edge(source, a, (3, 1));
+    let at = graph.add_edge(a, sink, (3, 1));
+    let sb = graph.add_edge(source, b, (10, 4));
+    let bt = graph.add_edge(b, sink, (10, 4));
+
+    let capacity = |e: petgraph::graph::EdgeReference<(i64, i64)>| e.weight().0;
+    let cost = |e: petgraph::graph::EdgeReference<(i64, i64)>| e.weight().1;
+
+    assert_eq!(
+        min_cost_flow(&graph, source, sink, 2, capacity, cost),
+        Ok((2, 4, vec![2, 2, 0, 0]))
+    );
+    assert_eq!(
+        min_cost_flow(&graph, source, sink, 5, capacity, cost),
+        Ok((5, 6 + 16, vec![3, 3, 2, 2]))
+    );
+    // More than the network can carry gives the cheapest maximum flow.
+    let (flow, cost_value, flows) =
+        min_cost_flow(&graph, source, sink, 100, capacity, cost).unwrap();
+    assert_eq!(flow, 13);
+    assert_eq!(cost_value, 6 + 80);
+    assert_eq!(flows[sa.index()], 3);
+    assert_eq!(flows[at.index()], 3);
+    assert_eq!(flows[sb.index()], 10);
+    assert_eq!(flows[bt.index()], 10);
+    assert_eq!(
+        min_cost_max_flow(&graph, source, sink, capacity, cost),
+        Ok((flow, cost_value, flows))
+    );
+
+    assert_eq!(
+        min_cost_max_flow(&graph, sink, source, capacity, cost),
+        Ok((0, 0, vec![0; 4]))
+    );
+}
+
+#[test]
+fn test_min_cost_flow_reroutes() {
+    // The cheapest path for one unit must be undone to send two units.
+    let mut graph = Graph::<(), (i32, i32)>::new();
+    let s = graph.add_node(());
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let t = graph.add_node(());
+    graph.extend_with_edges([
+        (s, a, (1, 1)),
+        (s, b, (1, 5)),
+        (a, b, (1, 1)),
+        (a, t, (1, 5)),
+        (b, t, (1, 1)),
+    ]);
+
+    let capacity = |e: petgraph::graph::EdgeReference<(i32, i32)>| e.weight().0;
+    let cost = |e: petgraph::graph::EdgeReference<(i32, i32)>| e.weight().1;
+
+    assert_eq!(
+        min_cost_flow(&graph, s, t, 1, capacity, cost),
+        Ok((1, 3, vec![1, 0, 1, 0, 1]))
+    );
+    assert_eq!(
+        min_cost_max_flow(&graph, s, t, capacity, cost),
+        Ok((2, 12, vec![1, 1, 0, 1, 1]))
+    );
+}
+
+#[test]
+fn test_min_cost_flow_negative_costs() {
+    let mut graph = Graph::<(), (f64, f64)>::new();
+    let s = graph.add_node(());
+    let a = graph.add_node(());
+    let t = graph.add_node(());
+    graph.extend_with_edges([(s, a, (2., -3.)), (a, t, (1., 1.)), (s, t, (1., 1.))]);
+
+    let (flow, cost, _) =
+        min_cost_max_flow(&graph, s, t, |e| e.weight().0, |e| e.weight().1).unwrap();
+    assert_eq!(flow, 2.);
+    assert_eq!(cost, -1.);
+
+    // A negative cycle with positive capacity is rejected, even if unreachable.
+    let x = graph.add_node(());
+    let y = graph.add_node(());
+    graph.extend_with_edges([(x, y, (1., -2.)), (y, x, (1., 1.))]);
+    assert_eq!(
+        min_cost_max_flow(&graph, s, t, |e| e.weight().0, |e| e.weight().1),
+        Err(NegativeCycle(()))
+    );
+
+    // A negative cycle without capacity is harmless.
+    assert!(min_cost_max_flow(
+        &graph,
+        s,
+        t,
+        |e| if e.source() == y { 0. } else { e.weight().0 },
+        |e| e.weight().1
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_min_cost_flow_stable_graph() {
+    let mut graph = StableGraph::<(), (i32, i32)>::new();
+    let s = graph.add_node(());
+    let removed = graph.add_node(());
+    let a = graph.add_node(());
+    let t = graph.add_node(());
+    graph.add_edge(s, removed, (5, 0));
+    graph.add_edge(removed, t, (5, 0));
+    let sa = graph.add_edge(s, a, (2, 2));
+    let at = graph.add_edge(a, t, (3, 1));
+    let st = graph.add_edge(s, t, (1, 7));
+    graph.remove_node(removed);
+
+    let (flow, cost, flows) =
+        min_cost_max_flow(&graph, s, t, |e| e.weight().0, |e| e.weight().1).unwrap();
+    assert_eq!(flow, 3);
+    assert_eq!(cost, 13);
+    assert_eq!(flows.len(), 5);
+    assert_eq!(flows[sa.index()], 2);
+    assert_eq!(flows[at.index()], 2);
+    assert_eq!(flows[st.index()], 1);
+}
@@ -0,0 +1,122 @@
+use petgraph::algo::{dinics, ford_fulkerson, min_st_cut, stoer_wagner_min_cut};
+use petgraph::prelude::{Graph, StableGraph, UnGraph};
+use petgraph::visit::EdgeRef;
+
+#[test]
+fn test_min_st_cut() {
+    // Example from CLRS book
+    let mut graph = Graph::<u8, u8>::new();
+    let source = graph.add_node(0);
+    let v1 = graph.add_node(1);
+    let v2 = graph.add_node(2);
+    let v3 = graph.add_node(3);
+    let v4 = graph.add_node(4);
+    let sink = graph.add_node(5);
+    graph.extend_with_edges([
+        (0, 1, 16),
+        (0, 2, 13),
+        (1, 2, 10),
+        (1, 3, 12),
+        (2, 1, 4),
+        (2, 4, 14),
+        (3, 2, 9),
+        (3, 5, 20),
+        (4, 3, 7),
+        (4, 5, 4),
+    ]);
+
+    for (max_flow, flows) in [
+        ford_fulkerson(&graph, source, sink),
+        dinics(&graph, source, sink),
+    ] {
+        let (mut source_side, cut_edges) = min_st_cut(&graph, source, &flows);
+        source_side.sort();
+        assert_eq!(source_side, vec![source, v1, v2, v4]);
+        let cut: Vec<_> = cut_edges
+            .iter()
+            .map(|&e| graph.edge_endpoints(e).unwrap())
+            .collect();
+        assert_eq!(cut, vec![(v1, v3), (v4, v3), (v4, sink)]);
+        let capacity: u8 = cut_edges.iter().map(|&e| graph[e]).sum();
+        assert_eq!(capacity, max_flow);
+        assert_eq!(max_flow, 23);
+    }
+}
+
+#[test]
+fn test_min_st_cut_disconnected() {
+    let mut graph = StableGraph::<(), f32>::new();
+    let source = graph.add_node(());
+    let removed = graph.add_node(());
+    let a = graph.add_node(());
+    let sink = graph.add_node(());
+    graph.add_edge(source, removed, 1.);
+    graph.add_edge(source, a, 1.);
+    graph.add_edge(sink, a, 1.);
+    graph.remove_node(removed);
+
+    let (max_flow, flows) = ford_fulkerson(&graph, source, sink);
+    assert_eq!(max_flow, 0.);
+    let (mut source_side, cut_edges) = min_st_cut(&graph, source, &flows);
+    source_side.sort();
+    assert_eq!(source_side, vec![source, a]);
+    assert!(cut_edges.is_empty());
+}
+
+#[test]
+fn test_stoer_wagner() {
+    // Example from the original paper by Stoer and Wagner.
+    let mut graph = UnGraph::<(), u32>::new_undirected();
+    let nodes: Vec<_> = (0..8).map(|_| graph.add_node(())).collect();
+    graph.extend_with_edges([
+        (0, 1, 2),
+        (0, 4, 3),
+        (1, 2, 3),
+        (1, 4, 2),
+        (1, 5, 2),
+        (2, 3, 4),
+        (2, 6, 2),
+        (3, 6, 2),
+        (3, 7, 2),
+        (4, 5, 3),
+        (5, 6, 1),
+        (6, 7, 3),
+    ]);
+
+    let (weight, mut side) = stoer_wagner_min_cut(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 4);
+    side.sort();
+    let expected = vec![nodes[2], nodes[3], nodes[6], nodes[7]];
+    let complement = vec![nodes[0], nodes[1], nodes[4], nodes[5]];
+    assert!(side == expected || side == complement);
+    let crossing: u32 = graph
+        .edge_references()
+        .filter(|e| side.contains(&e.source()) != side.contains(&e.target()))
+        .map(|e| *e.weight())
+        .sum();
+    assert_eq!(crossing, weight);
+}
+
+#[test]
+fn test_stoer_wagner_small() {
+    let mut graph = UnGraph::<(), f64>::new_undirected();
+    assert_eq!(stoer_wagner_min_cut(&graph, |e| *e.weight()), None);
+    let a = graph.add_node(());
+    assert_eq!(stoer_wagner_min_cut(&graph, |e| *e.weight()), None);
+
+    // Disconnected graphs have a cut of weight zero.
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(a, b, 1.5);
+    graph.add_edge(a, b, 1.5);
+    graph.add_edge(c, c, 1.);
+    let (weight, side) = stoer_wagner_min_cut(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 0.);
+    assert!(side == vec![c] || side == vec![a, b] || side == vec![b, a]);
+
+    // Parallel edges add up.
+    graph.add_edge(b, c, 4.);
+    let (weight, side) = stoer_wagner_min_cut(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 3.);
+    assert!(side == vec![a] || side == vec![b, c] || side == vec![c, b]);
+}
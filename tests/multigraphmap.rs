@@ -0,0 +1,173 @@
+#![cfg(feature = "graphmap")]
+extern crate petgraph;
+
+use std::collections::HashSet;
+
+use petgraph::algo::{connected_components, dijkstra, has_path_connecting, toposort};
+use petgraph::data::{Build, Element, FromElements};
+use petgraph::multigraphmap::{DiMultiGraphMap, UnMultiGraphMap};
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeIndexable, IntoEdgeReferences, NodeIndexable, Walker};
+
+#[test]
+fn parallel_edges() {
+    let mut gr = DiMultiGraphMap::<_, _>::new();
+    let first = gr.add_edge("a", "b", 1);
+    let second = gr.add_edge("a", "b", 2);
+    let back = gr.add_edge("b", "a", 3);
+    let loop_ = gr.add_edge("b", "b", 4);
+    assert_ne!(first, second);
+    assert_eq!(gr.node_count(), 2);
+    assert_eq!(gr.edge_count(), 4);
+
+    fn weights(gr: &DiMultiGraphMap<&str, i32>, a: &str, b: &str) -> Vec<i32> {
+        let mut weights: Vec<_> = gr.edges_connecting(a, b).map(|e| *e.weight()).collect();
+        weights.sort();
+        weights
+    }
+    assert_eq!(weights(&gr, "a", "b"), [1, 2]);
+    assert_eq!(weights(&gr, "b", "a"), [3]);
+    assert_eq!(weights(&gr, "b", "b"), [4]);
+    assert_eq!(weights(&gr, "a", "a"), []);
+    assert!(gr.contains_edge("a", "b"));
+    assert!(!gr.contains_edge("a", "a"));
+
+    assert_eq!(gr.neighbors("a").collect::<Vec<_>>(), ["b", "b"]);
+    assert_eq!(
+        gr.neighbors_directed("a", Incoming).collect::<Vec<_>>(),
+        ["b"]
+    );
+    assert_eq!(gr.edges_directed("b", Incoming).count(), 3);
+    for edge in gr.edges_directed("b", Incoming) {
+        assert_eq!(edge.target(), "b");
+        assert_eq!(gr.edge_endpoints(edge.id()), Some((edge.source(), "b")));
+    }
+
+    assert_eq!(gr[back], 3);
+    gr[back] = 5;
+    assert_eq!(gr.edge_weight(back), Some(&5));
+    assert_eq!(gr.remove_edge(first), Some(1));
+    assert_eq!(gr.remove_edge(first), None);
+    assert_eq!(weights(&gr, "a", "b"), [2]);
+    // The keys of the other edges stay valid.
+    assert_eq!(gr.edge_endpoints(second), Some(("a", "b")));
+    assert_eq!(gr.edge_endpoints(loop_), Some(("b", "b")));
+    assert_eq!(gr.edge_count(), 3);
+}
+
+#[test]
+fn undirected() {
+    let mut gr = UnMultiGraphMap::<_, _>::from_edges([(1, 2, 'x'), (2, 1, 'y'), (2, 3, 'z')]);
+    assert_eq!(gr.edges_connecting(1, 2).count(), 2);
+    assert_eq!(gr.edges_connecting(2, 1).count(), 2);
+    for edge in gr.edges(2) {
+        assert_eq!(edge.source(), 2);
+    }
+    for edge in gr.edges_directed(2, Incoming) {
+        assert_eq!(edge.target(), 2);
+    }
+    let mut neighbors: Vec<_> = gr.neighbors(2).collect();
+    neighbors.sort();
+    assert_eq!(neighbors, [1, 1, 3]);
+
+    assert!(gr.remove_node(1));
+    assert!(!gr.remove_node(1));
+    assert_eq!(gr.edge_count(), 1);
+    assert_eq!(gr.neighbors(2).collect::<Vec<_>>(), [3]);
+    assert_eq!(
+        gr.all_edges().map(|e| *e.weight()).collect::<Vec<_>>(),
+        ['z']
+    );
+}
+
+#[test]
+fn remove_node() {
+    let mut gr = DiMultiGraphMap::<_, ()>::from_edges([(0, 1), (1, 1), (1, 2), (1, 2), (2, 0)]);
+    assert!(gr.remove_node(1));
+    assert_eq!(gr.node_count(), 2);
+    assert_eq!(gr.edge_count(), 1);
+    assert_eq!(gr.neighbors(0).count(), 0);
+    assert_eq!(gr.neighbors_directed(2, Incoming).count(), 0);
+    assert_eq!(gr.neighbors(2).collect::<Vec<_>>(), [0]);
+
+    // Indices stay compact after removals.
+    for i in 0..gr.node_bound() {
+        let n = NodeIndexable::from_index(&gr, i);
+        assert_eq!(NodeIndexable::to_index(&gr, n), i);
+    }
+    for i in 0..gr.edge_bound() {
+        let e = EdgeIndexable::from_index(&gr, i);
+        assert_eq!(EdgeIndexable::to_index(&gr, e), i);
+    }
+}
+
+#[test]
+fn graph_conversions() {
+    let gr = DiMultiGraphMap::<_, _>::from_edges([("a", "b", 1.), ("a", "b", 2.), ("b", "c", 3.)]);
+    let graph: DiGraph<_, _> = gr.clone().into_graph();
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.edge_count(), 3);
+
+    let back = DiMultiGraphMap::<_, _>::from_graph(graph);
+    assert_eq!(back.edge_count(), 3);
+    assert_eq!(back.edges_connecting("a", "b").count(), 2);
+
+    let elements = gr
+        .edge_references()
+        .map(|e| (e.source(), e.target(), *e.weight()));
+    let rebuilt = DiMultiGraphMap::<_, _>::from_edges(elements);
+    assert_eq!(rebuilt.edge_count(), 3);
+
+    let from_elements = DiMultiGraphMap::<_, _>::from_elements(vec![
+        Element::Node { weight: "a" },
+        Element::Node { weight: "b" },
+        Element::Edge {
+            source: 0,
+            target: 1,
+            weight: 1.,
+        },
+        Element::Edge {
+            source: 0,
+            target: 1,
+            weight: 2.,
+        },
+    ]);
+    assert_eq!(from_elements.edges_connecting("a", "b").count(), 2);
+}
+
+#[test]
+fn build_update_edge() {
+    let mut gr = DiMultiGraphMap::<_, _>::new();
+    let key = Build::update_edge(&mut gr, 0, 1, "first");
+    gr.add_edge(0, 1, "second");
+    assert_eq!(Build::update_edge(&mut gr, 0, 1, "updated"), key);
+    assert_eq!(gr[key], "updated");
+    assert_eq!(gr.edge_count(), 2);
+}
+
+#[test]
+fn algorithms() {
+    // Two parallel routes between each pair of cities, and an isolated city.
+    let mut gr = UnMultiGraphMap::<_, _>::new();
+    gr.add_edge("A", "B", 7);
+    gr.add_edge("A", "B", 3);
+    gr.add_edge("B", "C", 2);
+    gr.add_edge("B", "C", 8);
+    gr.add_edge("A", "C", 9);
+    gr.add_node("D");
+
+    let scores = dijkstra(&gr, "A", None, |e| *e.weight());
+    assert_eq!(scores["B"], 3);
+    assert_eq!(scores["C"], 5);
+    assert!(!scores.contains_key("D"));
+    assert_eq!(connected_components(&gr), 2);
+    assert!(has_path_connecting(&gr, "C", "A", None));
+
+    let reachable: HashSet<_> = Dfs::new(&gr, "C").iter(&gr).collect();
+    assert_eq!(reachable, ["A", "B", "C"].into_iter().collect());
+
+    let dag = DiMultiGraphMap::<_, ()>::from_edges([(1, 2), (1, 2), (2, 3), (1, 3)]);
+    assert_eq!(toposort(&dag, None), Ok(vec![1, 2, 3]));
+    let cyclic = DiMultiGraphMap::<_, ()>::from_edges([(1, 2), (2, 1)]);
+    assert!(toposort(&cyclic, None).is_err());
+}
@@ -0,0 +1,191 @@
+use petgraph::algo::push_relabel;
+use petgraph::prelude::{Direction, EdgeRef, Graph};
+
+#[test]
+fn test_push_relabel_a() {
+    // Example from https://downey.io/blog/max-flow-ford-fulkerson-algorithm-explanation/
+    // Graph Image: https://images.downey.io/max-flow/max-flow-3.png
+    let mut graph = Graph::<usize, u16>::new();
+    let source = graph.add_node(0);
+    let _ = graph.add_node(1);
+    let _ = graph.add_node(2);
+    let sink = graph.add_node(3);
+    graph.extend_with_edges([(0, 1, 3), (0, 2, 2), (1, 2, 5), (1, 3, 2), (2, 3, 3)]);
+    let (max_flow, _) = push_relabel(&graph, source, sink);
+    assert_eq!(5, max_flow);
+}
+
+#[test]
+fn test_push_relabel_b() {
+    // Example from https://brilliant.org/wiki/ford-fulkerson-algorithm/
+    let mut graph = Graph::<usize, f32>::new();
+    let source = graph.add_node(0);
+    let _ = graph.add_node(1);
+    let _ = graph.add_node(2);
+    let _ = graph.add_node(3);
+    let _ = graph.add_node(4);
+    let sink = graph.add_node(5);
+    graph.extend_with_edges([
+        (0, 1, 4.),
+        (0, 2, 3.),
+        (1, 3, 4.),
+        (2, 4, 6.),
+        (3, 2, 3.),
+        (3, 5, 2.),
+        (4, 5, 6.),
+    ]);
+    let (max_flow, _) = push_relabel(&graph, source, sink);
+    assert_eq!(7.0, max_flow);
+}
+#[test]
+fn test_push_relabel_c() {
+    // Example from https://cp-algorithms.com/graph/edmonds_karp.html
+    let mut graph = Graph::<usize, f32>::new();
+    let source = graph.add_node(0);
+    let _ = graph.add_node(1);
+    let _ = graph.add_node(2);
+    let _ = graph.add_node(3);
+    let _ = graph.add_node(4);
+    let sink = graph.add_node(5);
+    graph.extend_with_edges([
+        (0, 1, 7.),
+        (0, 2, 4.),
+        (1, 3, 5.),
+        (1, 4, 3.),
+        (2, 1, 3.),
+        (2, 4, 2.),
+        (3, 5, 8.),
+        (4, 3, 3.),
+        (4, 5, 5.),
+    ]);
+    let (max_flow, _) = push_relabel(&graph, source, sink);
+    assert_eq!(10.0, max_flow);
+}
+
+#[test]
+fn test_push_relabel_d() {
+    // Example from https://www.programiz.com/dsa/ford-fulkerson-algorithm (corrected: result not 6 but 5)
+    let mut graph = Graph::<u8, f32>::new();
+    let source = graph.add_node(0);
+    let _ = graph.add_node(1);
+    let _ = graph.add_node(2);
+    let _ = graph.add_node(3);
+    let _ = graph.add_node(4);
+    let sink = graph.add_node(5);
+    graph.extend_with_edges([
+        (0, 1, 8.),
+        (0, 2, 3.),
+        (1, 3, 9.),
+        (2, 3, 7.),
+        (2, 4, 4.),
+        (3, 5, 2.),
+        (4, 5, 5.),
+    ]);
+    let (max_flow, _) = push_relabel(&graph, source, sink);
+    assert_eq!(5.0, max_flow);
+}
+
+#[test]
+fn test_push_relabel_e() {
+    let mut graph = Graph::<u8, u8>::new();
+    let source = graph.add_node(0);
+    let _ = graph.add_node(1);
+    let _ = graph.add_node(2);
+    let _ = graph.add_node(3);
+    let _ = graph.add_node(4);
+    let sink = graph.add_node(5);
+    graph.extend_with_edges([
+        (0, 1, 16),
+        (0, 2, 13),
+        (1, 2, 10),
+        (1, 3, 12),
+        (2, 1, 4),
+        (2, 4, 14),
+        (3, 2, 9),
+        (3, 5, 20),
+        (4, 3, 7),
+        (4, 5, 4),
+    ]);
+    let (max_flow, _) = push_relabel(&graph, source, sink);
+    assert_eq!(23, max_flow);
+}
+
+#[test]
+fn test_push_relabel_f() {
+    // Example taken from https://medium.com/@jithmisha/solving-the-maximum-flow-problem-with-ford-fulkerson-method-3fccc2883dc7
+    let mut graph = Graph::<u8, u8>::new();
+    let source = graph.add_node(0);
+    let _ = graph.add_node(1);
+    let _ = graph.add_node(2);
+    let _ = graph.add_node(3);
+    let _ = graph.add_node(4);
+    let sink = graph.add_node(5);
+    graph.extend_with_edges([
+        (0, 1, 10),
+        (0, 2, 10),
+        (1, 2, 2),
+        (1, 3, 4),
+        (1, 4, 8),
+        (2, 4, 9),
+        (3, 5, 10),
+        (4, 3, 6),
+        (4, 5, 10),
+    ]);
+    let (max_flow, _) = push_relabel(&graph, source, sink);
+    assert_eq!(19, max_flow);
+}
+
+#[test]
+fn test_push_relabel_flows() {
+    // Flow also has to be returned from dead ends back to the source.
+    let mut graph = Graph::<u8, u32>::new();
+    let source = graph.add_node(0);
+    let a = graph.add_node(1);
+    let b = graph.add_node(2);
+    let dead_end = graph.add_node(3);
+    let sink = graph.add_node(4);
+    let edges = [
+        graph.add_edge(source, a, 10),
+        graph.add_edge(source, b, 4),
+        graph.add_edge(a, dead_end, 7),
+        graph.add_edge(a, sink, 3),
+        graph.add_edge(b, a, 5),
+        graph.add_edge(b, sink, 2),
+        graph.add_edge(dead_end, b, 1),
+    ];
+    let (max_flow, flows) = push_relabel(&graph, source, sink);
+    assert_eq!(5, max_flow);
+    assert_eq!(flows.len(), edges.len());
+    for node in graph.node_indices() {
+        let out_flow: u32 = graph
+            .edges_directed(node, Direction::Outgoing)
+            .map(|e| flows[e.id().index()])
+            .sum();
+        let in_flow: u32 = graph
+            .edges_directed(node, Direction::Incoming)
+            .map(|e| flows[e.id().index()])
+            .sum();
+        if node == source {
+            assert_eq!(out_flow - in_flow, max_flow);
+        } else if node == sink {
+            assert_eq!(in_flow - out_flow, max_flow);
+        } else {
+            assert_eq!(out_flow, in_flow);
+        }
+    }
+    for edge in graph.edge_references() {
+        assert!(flows[edge.id().index()] <= *edge.weight());
+    }
+}
+
+#[test]
+fn test_push_relabel_source_is_sink() {
+    let mut graph = Graph::<u8, u32>::new();
+    let a = graph.add_node(0);
+    let b = graph.add_node(1);
+    graph.add_edge(a, b, 3);
+    graph.add_edge(b, a, 3);
+    let (max_flow, flows) = push_relabel(&graph, a, a);
+    assert_eq!(0, max_flow);
+    assert_eq!(vec![0, 0], flows);
+}
@@ -30,11 +30,18 @@ use rand::Rng;
 #[cfg(feature = "stable_graph")]
 use petgraph::algo::steiner_tree;
 use petgraph::algo::{
-    bellman_ford, bridges, condensation, connected_components, dijkstra, dsatur_coloring,
-    find_negative_cycle, floyd_warshall, ford_fulkerson, greedy_feedback_arc_set, greedy_matching,
-    is_cyclic_directed, is_cyclic_undirected, is_isomorphic, is_isomorphic_matching, johnson,
-    k_shortest_path, kosaraju_scc, maximal_cliques as maximal_cliques_algo, maximum_matching,
-    min_spanning_tree, page_rank, spfa, tarjan_scc, toposort, Matching,
+    bellman_ford, betweenness_centrality, bidirectional_astar, bidirectional_dijkstra, bridges,
+    condensation, connected_components, critical_path, dijkstra, dijkstra_with_paths,
+    dsatur_coloring, edge_betweenness_centrality, exact_coloring, find_negative_cycle,
+    floyd_warshall, ford_fulkerson, greedy_coloring, greedy_feedback_arc_set, greedy_matching,
+    has_path_connecting, is_cyclic_directed, is_cyclic_undirected, is_isomorphic,
+    is_isomorphic_matching, johnson, k_shortest_path, kosaraju_scc, label_propagation, leiden,
+    longest_path, louvain, maximal_cliques as maximal_cliques_algo, maximum_matching,
+    maximum_weight_bipartite_matching, maximum_weight_matching, min_cost_max_flow,
+    min_spanning_tree, min_st_cut, minimum_cost_perfect_bipartite_matching,
+    misra_gries_edge_coloring, modularity, page_rank, push_relabel, shortest_simple_paths, spfa,
+    stoer_wagner_min_cut, tarjan_scc, toposort, weighted_betweenness_centrality,
+    weighted_edge_betweenness_centrality, ColoringStrategy, Matching,
 };
 use petgraph::data::FromElements;
 use petgraph::dot::{Config, Dot};
@@ -791,6 +798,112 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // checks that the paths reconstructed by dijkstra_with_paths are valid and
+    // have the same cost as computed by dijkstra.
+    fn dijkstra_with_paths_consistent(g: Graph<u32, u32>, node: usize) -> bool {
+        if g.node_count() == 0 {
+            return true;
+        }
+        let v = node_index(node % g.node_count());
+        let distances = dijkstra(&g, v, None, |e| *e.weight());
+        let paths = dijkstra_with_paths(&g, [v], [], |e| *e.weight());
+        if paths.scores != distances {
+            return false;
+        }
+        for (&target, &cost) in &distances {
+            let (nodes, edges) = paths.path_to(target).unwrap();
+            if nodes.first() != Some(&v) || nodes.last() != Some(&target) {
+                return false;
+            }
+            let mut path_cost = 0;
+            for (i, &edge) in edges.iter().enumerate() {
+                if g.edge_endpoints(edge) != Some((nodes[i], nodes[i + 1])) {
+                    return false;
+                }
+                path_cost += g[edge];
+            }
+            if path_cost != cost {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    // checks that the bidirectional searches find paths of the same cost as dijkstra.
+    fn bidirectional_dijkstra_optimal(g: Graph<u32, u32>, a: usize, b: usize) -> bool {
+        if g.node_count() == 0 {
+            return true;
+        }
+        let start = node_index(a % g.node_count());
+        let finish = node_index(b % g.node_count());
+        let distances = dijkstra(&g, start, None, |e| *e.weight());
+        let expected = distances.get(&finish).cloned();
+        let check = |result: Option<(u32, Vec<NodeIndex>)>| match result {
+            None => expected.is_none(),
+            Some((cost, path)) => {
+                let path_cost = path.windows(2).map(|pair| {
+                    g.edges_connecting(pair[0], pair[1]).map(|e| *e.weight()).min().unwrap()
+                }).sum::<u32>();
+                Some(cost) == expected
+                    && path_cost == cost
+                    && path.first() == Some(&start)
+                    && path.last() == Some(&finish)
+            }
+        };
+        check(bidirectional_dijkstra(&g, start, finish, |e| *e.weight()))
+            && check(bidirectional_astar(&g, start, finish, |e| *e.weight(), |_| 0, |_| 0))
+    }
+}
+
+quickcheck! {
+    // checks that Yen's algorithm yields every simple path exactly once, by increasing cost.
+    fn shortest_simple_paths_complete(g: Small<Graph<(), u32>>, a: usize, b: usize) -> bool {
+        // The number of simple paths grows too fast to enumerate them on larger graphs.
+        if g.node_count() == 0 || g.node_count() > 7 {
+            return true;
+        }
+        // Without parallel edges, simple paths are identified by their nodes.
+        let mut simple = Graph::<(), u32>::with_capacity(g.node_count(), g.edge_count());
+        for _ in g.node_indices() {
+            simple.add_node(());
+        }
+        for edge in g.edge_references() {
+            simple.update_edge(edge.source(), edge.target(), *edge.weight() % 16);
+        }
+        let source = node_index(a % g.node_count());
+        let target = node_index(b % g.node_count());
+        if source == target {
+            return true;
+        }
+
+        let mut expected: Vec<(u32, Vec<NodeIndex>)> =
+            petgraph::algo::all_simple_paths::<Vec<_>, _, std::collections::hash_map::RandomState>(
+                &simple, source, target, 0, None,
+            )
+            .map(|nodes| {
+                let cost = nodes
+                    .windows(2)
+                    .map(|pair| simple[simple.find_edge(pair[0], pair[1]).unwrap()])
+                    .sum();
+                (cost, nodes)
+            })
+            .collect();
+        let mut found: Vec<(u32, Vec<NodeIndex>)> =
+            shortest_simple_paths(&simple, source, target, |e| *e.weight())
+                .map(|(cost, nodes, _)| (cost, nodes))
+                .collect();
+        if found.windows(2).any(|pair| pair[0].0 > pair[1].0) {
+            return false;
+        }
+        expected.sort();
+        found.sort();
+        expected == found
+    }
+}
+
 quickcheck! {
     // checks that the distances computed by k'th shortest path is always greater or equal compared to their dijkstra computation
     fn k_shortest_path_(g: Graph<u32, u32>, node: usize) -> bool {
@@ -1192,6 +1305,69 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn reachability_index_and_closure(g: Graph<(), (), Directed>) -> bool {
+        use petgraph::algo::tred::{transitive_closure, ReachabilityIndex};
+
+        let index = ReachabilityIndex::new(&g);
+        let closure: HashSet<_> = transitive_closure(&g).into_iter().collect();
+        for a in g.node_indices() {
+            for b in g.node_indices() {
+                let reaches = g.neighbors(a).any(|next| has_path_connecting(&g, next, b, None));
+                assert_eq!(index.reaches(a, b), reaches);
+                assert_eq!(closure.contains(&(a, b)), reaches);
+            }
+        }
+        true
+    }
+
+    fn transitive_reduction_general(g: Graph<(), (), Directed>) -> bool {
+        use petgraph::algo::tred::{transitive_closure, transitive_reduction};
+
+        let reduction = transitive_reduction(&g);
+        let mut reduced = g.clone();
+        reduced.clear_edges();
+        for &(a, b) in &reduction {
+            reduced.add_edge(a, b, ());
+        }
+        if transitive_closure(&reduced) != transitive_closure(&g) {
+            return false;
+        }
+        // Removing any edge between two components changes the closure.
+        let mut component = vec![0; g.node_count()];
+        for (c, scc) in kosaraju_scc(&g).into_iter().enumerate() {
+            for node in scc {
+                component[node.index()] = c;
+            }
+        }
+        reduced.edge_indices().all(|e| {
+            let (a, b) = reduced.edge_endpoints(e).unwrap();
+            let mut without = reduced.clone();
+            without.remove_edge(e);
+            component[a.index()] == component[b.index()]
+                || !has_path_connecting(&without, a, b, None)
+        })
+    }
+
+    fn transitive_reduction_dag(g: Dag<()>) -> bool {
+        use petgraph::algo::tred::transitive_reduction;
+
+        let acyclic = g.0;
+        let toposort = toposort(&acyclic, None).unwrap();
+        let (toposorted, _): (petgraph::adj::List<(), usize>, _) =
+            petgraph::algo::tred::dag_to_toposorted_adjacency_list(&acyclic, &toposort);
+        let (tred, _) = petgraph::algo::tred::dag_transitive_reduction_closure(&toposorted);
+        let mut expected: Vec<_> = tred
+            .edge_references()
+            .map(|e| (toposort[e.source()], toposort[e.target()]))
+            .collect();
+        let mut reduction = transitive_reduction(&acyclic);
+        expected.sort();
+        reduction.sort();
+        reduction == expected
+    }
+}
+
 quickcheck! {
     fn greedy_fas_remaining_graph_is_acyclic(g: StableDiGraph<(), ()>) -> bool {
         let mut g = g;
@@ -1316,6 +1492,119 @@ quickcheck! {
         true
     }
 }
+/// Best matchings found by brute force.
+#[derive(Debug, Default)]
+struct BestMatchings {
+    max_weight: u32,
+    /// The largest cardinality, and the maximum weight with this cardinality.
+    max_cardinality: (usize, u32),
+    min_perfect: Option<u32>,
+}
+
+/// Enumerate all the matchings of `g`, ignoring self-loops.
+fn brute_force_weighted_matching(g: &UnGraph<(), u8>) -> BestMatchings {
+    fn search(
+        g: &UnGraph<(), u8>,
+        edges: &[EdgeIndex],
+        matched: &mut Vec<bool>,
+        size: usize,
+        weight: u32,
+        best: &mut BestMatchings,
+    ) {
+        match edges.split_first() {
+            None => {
+                best.max_weight = best.max_weight.max(weight);
+                best.max_cardinality = best.max_cardinality.max((size, weight));
+                if 2 * size == g.node_count() {
+                    best.min_perfect = Some(best.min_perfect.map_or(weight, |w| w.min(weight)));
+                }
+            }
+            Some((&edge, rest)) => {
+                search(g, rest, matched, size, weight, best);
+                let (a, b) = g.edge_endpoints(edge).unwrap();
+                if a != b && !matched[a.index()] && !matched[b.index()] {
+                    matched[a.index()] = true;
+                    matched[b.index()] = true;
+                    search(g, rest, matched, size + 1, weight + g[edge] as u32, best);
+                    matched[a.index()] = false;
+                    matched[b.index()] = false;
+                }
+            }
+        }
+    }
+
+    let edges: Vec<_> = g.edge_indices().collect();
+    let mut best = BestMatchings::default();
+    search(g, &edges, &mut vec![false; g.node_count()], 0, 0, &mut best);
+    best
+}
+
+/// The total weight of a matching, using the heaviest edge between matched nodes.
+fn matching_weight(g: &UnGraph<(), u8>, m: &Matching<&UnGraph<(), u8>>) -> u32 {
+    m.edges()
+        .map(|(a, b)| {
+            g.edges_connecting(a, b)
+                .map(|e| *e.weight() as u32)
+                .max()
+                .unwrap()
+        })
+        .sum()
+}
+
+quickcheck! {
+    // checks the weighted bipartite matchings against all the matchings of the
+    // bipartite graph made of the edges between even and odd nodes.
+    fn weighted_bipartite_matching(g: Graph<(), u8, Undirected>) -> bool {
+        let mut bipartite = UnGraph::with_capacity(g.node_count(), 0);
+        for _ in g.node_indices() {
+            bipartite.add_node(());
+        }
+        for e in g.edge_references() {
+            if (e.source().index() + e.target().index()) % 2 == 1 {
+                bipartite.add_edge(e.source(), e.target(), *e.weight());
+            }
+        }
+        if bipartite.edge_count() > 16 {
+            return true;
+        }
+        let (even, odd): (Vec<_>, Vec<_>) = bipartite.node_indices().partition(|n| n.index() % 2 == 0);
+        let expected = brute_force_weighted_matching(&bipartite);
+
+        let weight = |e: petgraph::graph::EdgeReference<u8>| *e.weight() as u32;
+        let (m, max) = maximum_weight_bipartite_matching(&bipartite, &even, &odd, weight);
+        assert!(is_valid_matching(&m));
+        assert_eq!(max, expected.max_weight);
+        assert_eq!(matching_weight(&bipartite, &m), expected.max_weight);
+
+        let min = minimum_cost_perfect_bipartite_matching(&bipartite, &even, &odd, weight);
+        assert_eq!(min.as_ref().map(|(_, cost)| *cost), expected.min_perfect);
+        if let Some((m, _)) = min {
+            assert!(is_valid_matching(&m));
+            assert!(m.is_perfect());
+        }
+        true
+    }
+}
+
+quickcheck! {
+    // checks the weighted blossom algorithm against all the matchings of small graphs.
+    fn weighted_matching(g: Graph<(), u8, Undirected>) -> bool {
+        if g.edge_count() > 16 {
+            return true;
+        }
+        let expected = brute_force_weighted_matching(&g);
+
+        let m = maximum_weight_matching(&g, false, |e| *e.weight() as i64);
+        assert!(is_valid_matching(&m));
+        assert_eq!(matching_weight(&g, &m), expected.max_weight);
+
+        let m = maximum_weight_matching(&g, true, |e| *e.weight() as i64);
+        assert!(is_valid_matching(&m));
+        assert_eq!((m.len(), matching_weight(&g, &m)), expected.max_cardinality);
+        true
+    }
+}
+
 quickcheck! {
     fn test_bridges(g: Graph<(), (), Undirected>) -> bool {
         let num = connected_components(&g);
@@ -1403,6 +1692,237 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // checks that push-relabel computes a valid flow with the same value as ford-fulkerson.
+    fn test_push_relabel(gr: Graph<usize, u32>) -> bool {
+        if gr.node_count() <= 1 || gr.edge_count() == 0 {
+            return true;
+        }
+        let source = NodeIndex::from(0);
+        let destination = NodeIndex::from(gr.node_count() as u32 / 2);
+        let (expected_flow, _) = ford_fulkerson(&gr, source, destination);
+        let (max_flow, flows) = push_relabel(&gr, source, destination);
+        let capacity_constraint = gr
+            .edge_references()
+            .all(|e| flows[e.id().index()] <= *e.weight());
+        let flow_conservation_constraint = gr.node_indices().all(|node| {
+            let out_flow: u64 = gr.edges_directed(node, Direction::Outgoing).map(|e| flows[e.id().index()] as u64).sum();
+            let in_flow: u64 = gr.edges_directed(node, Direction::Incoming).map(|e| flows[e.id().index()] as u64).sum();
+            if node == source {
+                out_flow == in_flow + max_flow as u64
+            } else if node == destination {
+                in_flow == out_flow + max_flow as u64
+            } else {
+                out_flow == in_flow
+            }
+        });
+        max_flow == expected_flow && capacity_constraint && flow_conservation_constraint
+    }
+}
+
+quickcheck! {
+    // checks that the cheapest maximum flow is a valid maximum flow whose cost is
+    // the sum of its edge costs.
+    fn test_min_cost_max_flow(gr: Graph<usize, (u32, u8)>) -> bool {
+        if gr.node_count() <= 1 || gr.edge_count() == 0 {
+            return true;
+        }
+        let source = NodeIndex::from(0);
+        let destination = NodeIndex::from(gr.node_count() as u32 / 2);
+        let capacities = gr.map(|_, &w| w, |_, w| w.0);
+        let (max_flow, _) = ford_fulkerson(&capacities, source, destination);
+        let (flow, cost, flows) = min_cost_max_flow(
+            &gr,
+            source,
+            destination,
+            |e| e.weight().0 as i64,
+            |e| e.weight().1 as i64,
+        )
+        .unwrap();
+        let capacity_constraint = gr
+            .edge_references()
+            .all(|e| 0 <= flows[e.id().index()] && flows[e.id().index()] <= e.weight().0 as i64);
+        let flow_conservation_constraint = gr.node_indices().all(|node| {
+            let out_flow: i64 = gr.edges_directed(node, Direction::Outgoing).map(|e| flows[e.id().index()]).sum();
+            let in_flow: i64 = gr.edges_directed(node, Direction::Incoming).map(|e| flows[e.id().index()]).sum();
+            if node == source {
+                out_flow - in_flow == flow
+            } else if node == destination {
+                in_flow - out_flow == flow
+            } else {
+                out_flow == in_flow
+            }
+        });
+        let total_cost: i64 = gr.edge_references().map(|e| flows[e.id().index()] * e.weight().1 as i64).sum();
+        flow == max_flow as i64 && cost == total_cost && capacity_constraint && flow_conservation_constraint
+    }
+}
+
+quickcheck! {
+    // checks that the edges of the minimum cut have a total capacity equal to the
+    // maximum flow, and separate the sink from the source.
+    fn test_min_st_cut(gr: Graph<usize, u32>) -> bool {
+        if gr.node_count() <= 1 || gr.edge_count() == 0 {
+            return true;
+        }
+        let source = NodeIndex::from(0);
+        let destination = NodeIndex::from(gr.node_count() as u32 / 2);
+        let (max_flow, flows) = ford_fulkerson(&gr, source, destination);
+        let (source_side, cut_edges) = min_st_cut(&gr, source, &flows);
+        let capacity: u64 = cut_edges.iter().map(|&e| gr[e] as u64).sum();
+        capacity == max_flow as u64 && !source_side.contains(&destination)
+    }
+}
+
+quickcheck! {
+    // checks the Stoer-Wagner minimum cut against all possible cuts.
+    fn test_stoer_wagner_min_cut(gr: Small<UnGraph<(), u8>>) -> bool {
+        let n = gr.node_count();
+        if n > 10 {
+            return true;
+        }
+        let result = stoer_wagner_min_cut(&*gr, |e| *e.weight() as u32);
+        if n < 2 {
+            return result.is_none();
+        }
+        let cut_weight = |in_side: &dyn Fn(NodeIndex) -> bool| -> u32 {
+            gr.edge_references()
+                .filter(|e| in_side(e.source()) != in_side(e.target()))
+                .map(|e| *e.weight() as u32)
+                .sum()
+        };
+        // Enumerate the cuts by the set of nodes separated from the last node.
+        let minimum = (1..(1u32 << (n - 1)))
+            .map(|mask| cut_weight(&|v: NodeIndex| mask & (1 << v.index()) != 0))
+            .min()
+            .unwrap();
+        let (weight, side) = result.unwrap();
+        !side.is_empty()
+            && side.len() < n
+            && weight == minimum
+            && cut_weight(&|v: NodeIndex| side.contains(&v)) == weight
+    }
+}
+
+quickcheck! {
+    fn longest_and_critical_paths(g: Dag<()>, negative: bool) -> bool {
+        let dag = g.0;
+        let offset = if negative { 3 } else { 0 };
+        let edge_weight = |i: usize| (i * 7 % 11) as i64 - offset;
+        let weight = |e: petgraph::graph::EdgeReference<()>| edge_weight(e.id().index());
+        let (length, path) = longest_path(&dag, weight).unwrap();
+
+        // Compare with the shortest paths from every node for the negated weights.
+        let negated = dag.map(|_, _| (), |e, _| -edge_weight(e.index()) as f64);
+        let expected = dag
+            .node_indices()
+            .filter_map(|source| {
+                let paths = bellman_ford(&negated, source).unwrap();
+                paths.distances.into_iter().map(|d| -d).filter(|d| d.is_finite()).reduce(f64::max)
+            })
+            .reduce(f64::max)
+            .unwrap_or(0.);
+        assert_eq!(length as f64, expected);
+        let path_length: i64 = path
+            .windows(2)
+            .map(|pair| weight(dag.edges_connecting(pair[0], pair[1]).max_by_key(|&e| weight(e)).unwrap()))
+            .sum();
+        assert_eq!(path_length, length);
+
+        let schedule = critical_path(&dag, weight).unwrap();
+        assert_eq!((schedule.length, &schedule.path), (length, &path));
+        dag.node_indices().all(|n| {
+            let i = n.index();
+            schedule.slack[i] == schedule.latest_start[i] - schedule.earliest_start[i]
+                && (negative || schedule.slack[i] >= 0)
+                && (negative || !path.contains(&n) || schedule.slack[i] == 0)
+        }) && dag.edge_references().all(|e| {
+            let (s, t) = (e.source().index(), e.target().index());
+            schedule.earliest_start[s] + weight(e) <= schedule.earliest_start[t]
+                && schedule.latest_start[s] + weight(e) <= schedule.latest_start[t]
+        })
+    }
+}
+
+quickcheck! {
+    fn acyclic_reachability_queries(g: Dag<()>) -> bool {
+        use petgraph::acyclic::Acyclic;
+
+        let dag = g.0;
+        let acyclic = Acyclic::try_from(dag.clone()).unwrap();
+        let nodes: Vec<_> = dag.node_indices().take(20).collect();
+        for &a in &nodes {
+            let descendants: HashSet<_> = acyclic.descendants(a).into_iter().collect();
+            let ancestors: HashSet<_> = acyclic.ancestors(a).into_iter().collect();
+            for &b in &nodes {
+                let reachable = has_path_connecting(&dag, a, b, None);
+                assert_eq!(acyclic.is_reachable(a, b), reachable);
+                assert_eq!(descendants.contains(&b), reachable && a != b);
+                assert_eq!(ancestors.contains(&b), has_path_connecting(&dag, b, a, None) && a != b);
+
+                let lcas = acyclic.lowest_common_ancestors(a, b);
+                let is_common = |c| {
+                    has_path_connecting(&dag, c, a, None) && has_path_connecting(&dag, c, b, None)
+                };
+                for &c in &lcas {
+                    assert!(is_common(c));
+                    assert!(dag.neighbors(c).all(|child| !is_common(child)));
+                }
+                // Every common ancestor has a lowest one among its descendants.
+                for c in dag.node_indices().filter(|&c| is_common(c)) {
+                    assert!(lcas.iter().any(|&lca| has_path_connecting(&dag, c, lca, None)));
+                }
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn acyclic_batch_edge_insertion(dag: Dag<()>, batch: Vec<(usize, usize)>) -> bool {
+        use petgraph::acyclic::{Acyclic, AcyclicEdgeError};
+
+        let mut graph = dag.0;
+        let n = graph.node_count();
+        if n == 0 {
+            return true;
+        }
+        let batch: Vec<_> = batch
+            .into_iter()
+            .map(|(a, b)| (node_index(a % n), node_index(b % n)))
+            .collect();
+        let mut acyclic = Acyclic::try_from(graph.clone()).unwrap();
+        let order: Vec<_> = acyclic.nodes_iter().collect();
+        let result = acyclic.try_add_edges(batch.iter().map(|&(a, b)| (a, b, ())));
+
+        graph.extend_with_edges(&batch);
+        match result {
+            Ok(edges) => {
+                assert!(!is_cyclic_directed(&graph));
+                assert_eq!(edges.len(), batch.len());
+                assert_eq!(acyclic.edge_count(), graph.edge_count());
+                let position: HashMap<_, _> =
+                    acyclic.nodes_iter().enumerate().map(|(i, v)| (v, i)).collect();
+                graph.edge_references().all(|e| position[&e.source()] < position[&e.target()])
+            }
+            Err(AcyclicEdgeError::Cycles(cycles)) => {
+                let sccs = tarjan_scc(&graph);
+                let cyclic = sccs.iter().filter(|scc| scc.len() > 1).count()
+                    + batch.iter().filter(|&&(a, b)| a == b).count();
+                // Self-loops in a larger cycle are counted twice in `cyclic`.
+                assert!(!cycles.is_empty() && cycles.len() <= cyclic);
+                assert!(cycles.iter().all(|cycle| {
+                    let v = cycle.node_id();
+                    batch.contains(&(v, v)) || sccs.iter().any(|scc| scc.len() > 1 && scc.contains(&v))
+                }));
+                acyclic.edge_count() + batch.len() == graph.edge_count()
+                    && acyclic.nodes_iter().eq(order)
+            }
+            Err(_) => false,
+        }
+    }
+}
+
 quickcheck! {
     fn test_dynamic_toposort(g: DiGraph<(), ()>) -> bool {
         use petgraph::acyclic::Acyclic;
@@ -1482,6 +2002,47 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn greedy_and_exact_coloring_quickcheck(g: Graph<(), (), Undirected>, seed: u64) -> bool {
+        let (_, dsatur_colors) = dsatur_coloring(&g);
+        for strategy in [
+            ColoringStrategy::LargestFirst,
+            ColoringStrategy::SmallestLast,
+            ColoringStrategy::RandomSequential(seed),
+        ] {
+            let (coloring, _) = greedy_coloring(&g, strategy);
+            assert!(is_proper_coloring(&g, &coloring), "greedy_coloring returned a non proper coloring");
+        }
+        if g.node_count() <= 12 {
+            let (coloring, nb_colors) = exact_coloring(&g);
+            assert!(is_proper_coloring(&g, &coloring), "exact_coloring returned a non proper coloring");
+            assert!(nb_colors <= dsatur_colors);
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn misra_gries_edge_coloring_quickcheck(g: Graph<(), (), Undirected>) -> bool {
+        // Keep a single edge between each pair of nodes.
+        let mut simple = g.clone();
+        simple.clear_edges();
+        for edge in g.edge_references() {
+            let (a, b) = (edge.source(), edge.target());
+            if a != b && simple.find_edge(a, b).is_none() {
+                simple.add_edge(a, b, ());
+            }
+        }
+        let (coloring, nb_colors) = misra_gries_edge_coloring(&simple);
+        let max_degree = simple.node_indices().map(|node| simple.edges(node).count()).max();
+        assert!(nb_colors <= max_degree.unwrap_or(0) + 1);
+        simple.node_indices().all(|node| {
+            let colors: HashSet<_> = simple.edges(node).map(|e| coloring[&e.id()]).collect();
+            colors.len() == simple.edges(node).count()
+        })
+    }
+}
+
 quickcheck! {
     // Test that removal of articulation points will always increase the amount of connected components.
     fn test_articulation_points(g: Graph<(), u32, Undirected>) -> bool {
@@ -1569,6 +2130,30 @@ fn steiner_tree_spans_terminals() {
     quickcheck::quickcheck(prop as fn(Graph<(), u32, Undirected>) -> bool);
 }
 
+#[cfg(feature = "stable_graph")]
+quickcheck! {
+    // Mehlhorn's approximation costs at most twice the exact Dreyfus–Wagner tree.
+    fn steiner_tree_approximation_ratio(g: UnGraph<(), u32>, seed: u8) -> bool {
+        use petgraph::algo::steiner_tree::{dreyfus_wagner_steiner_tree, mehlhorn_steiner_tree};
+
+        let terminals: Vec<_> = g
+            .node_indices()
+            .filter(|node| (node.index() + seed as usize) % 7 == 0)
+            .take(6)
+            .collect();
+        let cost = |edges: &HashSet<EdgeIndex>| edges.iter().map(|&e| g[e] as u64).sum::<u64>();
+        let exact = dreyfus_wagner_steiner_tree(&g, &terminals, |e| *e.weight() as u64);
+        let approximate = mehlhorn_steiner_tree(&g, &terminals, |e| *e.weight() as u64);
+        match (exact, approximate) {
+            (Some(exact), Some(approximate)) => {
+                let (exact, approximate) = (cost(&exact), cost(&approximate));
+                exact <= approximate && approximate <= 2 * exact
+            }
+            (exact, approximate) => exact.is_none() && approximate.is_none(),
+        }
+    }
+}
+
 #[test]
 fn maximal_cliques_matches_ref_impl() {
     use maximal_cliques::maximal_cliques_ref;
@@ -1699,3 +2284,110 @@ quickcheck! {
         true
     }
 }
+
+fn betweenness_unit_costs<Ty: EdgeType>(g: &Graph<(), u8, Ty>) -> bool {
+    // Dijkstra-based betweenness with unit costs must agree with the BFS one.
+    let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-6);
+    let unweighted = betweenness_centrality(g, false);
+    let weighted = weighted_betweenness_centrality(g, false, |_| 1u32);
+    let edges = edge_betweenness_centrality(g, false);
+    let edges_weighted = weighted_edge_betweenness_centrality(g, false, |_| 1u32);
+    close(&unweighted, &weighted) && close(&edges, &edges_weighted)
+}
+
+quickcheck! {
+    fn betweenness_weighted_unit_costs(g: Graph<(), u8>) -> bool {
+        betweenness_unit_costs(&g)
+    }
+
+    fn betweenness_weighted_unit_costs_undirected(g: Graph<(), u8, Undirected>) -> bool {
+        betweenness_unit_costs(&g)
+    }
+}
+
+/// Check that `labels` are numbered from zero in node order.
+fn is_dense_labeling(labels: &[usize]) -> bool {
+    let mut next = 0;
+    labels.iter().all(|&label| {
+        if label == next {
+            next += 1;
+        }
+        label < next
+    })
+}
+
+/// Check that each community induces a connected subgraph.
+fn communities_are_connected(g: &UnGraph<(), u8>, labels: &[usize]) -> bool {
+    let same_community = EdgeFiltered::from_fn(g, |e| {
+        labels[e.source().index()] == labels[e.target().index()]
+    });
+    let mut components = HashMap::new();
+    for node in g.node_indices() {
+        let root = *components.entry(labels[node.index()]).or_insert(node);
+        if !has_path_connecting(&same_community, root, node, None) {
+            return false;
+        }
+    }
+    true
+}
+
+quickcheck! {
+    fn community_detection(g: UnGraph<(), u8>, seed: u64) -> bool {
+        let weight = |e: petgraph::graph::EdgeReference<u8>| *e.weight() as f64;
+        let singletons: Vec<usize> = (0..g.node_count()).collect();
+        let baseline = modularity(&g, &singletons, weight, 1.);
+
+        let louvain_labels = louvain(&g, weight, 1., seed);
+        let leiden_labels = leiden(&g, weight, 1., seed);
+        let lpa_labels = label_propagation(&g, weight, seed);
+        is_dense_labeling(&louvain_labels)
+            && is_dense_labeling(&leiden_labels)
+            && is_dense_labeling(&lpa_labels)
+            && modularity(&g, &louvain_labels, weight, 1.) >= baseline - 1e-9
+            && modularity(&g, &leiden_labels, weight, 1.) >= baseline - 1e-9
+            && communities_are_connected(&g, &leiden_labels)
+    }
+}
+
+/// Check that a batch of changes to a `Csr` matches applying them one by one.
+fn csr_batch_changes<Ty: EdgeType>(edges: Vec<(u8, u8, u8)>, changes: Vec<(bool, u8, u8, u8)>) {
+    use petgraph::csr::{Csr, EdgeChange};
+
+    let n = 16;
+    let edges = edges
+        .into_iter()
+        .map(|(a, b, w)| (a as u32 % n, b as u32 % n, w));
+    let mut batch = Csr::<(), u8, Ty>::from_edges(edges.chain([(n - 1, n - 1, 0)]));
+    let mut single = batch.clone();
+    let changes: Vec<_> = changes
+        .into_iter()
+        .map(|(add, a, b, w)| {
+            let (source, target) = (a as u32 % n, b as u32 % n);
+            if add {
+                single.add_edge(source, target, w);
+                EdgeChange::Add {
+                    source,
+                    target,
+                    weight: w,
+                }
+            } else {
+                single.remove_edge(source, target);
+                EdgeChange::Remove { source, target }
+            }
+        })
+        .collect();
+    batch.apply_changes(changes);
+
+    assert_eq!(batch.edge_count(), single.edge_count());
+    for node in 0..n {
+        assert_eq!(batch.neighbors_slice(node), single.neighbors_slice(node));
+        assert_eq!(batch.edges_slice(node), single.edges_slice(node));
+    }
+}
+
+quickcheck! {
+    fn csr_apply_changes(edges: Vec<(u8, u8, u8)>, changes: Vec<(bool, u8, u8, u8)>) -> () {
+        csr_batch_changes::<Directed>(edges.clone(), changes.clone());
+        csr_batch_changes::<Undirected>(edges, changes);
+    }
+}
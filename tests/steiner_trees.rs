@@ -362,8 +362,34 @@ fn example_kou_paper() -> (UnGraph<(), usize>, Vec<NodeIndex>) {
 #[cfg(test)]
 mod test {
     use crate::{b01_example, b07_example, example_kou_paper};
-    use petgraph::algo::{connected_components, steiner_tree};
-    use petgraph::graph::UnGraph;
+    use hashbrown::HashSet;
+    use petgraph::algo::{
+        connected_components, dreyfus_wagner_steiner_tree, mehlhorn_steiner_tree, steiner_tree,
+    };
+    use petgraph::graph::{EdgeIndex, NodeIndex, UnGraph};
+    use petgraph::stable_graph::{EdgeReference, StableUnGraph};
+
+    /// Check that `edges` form a tree spanning the terminals, and return its cost.
+    fn tree_cost<E: Copy + Into<f64>>(
+        graph: &UnGraph<(), E>,
+        terminals: &[NodeIndex],
+        edges: &HashSet<EdgeIndex>,
+    ) -> f64 {
+        let tree = graph.filter_map(|_, _| Some(()), |e, &w| edges.contains(&e).then_some(w));
+        let mut nodes: HashSet<_> = edges
+            .iter()
+            .flat_map(|&e| {
+                let (a, b) = graph.edge_endpoints(e).unwrap();
+                [a, b]
+            })
+            .collect();
+        nodes.extend(terminals);
+        assert_eq!(edges.len() + 1, nodes.len());
+        // The isolated nodes outside of the tree are components of their own.
+        let outside = graph.node_count() - nodes.len();
+        assert_eq!(connected_components(&tree), outside + 1);
+        edges.iter().map(|&e| graph[e].into()).sum()
+    }
 
     #[test]
     fn b01_vienna_test() {
@@ -402,4 +428,69 @@ mod test {
         assert_eq!(connected_components(&UnGraph::from(st)), 1);
         assert_eq!(weights, 8);
     }
+
+    #[test]
+    fn dreyfus_wagner_vienna_tests() {
+        for ((graph, terminals), optimum) in [(b01_example(), 82.), (b07_example(), 111.)] {
+            let tree = dreyfus_wagner_steiner_tree(&graph, &terminals, |e| *e.weight()).unwrap();
+            assert_eq!(tree_cost(&graph, &terminals, &tree), optimum);
+
+            let tree = mehlhorn_steiner_tree(&graph, &terminals, |e| *e.weight()).unwrap();
+            let cost = tree_cost(&graph, &terminals, &tree);
+            assert!(optimum <= cost && cost <= 2. * optimum);
+        }
+    }
+
+    #[test]
+    fn example_kous_paper_edge_sets() {
+        let (graph, terminals) = example_kou_paper();
+        let graph = graph.map(|_, _| (), |_, &w| w as u32);
+        let tree = dreyfus_wagner_steiner_tree(&graph, &terminals, |e| *e.weight()).unwrap();
+        assert_eq!(tree_cost(&graph, &terminals, &tree), 8.);
+        let tree = mehlhorn_steiner_tree(&graph, &terminals, |e| *e.weight()).unwrap();
+        assert_eq!(tree_cost(&graph, &terminals, &tree), 8.);
+    }
+
+    #[test]
+    fn steiner_tree_edge_cases() {
+        let mut graph = StableUnGraph::<(), f64>::default();
+        let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+        graph.extend_with_edges([(0, 1, 1.), (1, 2, 1.), (0, 2, 3.), (3, 4, 1.), (2, 5, 1.)]);
+        graph.remove_node(nodes[5]);
+
+        type Cost<'a> = &'a dyn Fn(EdgeReference<f64>) -> f64;
+        for algorithm in [
+            dreyfus_wagner_steiner_tree::<_, Cost, f64>,
+            mehlhorn_steiner_tree::<_, Cost, f64>,
+        ] {
+            let cost: Cost = &|e| *e.weight();
+            let tree = algorithm(&graph, &[nodes[0], nodes[2], nodes[0]], cost).unwrap();
+            assert_eq!(tree.len(), 2);
+            // A constant cost prefers the direct edge.
+            let tree = algorithm(&graph, &[nodes[0], nodes[2]], &|_| 1.).unwrap();
+            assert_eq!(tree.len(), 1);
+
+            assert!(algorithm(&graph, &[], cost).unwrap().is_empty());
+            assert!(algorithm(&graph, &[nodes[4]], cost).unwrap().is_empty());
+            assert!(algorithm(&graph, &[nodes[0], nodes[4]], cost).is_none());
+        }
+    }
+}
+
+// The trait-generic versions do not need the `stable_graph` feature.
+#[test]
+fn generic_steiner_trees() {
+    use petgraph::algo::{dreyfus_wagner_steiner_tree, mehlhorn_steiner_tree};
+    use petgraph::graph::UnGraph;
+
+    // A star with an expensive shortcut between two of its leaves.
+    let graph = UnGraph::<(), u32>::from_edges([(0, 1, 1), (0, 2, 1), (0, 3, 1), (1, 2, 5)]);
+    let terminals = [1.into(), 2.into(), 3.into()];
+    for tree in [
+        mehlhorn_steiner_tree(&graph, &terminals, |e| *e.weight()),
+        dreyfus_wagner_steiner_tree(&graph, &terminals, |e| *e.weight()),
+    ] {
+        let tree = tree.unwrap();
+        assert_eq!(tree.iter().map(|&e| graph[e]).sum::<u32>(), 3);
+    }
 }